@@ -144,6 +144,7 @@ export const definitions = {
                     bond_extra: "Weight",
                     unbond: "Weight",
                     withdraw_unbonded: "Weight",
+                    nominate: "Weight",
                 },
                 StatemintConfig: {
                     parachain_id: "u32",
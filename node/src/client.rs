@@ -20,7 +20,9 @@ pub trait RuntimeApiCollection:
 	+ sp_block_builder::BlockBuilder<Block>
 	+ frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Nonce>
 	+ pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance>
-	+ pallet_asset_index_rpc::AssetIndexRuntimeApi<Block, AccountId, AssetId, Balance>
+	+ pallet_asset_index_rpc::AssetIndexRuntimeApi<Block, AccountId, AssetId, Balance, BlockNumber>
+	+ pallet_committee_rpc::CommitteeRuntimeApi<Block, AccountId, Hash, BlockNumber>
+	+ pallet_saft_registry_rpc::SaftRegistryRuntimeApi<Block, AssetId, Balance, BlockNumber>
 	+ sp_api::Metadata<Block>
 	+ sp_offchain::OffchainWorkerApi<Block>
 	+ sp_session::SessionKeys<Block>
@@ -37,7 +39,9 @@ where
 		+ sp_block_builder::BlockBuilder<Block>
 		+ frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Nonce>
 		+ pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance>
-		+ pallet_asset_index_rpc::AssetIndexRuntimeApi<Block, AccountId, AssetId, Balance>
+		+ pallet_asset_index_rpc::AssetIndexRuntimeApi<Block, AccountId, AssetId, Balance, BlockNumber>
+		+ pallet_committee_rpc::CommitteeRuntimeApi<Block, AccountId, Hash, BlockNumber>
+		+ pallet_saft_registry_rpc::SaftRegistryRuntimeApi<Block, AssetId, Balance, BlockNumber>
 		+ sp_api::Metadata<Block>
 		+ sp_offchain::OffchainWorkerApi<Block>
 		+ sp_session::SessionKeys<Block>
@@ -9,16 +9,72 @@
 #![allow(clippy::unnecessary_mut_passed)]
 
 use codec::Codec;
+use sp_runtime::DispatchError;
 use sp_std::prelude::*;
 
-use primitives::Ratio;
+use primitives::{
+	AssetProportions, NavSnapshot, PauseReason, PendingRedemption, Ratio, RebalancePlan, WithdrawalQuote,
+};
 
 sp_api::decl_runtime_apis! {
-	pub trait AssetIndexApi<AccountId, AssetId, Balance> where
+	/// Version 2 added every method except `get_nav`, which is unchanged since version 1.
+	#[api_version(2)]
+	pub trait AssetIndexApi<AccountId, AssetId, Balance, BlockNumber> where
 		AccountId: Codec,
 		AssetId: Codec,
 		Balance: Codec,
+		BlockNumber: Codec,
 	{
+		/// The per token value of the index token
 		fn get_nav() -> Ratio;
+
+		/// The per token value of the index token for the liquid assets only
+		fn get_liquid_nav() -> Ratio;
+
+		/// The per token value of the index token for the SAFTs only
+		fn get_saft_nav() -> Ratio;
+
+		/// The share of every asset in the total value of the index
+		fn get_asset_proportions() -> Result<AssetProportions<AssetId>, DispatchError>;
+
+		/// The amount of index tokens the given units of the asset are worth
+		fn get_index_token_equivalent(asset: AssetId, units: Balance) -> Result<Balance, DispatchError>;
+
+		/// The units of the asset the given amount of index tokens are worth
+		fn get_asset_equivalent(index_tokens: Balance, asset: AssetId) -> Result<Balance, DispatchError>;
+
+		/// All timestamped deposits of the account
+		fn get_deposits(account: AccountId) -> Vec<(Balance, BlockNumber)>;
+
+		/// All pending redemptions of the account
+		fn get_pending_withdrawals(account: AccountId) -> Vec<PendingRedemption<AssetId, Balance, BlockNumber>>;
+
+		/// The amount of currently locked index tokens of the account
+		fn get_locked_index_tokens(account: AccountId) -> Balance;
+
+		/// The fees and payouts the account would receive when withdrawing the given amount
+		fn quote_withdraw(
+			account: AccountId,
+			amount: Balance,
+		) -> Result<WithdrawalQuote<AssetId, Balance, BlockNumber>, DispatchError>;
+
+		/// The fees and payout the account would receive when withdrawing the given amount into a single asset
+		fn quote_withdraw_to_asset(
+			account: AccountId,
+			amount: Balance,
+			asset: AssetId,
+		) -> Result<WithdrawalQuote<AssetId, Balance, BlockNumber>, DispatchError>;
+
+		/// The trades required to move the index back to its target weights
+		fn get_rebalance_plan() -> Result<RebalancePlan<AssetId, Balance>, DispatchError>;
+
+		/// The total amount of index tokens minted to the treasury as management fee
+		fn get_accrued_management_fees() -> Balance;
+
+		/// The recorded NAV snapshots, ordered from oldest to newest
+		fn get_nav_snapshots() -> Vec<NavSnapshot<BlockNumber>>;
+
+		/// The reason why deposits and withdrawals are paused, if they are
+		fn get_pause_reason() -> Option<PauseReason<AssetId>>;
 	}
 }
@@ -6,20 +6,82 @@
 use codec::Codec;
 use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
 use jsonrpc_derive::rpc;
-use primitives::Ratio;
-use sp_api::ProvideRuntimeApi;
+use primitives::{
+	AssetProportions, NavSnapshot, PauseReason, PendingRedemption, Ratio, RebalancePlan, WithdrawalQuote,
+};
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
-use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use sp_runtime::{generic::BlockId, traits::Block as BlockT, DispatchError};
 use std::sync::Arc;
 
 pub use self::gen_client::Client as AssetIndexClient;
 pub use pallet_asset_index_rpc_runtime_api::AssetIndexApi as AssetIndexRuntimeApi;
 
+/// The version of the [`AssetIndexRuntimeApi`] that introduced all methods except `get_nav`.
+const API_VERSION_2: u32 = 2;
+
 /// Asset index state API
 #[rpc]
-pub trait AssetIndexApi<BlockHash, AccountId, AssetId, Balance> {
+pub trait AssetIndexApi<BlockHash, AccountId, AssetId, Balance, BlockNumber> {
 	#[rpc(name = "assetIndex_getNav")]
 	fn get_nav(&self, at: Option<BlockHash>) -> Result<Ratio>;
+
+	#[rpc(name = "assetIndex_getLiquidNav")]
+	fn get_liquid_nav(&self, at: Option<BlockHash>) -> Result<Ratio>;
+
+	#[rpc(name = "assetIndex_getSaftNav")]
+	fn get_saft_nav(&self, at: Option<BlockHash>) -> Result<Ratio>;
+
+	#[rpc(name = "assetIndex_getAssetProportions")]
+	fn get_asset_proportions(&self, at: Option<BlockHash>) -> Result<AssetProportions<AssetId>>;
+
+	#[rpc(name = "assetIndex_getIndexTokenEquivalent")]
+	fn get_index_token_equivalent(&self, asset: AssetId, units: Balance, at: Option<BlockHash>) -> Result<Balance>;
+
+	#[rpc(name = "assetIndex_getAssetEquivalent")]
+	fn get_asset_equivalent(&self, index_tokens: Balance, asset: AssetId, at: Option<BlockHash>) -> Result<Balance>;
+
+	#[rpc(name = "assetIndex_getDeposits")]
+	fn get_deposits(&self, account: AccountId, at: Option<BlockHash>) -> Result<Vec<(Balance, BlockNumber)>>;
+
+	#[rpc(name = "assetIndex_getPendingWithdrawals")]
+	fn get_pending_withdrawals(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> Result<Vec<PendingRedemption<AssetId, Balance, BlockNumber>>>;
+
+	#[rpc(name = "assetIndex_getLockedIndexTokens")]
+	fn get_locked_index_tokens(&self, account: AccountId, at: Option<BlockHash>) -> Result<Balance>;
+
+	#[rpc(name = "assetIndex_quoteWithdraw")]
+	fn quote_withdraw(
+		&self,
+		account: AccountId,
+		amount: Balance,
+		at: Option<BlockHash>,
+	) -> Result<WithdrawalQuote<AssetId, Balance, BlockNumber>>;
+
+	#[rpc(name = "assetIndex_quoteWithdrawToAsset")]
+	fn quote_withdraw_to_asset(
+		&self,
+		account: AccountId,
+		amount: Balance,
+		asset: AssetId,
+		at: Option<BlockHash>,
+	) -> Result<WithdrawalQuote<AssetId, Balance, BlockNumber>>;
+
+	#[rpc(name = "assetIndex_getRebalancePlan")]
+	fn get_rebalance_plan(&self, at: Option<BlockHash>) -> Result<RebalancePlan<AssetId, Balance>>;
+
+	#[rpc(name = "assetIndex_getAccruedManagementFees")]
+	fn get_accrued_management_fees(&self, at: Option<BlockHash>) -> Result<Balance>;
+
+	#[rpc(name = "assetIndex_getNavSnapshots")]
+	fn get_nav_snapshots(&self, at: Option<BlockHash>) -> Result<Vec<NavSnapshot<BlockNumber>>>;
+
+	#[rpc(name = "assetIndex_getPauseReason")]
+	fn get_pause_reason(&self, at: Option<BlockHash>) -> Result<Option<PauseReason<AssetId>>>;
 }
 
 /// A struct that implements the [`AssetIndexApi`].
@@ -35,38 +97,248 @@ impl<C, B> AssetIndexBackend<C, B> {
 	}
 }
 
+impl<C, Block> AssetIndexBackend<C, Block>
+where
+	Block: BlockT,
+	C: HeaderBackend<Block>,
+{
+	/// Returns the block for the given hash or the best block, if no hash was supplied.
+	fn block_id(&self, at: Option<<Block as BlockT>::Hash>) -> BlockId<Block> {
+		BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		))
+	}
+}
+
+impl<C, Block> AssetIndexBackend<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block>,
+{
+	/// Ensures the runtime at the given block implements at least the given version of the
+	/// [`AssetIndexRuntimeApi`], runtimes before `API_VERSION_2` only provide `get_nav`.
+	fn ensure_api_version<AccountId, AssetId, Balance, BlockNumber>(
+		&self,
+		at: &BlockId<Block>,
+		version: u32,
+	) -> Result<()>
+	where
+		C::Api: AssetIndexRuntimeApi<Block, AccountId, AssetId, Balance, BlockNumber>,
+		AccountId: Codec,
+		AssetId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		let api_version = self
+			.client
+			.runtime_api()
+			.api_version::<dyn AssetIndexRuntimeApi<Block, AccountId, AssetId, Balance, BlockNumber>>(at)
+			.map_err(|e| runtime_error("Unable to get the asset index runtime api version.", e))?;
+		match api_version {
+			Some(api_version) if api_version >= version => Ok(()),
+			api_version => Err(RpcError {
+				code: ErrorCode::ServerError(Error::UnsupportedApiVersion.into()),
+				message: format!("The runtime does not implement version {} of the asset index runtime api.", version),
+				data: Some(format!("{:?}", api_version).into()),
+			}),
+		}
+	}
+}
+
 pub enum Error {
 	RuntimeError,
+	DispatchError,
+	UnsupportedApiVersion,
 }
 
 impl From<Error> for i64 {
 	fn from(e: Error) -> i64 {
 		match e {
 			Error::RuntimeError => 1,
+			Error::DispatchError => 2,
+			Error::UnsupportedApiVersion => 3,
 		}
 	}
 }
 
-impl<C, Block, AccountId, AssetId, Balance> AssetIndexApi<<Block as BlockT>::Hash, AccountId, AssetId, Balance>
-	for AssetIndexBackend<C, Block>
+/// Converts a runtime api error into an rpc error
+fn runtime_error(message: &str, e: impl std::fmt::Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(Error::RuntimeError.into()),
+		message: message.into(),
+		data: Some(format!("{:?}", e).into()),
+	}
+}
+
+/// Converts an error that was returned by the runtime call into an rpc error
+fn dispatch_error(message: &str, e: DispatchError) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(Error::DispatchError.into()),
+		message: message.into(),
+		data: Some(format!("{:?}", e).into()),
+	}
+}
+
+impl<C, Block, AccountId, AssetId, Balance, BlockNumber>
+	AssetIndexApi<<Block as BlockT>::Hash, AccountId, AssetId, Balance, BlockNumber> for AssetIndexBackend<C, Block>
 where
 	Block: BlockT,
 	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
-	C::Api: AssetIndexRuntimeApi<Block, AccountId, AssetId, Balance>,
+	C::Api: AssetIndexRuntimeApi<Block, AccountId, AssetId, Balance, BlockNumber>,
 	AccountId: Codec,
 	AssetId: Codec,
 	Balance: Codec,
+	BlockNumber: Codec,
 {
 	fn get_nav(&self, at: Option<<Block as BlockT>::Hash>) -> Result<Ratio> {
-		let api = self.client.runtime_api();
-		let at = BlockId::hash(at.unwrap_or(
-			// If the block hash is not supplied assume the best block.
-			self.client.info().best_hash,
-		));
-		api.get_nav(&at).map_err(|e| RpcError {
-			code: ErrorCode::ServerError(Error::RuntimeError.into()),
-			message: "Unable to get current NAV.".into(),
-			data: Some(format!("{:?}", e).into()),
-		})
+		let at = self.block_id(at);
+		self.client.runtime_api().get_nav(&at).map_err(|e| runtime_error("Unable to get current NAV.", e))
+	}
+
+	fn get_liquid_nav(&self, at: Option<<Block as BlockT>::Hash>) -> Result<Ratio> {
+		let at = self.block_id(at);
+		self.ensure_api_version::<AccountId, AssetId, Balance, BlockNumber>(&at, API_VERSION_2)?;
+		self.client.runtime_api().get_liquid_nav(&at).map_err(|e| runtime_error("Unable to get current liquid NAV.", e))
+	}
+
+	fn get_saft_nav(&self, at: Option<<Block as BlockT>::Hash>) -> Result<Ratio> {
+		let at = self.block_id(at);
+		self.ensure_api_version::<AccountId, AssetId, Balance, BlockNumber>(&at, API_VERSION_2)?;
+		self.client.runtime_api().get_saft_nav(&at).map_err(|e| runtime_error("Unable to get current SAFT NAV.", e))
+	}
+
+	fn get_asset_proportions(&self, at: Option<<Block as BlockT>::Hash>) -> Result<AssetProportions<AssetId>> {
+		let at = self.block_id(at);
+		self.ensure_api_version::<AccountId, AssetId, Balance, BlockNumber>(&at, API_VERSION_2)?;
+		self.client
+			.runtime_api()
+			.get_asset_proportions(&at)
+			.map_err(|e| runtime_error("Unable to get asset proportions.", e))?
+			.map_err(|e| dispatch_error("Failed to calculate asset proportions.", e))
+	}
+
+	fn get_index_token_equivalent(
+		&self,
+		asset: AssetId,
+		units: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Balance> {
+		let at = self.block_id(at);
+		self.ensure_api_version::<AccountId, AssetId, Balance, BlockNumber>(&at, API_VERSION_2)?;
+		self.client
+			.runtime_api()
+			.get_index_token_equivalent(&at, asset, units)
+			.map_err(|e| runtime_error("Unable to get index token equivalent.", e))?
+			.map_err(|e| dispatch_error("Failed to calculate index token equivalent.", e))
+	}
+
+	fn get_asset_equivalent(
+		&self,
+		index_tokens: Balance,
+		asset: AssetId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Balance> {
+		let at = self.block_id(at);
+		self.ensure_api_version::<AccountId, AssetId, Balance, BlockNumber>(&at, API_VERSION_2)?;
+		self.client
+			.runtime_api()
+			.get_asset_equivalent(&at, index_tokens, asset)
+			.map_err(|e| runtime_error("Unable to get asset equivalent.", e))?
+			.map_err(|e| dispatch_error("Failed to calculate asset equivalent.", e))
+	}
+
+	fn get_deposits(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<(Balance, BlockNumber)>> {
+		let at = self.block_id(at);
+		self.ensure_api_version::<AccountId, AssetId, Balance, BlockNumber>(&at, API_VERSION_2)?;
+		self.client.runtime_api().get_deposits(&at, account).map_err(|e| runtime_error("Unable to get deposits.", e))
+	}
+
+	fn get_pending_withdrawals(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<PendingRedemption<AssetId, Balance, BlockNumber>>> {
+		let at = self.block_id(at);
+		self.ensure_api_version::<AccountId, AssetId, Balance, BlockNumber>(&at, API_VERSION_2)?;
+		self.client
+			.runtime_api()
+			.get_pending_withdrawals(&at, account)
+			.map_err(|e| runtime_error("Unable to get pending withdrawals.", e))
+	}
+
+	fn get_locked_index_tokens(&self, account: AccountId, at: Option<<Block as BlockT>::Hash>) -> Result<Balance> {
+		let at = self.block_id(at);
+		self.ensure_api_version::<AccountId, AssetId, Balance, BlockNumber>(&at, API_VERSION_2)?;
+		self.client
+			.runtime_api()
+			.get_locked_index_tokens(&at, account)
+			.map_err(|e| runtime_error("Unable to get locked index tokens.", e))
+	}
+
+	fn quote_withdraw(
+		&self,
+		account: AccountId,
+		amount: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<WithdrawalQuote<AssetId, Balance, BlockNumber>> {
+		let at = self.block_id(at);
+		self.ensure_api_version::<AccountId, AssetId, Balance, BlockNumber>(&at, API_VERSION_2)?;
+		self.client
+			.runtime_api()
+			.quote_withdraw(&at, account, amount)
+			.map_err(|e| runtime_error("Unable to quote withdrawal.", e))?
+			.map_err(|e| dispatch_error("Failed to quote withdrawal.", e))
+	}
+
+	fn quote_withdraw_to_asset(
+		&self,
+		account: AccountId,
+		amount: Balance,
+		asset: AssetId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<WithdrawalQuote<AssetId, Balance, BlockNumber>> {
+		let at = self.block_id(at);
+		self.ensure_api_version::<AccountId, AssetId, Balance, BlockNumber>(&at, API_VERSION_2)?;
+		self.client
+			.runtime_api()
+			.quote_withdraw_to_asset(&at, account, amount, asset)
+			.map_err(|e| runtime_error("Unable to quote withdrawal.", e))?
+			.map_err(|e| dispatch_error("Failed to quote withdrawal.", e))
+	}
+
+	fn get_rebalance_plan(&self, at: Option<<Block as BlockT>::Hash>) -> Result<RebalancePlan<AssetId, Balance>> {
+		let at = self.block_id(at);
+		self.ensure_api_version::<AccountId, AssetId, Balance, BlockNumber>(&at, API_VERSION_2)?;
+		self.client
+			.runtime_api()
+			.get_rebalance_plan(&at)
+			.map_err(|e| runtime_error("Unable to get rebalance plan.", e))?
+			.map_err(|e| dispatch_error("Failed to compute rebalance plan.", e))
+	}
+
+	fn get_accrued_management_fees(&self, at: Option<<Block as BlockT>::Hash>) -> Result<Balance> {
+		let at = self.block_id(at);
+		self.ensure_api_version::<AccountId, AssetId, Balance, BlockNumber>(&at, API_VERSION_2)?;
+		self.client
+			.runtime_api()
+			.get_accrued_management_fees(&at)
+			.map_err(|e| runtime_error("Unable to get accrued management fees.", e))
+	}
+
+	fn get_nav_snapshots(&self, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<NavSnapshot<BlockNumber>>> {
+		let at = self.block_id(at);
+		self.ensure_api_version::<AccountId, AssetId, Balance, BlockNumber>(&at, API_VERSION_2)?;
+		self.client.runtime_api().get_nav_snapshots(&at).map_err(|e| runtime_error("Unable to get NAV snapshots.", e))
+	}
+
+	fn get_pause_reason(&self, at: Option<<Block as BlockT>::Hash>) -> Result<Option<PauseReason<AssetId>>> {
+		let at = self.block_id(at);
+		self.ensure_api_version::<AccountId, AssetId, Balance, BlockNumber>(&at, API_VERSION_2)?;
+		self.client.runtime_api().get_pause_reason(&at).map_err(|e| runtime_error("Unable to get pause reason.", e))
 	}
 }
@@ -8,24 +8,24 @@ use frame_support::{
 	assert_ok,
 	dispatch::UnfilteredDispatchable,
 	sp_runtime::{
-		traits::{AccountIdConversion, Bounded, One},
+		traits::{AccountIdConversion, Bounded, One, Zero},
 		FixedPointNumber,
 	},
-	traits::{EnsureOrigin, Get},
+	traits::{Currency as _, EnsureOrigin, Get},
 };
 use orml_traits::MultiCurrency;
 use pallet_price_feed::{PriceFeed, PriceFeedBenchmarks};
 use primitives::{
 	fee::{FeeRate, RedemptionFeeRange},
 	traits::NavProvider,
-	AssetAvailability,
+	AssetAvailability, Ratio,
 };
 use xcm::v1::MultiLocation;
 
 use crate::Pallet as AssetIndex;
 
 use super::*;
-use crate::types::DepositRange;
+use crate::types::{DepositRange, RebalancePlan};
 
 benchmarks! {
 	add_asset {
@@ -104,6 +104,11 @@ benchmarks! {
 			origin.clone(),
 			tokens,
 		));
+
+		// advance the block number so that the withdrawal matures
+		<frame_system::Pallet<T>>::set_block_number(
+			<frame_system::Pallet<T>>::block_number() + T::WithdrawalPeriod::get(),
+		);
 		let call = Call::<T>::complete_withdraw{};
 	}: { call.dispatch_bypass_filter(origin)? } verify {
 		assert_eq!(pallet::PendingWithdrawals::<T>::get(&origin_account_id), None);
@@ -321,6 +326,169 @@ benchmarks! {
 	} verify {
 		assert_eq!(pallet::RedemptionFee::<T>::get(), range);
 	}
+
+	set_target_weights {
+		let n in 1 .. T::MaxTargets::get();
+		let origin = T::AdminOrigin::successful_origin();
+		let mut targets = vec![];
+		for i in 0 .. n {
+			let asset_id: T::AssetId = T::try_convert((i + 2) as u8).unwrap();
+			assert_ok!(AssetIndex::<T>::register_asset(origin.clone(), asset_id, AssetAvailability::Saft));
+			targets.push((asset_id, Ratio::zero()));
+		}
+		targets[0].1 = Ratio::one();
+		let call = Call::<T>::set_target_weights { targets: targets.clone() };
+	}: { call.dispatch_bypass_filter(origin)? } verify {
+		assert_eq!(pallet::TargetWeights::<T>::get(targets[0].0), Some(Ratio::one()));
+	}
+
+	set_rebalance_threshold {
+		let threshold = Ratio::saturating_from_rational(1, 100);
+		let call = Call::<T>::set_rebalance_threshold { threshold };
+	}: {
+		call.dispatch_bypass_filter(T::AdminOrigin::successful_origin())?
+	} verify {
+		assert_eq!(pallet::RebalanceThreshold::<T>::get(), threshold);
+	}
+
+	announce_rebalance {
+		let asset_id: T::AssetId = T::try_convert(2u8).unwrap();
+		let saft_id: T::AssetId = T::try_convert(3u8).unwrap();
+		let origin = T::AdminOrigin::successful_origin();
+		let origin_account_id = T::AdminOrigin::ensure_origin(origin.clone()).unwrap();
+		let units = 10_000u32.into();
+		let tokens = 50_000u32.into();
+
+		T::PriceFeedBenchmarks::create_feed(origin_account_id.clone(), asset_id).unwrap();
+		assert_ok!(AssetIndex::<T>::register_asset(
+			origin.clone(),
+			asset_id,
+			AssetAvailability::Liquid(MultiLocation::default())
+		));
+		assert_ok!(AssetIndex::<T>::register_asset(origin.clone(), saft_id, AssetAvailability::Saft));
+		T::Currency::deposit(asset_id, &origin_account_id, units)?;
+		assert_ok!(AssetIndex::<T>::add_asset(origin.clone(), asset_id, units, tokens));
+
+		// the liquid asset is expected to be sold off entirely
+		assert_ok!(AssetIndex::<T>::set_target_weights(
+			origin.clone(),
+			vec![(asset_id, Ratio::zero()), (saft_id, Ratio::one())]
+		));
+		let call = Call::<T>::announce_rebalance {};
+	}: { call.dispatch_bypass_filter(origin)? } verify {
+		assert!(AssetIndex::<T>::pending_rebalance().is_some());
+	}
+
+	complete_rebalance {
+		let asset_id: T::AssetId = T::try_convert(2u8).unwrap();
+		let origin = T::AdminOrigin::successful_origin();
+		pallet::PendingRebalance::<T>::put(RebalancePlan {
+			sells: vec![(asset_id, 1_000u32.into())],
+			buys: vec![(asset_id, 1_000u32.into())],
+		});
+		let call = Call::<T>::complete_rebalance {};
+	}: { call.dispatch_bypass_filter(origin)? } verify {
+		assert!(AssetIndex::<T>::pending_rebalance().is_none());
+	}
+
+	set_management_fee_rate {
+		let rate = FeeRate { numerator: 1, denominator: 100 };
+		let call = Call::<T>::set_management_fee_rate { rate };
+	}: {
+		call.dispatch_bypass_filter(T::AdminOrigin::successful_origin())?
+	} verify {
+		assert_eq!(pallet::ManagementFeeRate::<T>::get(), Some(rate));
+	}
+
+	accrue_management_fee {
+		let origin = T::AdminOrigin::successful_origin();
+		let origin_account_id = T::AdminOrigin::ensure_origin(origin.clone()).unwrap();
+		T::IndexToken::deposit_creating(&origin_account_id, 1_000_000_000u32.into());
+		assert_ok!(AssetIndex::<T>::set_management_fee_rate(origin, FeeRate { numerator: 1, denominator: 100 }));
+
+		let now = frame_system::Pallet::<T>::block_number() + T::BlocksPerYear::get();
+	}: {
+		AssetIndex::<T>::accrue_management_fee(now);
+	} verify {
+		assert!(!AssetIndex::<T>::accrued_management_fees().is_zero());
+	}
+
+	record_nav_snapshot {
+		let asset_id: T::AssetId = T::try_convert(2u8).unwrap();
+		let origin = T::AdminOrigin::successful_origin();
+		let origin_account_id = T::AdminOrigin::ensure_origin(origin.clone()).unwrap();
+		let units = 10_000u32.into();
+		let tokens = 50_000u32.into();
+
+		T::PriceFeedBenchmarks::create_feed(origin_account_id.clone(), asset_id).unwrap();
+		assert_ok!(AssetIndex::<T>::register_asset(
+			origin.clone(),
+			asset_id,
+			AssetAvailability::Liquid(MultiLocation::default())
+		));
+		T::Currency::deposit(asset_id, &origin_account_id, units)?;
+		assert_ok!(AssetIndex::<T>::add_asset(origin, asset_id, units, tokens));
+
+		// fill up the buffer so that the oldest snapshot gets replaced
+		let now = frame_system::Pallet::<T>::block_number();
+		for _ in 0 .. T::MaxNavSnapshots::get() {
+			assert_ok!(AssetIndex::<T>::record_nav_snapshot(now));
+		}
+	}: {
+		AssetIndex::<T>::record_nav_snapshot(now)?;
+	} verify {
+		assert_eq!(AssetIndex::<T>::nav_snapshots().len() as u32, T::MaxNavSnapshots::get());
+	}
+
+	check_circuit_breaker {
+		let n in 1 .. T::MaxPriceFeedChecks::get();
+		let origin = T::AdminOrigin::successful_origin();
+		let origin_account_id = T::AdminOrigin::ensure_origin(origin.clone()).unwrap();
+		let units = 10_000u32.into();
+		let tokens = 50_000u32.into();
+
+		// all checked price feeds are up to date
+		for i in 0 .. n {
+			let asset_id: T::AssetId = T::try_convert((i + 2) as u8).unwrap();
+			T::PriceFeedBenchmarks::create_feed(origin_account_id.clone(), asset_id).unwrap();
+			assert_ok!(AssetIndex::<T>::register_asset(
+				origin.clone(),
+				asset_id,
+				AssetAvailability::Liquid(MultiLocation::default())
+			));
+			T::Currency::deposit(asset_id, &origin_account_id, units)?;
+			assert_ok!(AssetIndex::<T>::add_asset(origin.clone(), asset_id, units, tokens));
+		}
+
+		// the current window is over and a new one starts
+		let now = frame_system::Pallet::<T>::block_number();
+		let nav = AssetIndex::<T>::nav().unwrap();
+		pallet::NavReference::<T>::put((now, nav));
+		let now = now + T::NavChangeWindow::get();
+	}: {
+		AssetIndex::<T>::check_circuit_breaker(now, Some(nav));
+	} verify {
+		assert_eq!(pallet::Paused::<T>::get(), None);
+		assert_eq!(pallet::NavReference::<T>::get().map(|(start, _)| start), Some(now));
+	}
+
+	pause {
+		let call = Call::<T>::pause {};
+	}: {
+		call.dispatch_bypass_filter(T::AdminOrigin::successful_origin())?
+	} verify {
+		assert_eq!(pallet::Paused::<T>::get(), Some(types::PauseReason::Manual));
+	}
+
+	resume {
+		let asset_id: T::AssetId = T::try_convert(2u8).unwrap();
+		pallet::Paused::<T>::put(types::PauseReason::StalePriceFeed(asset_id));
+		let call = Call::<T>::resume {};
+	}: {
+		call.dispatch_bypass_filter(T::AdminOrigin::successful_origin())?
+	} verify {
+		assert_eq!(pallet::Paused::<T>::get(), None);
+	}
 }
 
 #[cfg(test)]
@@ -393,4 +561,74 @@ mod tests {
 			assert_ok!(Pallet::<Test>::test_benchmark_withdraw());
 		});
 	}
+
+	#[test]
+	fn set_management_fee_rate() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_set_management_fee_rate());
+		});
+	}
+
+	#[test]
+	fn accrue_management_fee() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_accrue_management_fee());
+		});
+	}
+
+	#[test]
+	fn record_nav_snapshot() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_record_nav_snapshot());
+		});
+	}
+
+	#[test]
+	fn check_circuit_breaker() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_check_circuit_breaker());
+		});
+	}
+
+	#[test]
+	fn pause() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_pause());
+		});
+	}
+
+	#[test]
+	fn resume() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_resume());
+		});
+	}
+
+	#[test]
+	fn set_target_weights() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_set_target_weights());
+		});
+	}
+
+	#[test]
+	fn set_rebalance_threshold() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_set_rebalance_threshold());
+		});
+	}
+
+	#[test]
+	fn announce_rebalance() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_announce_rebalance());
+		});
+	}
+
+	#[test]
+	fn complete_rebalance() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_complete_rebalance());
+		});
+	}
 }
@@ -37,8 +37,11 @@ pub mod pallet {
 	use frame_support::{
 		pallet_prelude::*,
 		sp_runtime::{
-			traits::{AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, CheckedDiv, CheckedSub, Saturating, Zero},
-			ArithmeticError, FixedPointNumber,
+			traits::{
+				AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, CheckedDiv, CheckedSub, One, Saturating,
+				UniqueSaturatedInto, Zero,
+			},
+			ArithmeticError, FixedPointNumber, Permill,
 		},
 		sp_std::{convert::TryInto, prelude::*, result::Result},
 		traits::{Currency, ExistenceRequirement, Get, LockIdentifier, LockableCurrency, WithdrawReasons},
@@ -49,7 +52,7 @@ pub mod pallet {
 	use sp_core::U256;
 	use xcm::v1::MultiLocation;
 
-	use pallet_price_feed::{AssetPricePair, Price, PriceFeed};
+	use pallet_price_feed::{AssetPricePair, Price, PriceFeed, PriceFeedAge};
 	use primitives::{
 		fee::{BaseFee, FeeRate, RedemptionFeeRange},
 		traits::{AssetRecorder, MultiAssetRegistry, NavProvider, RemoteAssetManager, SaftRegistry},
@@ -58,7 +61,10 @@ pub mod pallet {
 
 	use crate::{
 		traits::LockupPeriodRange,
-		types::{AssetMetadata, AssetRedemption, AssetWithdrawal, DepositRange, IndexTokenLock, PendingRedemption},
+		types::{
+			AssetMetadata, AssetRedemption, AssetWithdrawal, DepositRange, IndexTokenLock, NavSnapshot, PauseReason,
+			PendingRedemption, RebalancePlan, WithdrawalQuote,
+		},
 	};
 	use primitives::traits::MaybeAssetIdConvert;
 
@@ -123,7 +129,7 @@ pub mod pallet {
 		type Currency: MultiReservableCurrency<Self::AccountId, CurrencyId = Self::AssetId, Balance = Self::Balance>;
 
 		/// The types that provides the necessary asset price pairs
-		type PriceFeed: PriceFeed<Self::AssetId>;
+		type PriceFeed: PriceFeed<Self::AssetId> + PriceFeedAge<Self::AssetId, Self::BlockNumber>;
 
 		#[cfg(feature = "runtime-benchmarks")]
 		/// The type that provides benchmark features of pallet_price_feed
@@ -136,6 +142,51 @@ pub mod pallet {
 		#[pallet::constant]
 		type BaseWithdrawalFee: Get<FeeRate>;
 
+		/// The additional fee that applies when a withdrawal is redeemed into a single asset
+		#[pallet::constant]
+		type SingleAssetWithdrawalFee: Get<FeeRate>;
+
+		/// The maximum share of the index' free balance of an asset that a single asset
+		/// withdrawal can redeem
+		#[pallet::constant]
+		type SingleAssetRedemptionCap: Get<Permill>;
+		/// The maximum number of assets a single deposit or withdrawal can list
+		#[pallet::constant]
+		type MaxAssetsPerCall: Get<u32>;
+		/// The maximum number of assets `set_target_weights` can assign a target weight
+		#[pallet::constant]
+		type MaxTargets: Get<u32>;
+		/// The number of blocks per year, used to derive the per block share of the annual
+		/// management fee
+		#[pallet::constant]
+		type BlocksPerYear: Get<Self::BlockNumber>;
+		/// The number of blocks between two recorded `NAV` snapshots
+		#[pallet::constant]
+		type NavSnapshotInterval: Get<Self::BlockNumber>;
+		/// The maximum number of `NAV` snapshots to keep, once reached the oldest snapshot is
+		/// replaced
+		#[pallet::constant]
+		type MaxNavSnapshots: Get<u32>;
+		/// The maximum change of the `NAV` within `NavChangeWindow` blocks before deposits and
+		/// withdrawals are paused
+		#[pallet::constant]
+		type MaxNavChange: Get<Permill>;
+		/// The number of blocks over which the change of the `NAV` is measured, the `NAV` is
+		/// compared every `NavSnapshotInterval` blocks
+		#[pallet::constant]
+		type NavChangeWindow: Get<Self::BlockNumber>;
+		/// The maximum number of blocks since the last update of a liquid asset's price feed
+		/// before deposits and withdrawals are paused
+		#[pallet::constant]
+		type MaxPriceAge: Get<Self::BlockNumber>;
+		/// The maximum number of assets whose price feed age is checked per block, the circuit
+		/// breaker cycles through all assets over multiple blocks
+		#[pallet::constant]
+		type MaxPriceFeedChecks: Get<u32>;
+		/// The number of blocks after `resume` during which the circuit breaker is not checked
+		#[pallet::constant]
+		type ResumeGracePeriod: Get<Self::BlockNumber>;
+
 		/// The treasury's pallet id, used for deriving its sovereign account
 		/// ID.
 		#[pallet::constant]
@@ -193,6 +244,11 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The raw storage key of the last account in `PendingWithdrawals` whose matured withdrawals
+	/// were completed in `on_idle`, the next block continues with the account after it.
+	#[pallet::storage]
+	pub type WithdrawalCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
 	/// Tracks the locks of the minted index token that are locked up until
 	/// their `LockupPeriod` is over  (AccountId) -> Vec<IndexTokenLockInfo>
 	#[pallet::storage]
@@ -200,6 +256,72 @@ pub mod pallet {
 	pub type IndexTokenLocks<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, Vec<IndexTokenLock<T::BlockNumber, T::Balance>>, ValueQuery>;
 
+	/// The share of the index' total value each asset should make up.
+	///
+	/// (AssetId) -> Ratio
+	#[pallet::storage]
+	#[pallet::getter(fn target_weight)]
+	pub type TargetWeights<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, Ratio, OptionQuery>;
+
+	/// The minimum deviation of an asset's proportion from its target weight that requires
+	/// rebalancing.
+	#[pallet::storage]
+	#[pallet::getter(fn rebalance_threshold)]
+	pub type RebalanceThreshold<T: Config> = StorageValue<_, Ratio, ValueQuery>;
+
+	/// The rebalance that was announced but whose trades were not completed yet.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_rebalance)]
+	pub type PendingRebalance<T: Config> = StorageValue<_, RebalancePlan<T::AssetId, T::Balance>, OptionQuery>;
+
+	/// The annual management fee, charged pro-rata to the index token issuance.
+	///
+	/// If this is not set, no management fee accrues.
+	#[pallet::storage]
+	#[pallet::getter(fn management_fee_rate)]
+	pub type ManagementFeeRate<T: Config> = StorageValue<_, FeeRate, OptionQuery>;
+
+	/// The block at which the management fee was last minted to the treasury.
+	#[pallet::storage]
+	pub type LastManagementFeeAccrual<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	/// The total amount of index tokens minted to the treasury as management fee.
+	#[pallet::storage]
+	#[pallet::getter(fn accrued_management_fees)]
+	pub type AccruedManagementFees<T: Config> = StorageValue<_, T::Balance, ValueQuery>;
+
+	/// The recorded `NAV` snapshots, stored as a ring buffer of `MaxNavSnapshots` slots.
+	///
+	/// A new snapshot is taken every `NavSnapshotInterval` blocks and overwrites the oldest one
+	/// once all slots are taken.
+	///
+	/// (Slot) -> NavSnapshot
+	#[pallet::storage]
+	pub type NavSnapshots<T: Config> = StorageMap<_, Twox64Concat, u32, NavSnapshot<T::BlockNumber>, OptionQuery>;
+
+	/// The slot of `NavSnapshots` the next snapshot is written to.
+	#[pallet::storage]
+	pub type NavSnapshotHead<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The reason why deposits and withdrawals are currently paused, if they are.
+	#[pallet::storage]
+	#[pallet::getter(fn pause_reason)]
+	pub type Paused<T: Config> = StorageValue<_, PauseReason<T::AssetId>, OptionQuery>;
+
+	/// The block at which the current `NavChangeWindow` started together with the `NAV` at that
+	/// block.
+	#[pallet::storage]
+	pub type NavReference<T: Config> = StorageValue<_, (T::BlockNumber, Price), OptionQuery>;
+
+	/// The raw storage key of the last asset in `Assets` whose price feed age was checked by the
+	/// circuit breaker.
+	#[pallet::storage]
+	pub type PriceFeedCheckCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
+	/// The block until which the circuit breaker is not checked after the index was resumed.
+	#[pallet::storage]
+	pub type GracePeriodEnd<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
 	/// Store a duration (in blocks) of the lockup period
 	#[pallet::storage]
 	pub type LockupPeriod<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
@@ -211,6 +333,13 @@ pub mod pallet {
 	#[pallet::getter(fn locked_index_tokens)]
 	pub type LockedIndexToken<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::Balance, ValueQuery>;
 
+	/// Tracks the units of a liquid asset in the treasury that are still secured by a SAFT.
+	/// These are part of the treasury's reserved balance, besides the reserves of pending
+	/// withdrawals.
+	///  (AssetId) -> Balance
+	#[pallet::storage]
+	pub type LockedSaftUnits<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, T::Balance, ValueQuery>;
+
 	/// The range of the index token equivalent a deposit must be in in order to be allowed.
 	///
 	/// A valid deposit lies within `[deposit_bounds.minimum, deposit_bounds.maximum]`.
@@ -293,6 +422,9 @@ pub mod pallet {
 		/// A new deposit of an asset into the index has been performed
 		/// \[AssetId, AssetUnits, Account, PINTPayout\]
 		Deposited(T::AssetId, T::Balance, AccountIdFor<T>, T::Balance),
+		/// A deposit of multiple assets into the index has been performed
+		/// \[Assets, Account, PINTPayout\]
+		BasketDeposited(Vec<(T::AssetId, T::Balance)>, AccountIdFor<T>, T::Balance),
 		/// Started the withdrawal process
 		/// \[Account, PINTAmount\]
 		WithdrawalInitiated(AccountIdFor<T>, T::Balance),
@@ -311,6 +443,32 @@ pub mod pallet {
 		NewLockupPeriod(T::BlockNumber),
 		/// RedemptionFeeRange has been updated
 		NewRedemptionFeeRange(RedemptionFeeRange<T::BlockNumber>),
+		/// The target weights of the index were updated
+		/// \[TargetWeights\]
+		TargetWeightsUpdated(Vec<(T::AssetId, Ratio)>),
+		/// The drift threshold for rebalancing was updated
+		/// \[Threshold\]
+		RebalanceThresholdUpdated(Ratio),
+		/// The index drifted from its target weights and needs to be rebalanced
+		/// \[RebalancePlan\]
+		RebalanceAnnounced(RebalancePlan<T::AssetId, T::Balance>),
+		/// The trades of the pending rebalance were completed
+		/// \[RebalancePlan\]
+		RebalanceCompleted(RebalancePlan<T::AssetId, T::Balance>),
+		/// The annual management fee was updated
+		/// \[FeeRate\]
+		ManagementFeeRateUpdated(FeeRate),
+		/// Index tokens were minted to the treasury as management fee
+		/// \[IndexTokens\]
+		ManagementFeeAccrued(T::Balance),
+		/// A snapshot of the index token's NAV was recorded
+		/// \[Block, NAV\]
+		NavSnapshotRecorded(T::BlockNumber, Price),
+		/// All deposits and withdrawals were paused, by the circuit breaker or the admin origin
+		/// \[Reason\]
+		Paused(PauseReason<T::AssetId>),
+		/// Deposits and withdrawals were resumed
+		Resumed,
 	}
 
 	#[pallet::error]
@@ -359,10 +517,70 @@ pub mod pallet {
 		DepositAmountBelowMinimum,
 		/// The deposited amount exceeded the cap allowed.
 		DepositExceedsMaximum,
+		/// Thrown if the index tokens minted by a deposit or the assets paid out by a withdrawal
+		/// are less than the minimum requested by the caller.
+		OutputBelowMinimum,
+		/// Thrown if a single asset withdrawal would redeem more than the allowed share of the
+		/// index' free balance of the asset.
+		SingleAssetRedemptionCapExceeded,
+		/// Thrown if the target weights do not add up to exactly one.
+		InvalidTargetWeights,
+		/// Thrown if a rebalance was requested but all assets are within the drift threshold.
+		NoRebalanceRequired,
+		/// Thrown if the management fee rate is not a valid fraction of at most 100%.
+		InvalidManagementFeeRate,
+		/// Thrown if deposits and withdrawals are paused by the circuit breaker.
+		IndexPaused,
+		/// Thrown if the index should be resumed but is not paused.
+		NotPaused,
+		/// Thrown if more SAFT units should be unlocked than are currently locked.
+		InsufficientLockedSAFTUnits,
+		/// Thrown if more than `MaxAssetsPerCall` assets were listed.
+		TooManyAssets,
+		/// Thrown if a basket deposit lists no assets.
+		EmptyBasket,
+		/// Thrown if an asset is listed more than once.
+		DuplicateAsset,
+		/// Thrown if more than `MaxTargets` target weights were provided.
+		TooManyTargets,
+		/// Thrown if a rebalance should be announced while the previous one is still pending.
+		RebalancePending,
+		/// Thrown if a rebalance should be completed but none was announced.
+		NoPendingRebalance,
+		/// Thrown if the index should be paused but is already paused.
+		AlreadyPaused,
 	}
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Mints the management fee that accrued since the last block to the treasury, records a
+		/// `NAV` snapshot every `NavSnapshotInterval` blocks and trips the circuit breaker on
+		/// stale price feeds or abnormal moves of the snapshot `NAV`.
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			Self::accrue_management_fee(now);
+			let mut weight = T::WeightInfo::accrue_management_fee();
+
+			let interval = T::NavSnapshotInterval::get();
+			let mut nav = None;
+			if !interval.is_zero() && (now % interval).is_zero() {
+				// the NAV can not be determined if a price feed is unavailable, skip the snapshot then
+				nav = Self::record_nav_snapshot(now).ok().map(|snapshot| snapshot.nav);
+				weight = weight.saturating_add(T::WeightInfo::record_nav_snapshot());
+			}
+
+			weight = weight.saturating_add(T::DbWeight::get().reads(2));
+			if !Paused::<T>::exists() && now >= GracePeriodEnd::<T>::get() {
+				Self::check_circuit_breaker(now, nav);
+				weight = weight.saturating_add(T::WeightInfo::check_circuit_breaker(T::MaxPriceFeedChecks::get()));
+			}
+			weight
+		}
+
+		/// Completes matured pending withdrawals with the weight that is left in the block.
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::complete_matured_withdrawals(now, remaining_weight)
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -512,6 +730,149 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Sets the share of the index' total value each asset should make up.
+		///
+		/// Only callable by the admin origin
+		///
+		/// This replaces all existing target weights. Liquid assets without a target weight are
+		/// expected to be sold off entirely.
+		///
+		/// Parameters:
+		/// - `targets`: At most `MaxTargets` distinct registered assets together with their target
+		///   weight, these must add up to exactly one.
+		#[pallet::weight(T::WeightInfo::set_target_weights(T::MaxTargets::get()))]
+		pub fn set_target_weights(origin: OriginFor<T>, targets: Vec<(T::AssetId, Ratio)>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(targets.len() as u32 <= T::MaxTargets::get(), Error::<T>::TooManyTargets);
+
+			let mut total = Ratio::zero();
+			for (idx, (asset, weight)) in targets.iter().enumerate() {
+				ensure!(Assets::<T>::contains_key(asset), Error::<T>::UnsupportedAsset);
+				ensure!(targets[..idx].iter().all(|(other, _)| other != asset), Error::<T>::DuplicateAsset);
+				total = total.checked_add(weight).ok_or(Error::<T>::InvalidTargetWeights)?;
+			}
+			ensure!(total == Ratio::one(), Error::<T>::InvalidTargetWeights);
+
+			// the previous targets were bounded by `MaxTargets` as well
+			TargetWeights::<T>::remove_all(Some(T::MaxTargets::get()));
+			for (asset, weight) in targets.iter() {
+				TargetWeights::<T>::insert(asset, weight);
+			}
+			Self::deposit_event(Event::<T>::TargetWeightsUpdated(targets));
+			Ok(())
+		}
+
+		/// Updates the minimum deviation from the target weights that requires rebalancing
+		///
+		/// Only callable by the admin origin
+		///
+		/// Parameters:
+		/// - `threshold`: the absolute drift of an asset's proportion from its target weight
+		#[pallet::weight(T::WeightInfo::set_rebalance_threshold())]
+		pub fn set_rebalance_threshold(origin: OriginFor<T>, threshold: Ratio) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			RebalanceThreshold::<T>::put(threshold);
+			Self::deposit_event(Event::<T>::RebalanceThresholdUpdated(threshold));
+			Ok(())
+		}
+
+		/// Announces the trades required to move the index back to its target weights.
+		///
+		/// Only callable by the admin origin
+		///
+		/// This computes the current `RebalancePlan`, queues it as `PendingRebalance` and
+		/// announces the withdrawal of all the assets to sell to the `RemoteAssetManager`, so that
+		/// the funds are available to be traded on the asset's native location.
+		///
+		/// Only one rebalance can be pending at a time.
+		#[pallet::weight(T::WeightInfo::announce_rebalance())]
+		pub fn announce_rebalance(origin: OriginFor<T>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(!PendingRebalance::<T>::exists(), Error::<T>::RebalancePending);
+			let plan = Self::rebalance_plan()?;
+			ensure!(!plan.is_empty(), Error::<T>::NoRebalanceRequired);
+
+			for (asset, units) in plan.sells.iter() {
+				T::RemoteAssetManager::announce_withdrawal(*asset, *units);
+			}
+			PendingRebalance::<T>::put(plan.clone());
+			Self::deposit_event(Event::<T>::RebalanceAnnounced(plan));
+			Ok(())
+		}
+
+		/// Completes the pending rebalance once its trades were executed on the assets' native
+		/// locations.
+		///
+		/// Only callable by the admin origin
+		///
+		/// This removes the `PendingRebalance` and notifies the `RemoteAssetManager` about the
+		/// bought assets, so that they can be put to use on their native location.
+		#[pallet::weight(T::WeightInfo::complete_rebalance())]
+		pub fn complete_rebalance(origin: OriginFor<T>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let plan = PendingRebalance::<T>::take().ok_or(Error::<T>::NoPendingRebalance)?;
+
+			for (asset, units) in plan.buys.iter() {
+				T::RemoteAssetManager::deposit(*asset, *units);
+			}
+			Self::deposit_event(Event::<T>::RebalanceCompleted(plan));
+			Ok(())
+		}
+
+		/// Updates the annual management fee
+		///
+		/// Only callable by the admin origin
+		///
+		/// The fee accrued under the previous rate is minted to the treasury before the new rate
+		/// takes effect.
+		///
+		/// Parameters:
+		/// - `rate`: the share of the index token issuance that is minted to the treasury per year
+		#[pallet::weight(T::WeightInfo::set_management_fee_rate())]
+		pub fn set_management_fee_rate(origin: OriginFor<T>, rate: FeeRate) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(rate.denominator > 0 && rate.numerator <= rate.denominator, Error::<T>::InvalidManagementFeeRate);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			Self::accrue_management_fee(now);
+			LastManagementFeeAccrual::<T>::put(now);
+			ManagementFeeRate::<T>::put(rate);
+			Self::deposit_event(Event::<T>::ManagementFeeRateUpdated(rate));
+			Ok(())
+		}
+
+		/// Pauses all deposits and withdrawals until they are resumed.
+		///
+		/// Only callable by the admin origin, which allows the committee to pause the index with an
+		/// emergency proposal.
+		#[pallet::weight(T::WeightInfo::pause())]
+		pub fn pause(origin: OriginFor<T>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(!Paused::<T>::exists(), Error::<T>::AlreadyPaused);
+			Paused::<T>::put(PauseReason::Manual);
+			Self::deposit_event(Event::<T>::Paused(PauseReason::Manual));
+			Ok(())
+		}
+
+		/// Resumes deposits and withdrawals after they were paused by the circuit breaker or the
+		/// admin origin.
+		///
+		/// Only callable by the admin origin
+		///
+		/// The circuit breaker is not checked for the next `ResumeGracePeriod` blocks, which gives
+		/// stale price feeds time to be updated. Afterwards a new `NavChangeWindow` starts at the
+		/// current `NAV`.
+		#[pallet::weight(T::WeightInfo::resume())]
+		pub fn resume(origin: OriginFor<T>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(Paused::<T>::take().is_some(), Error::<T>::NotPaused);
+			NavReference::<T>::kill();
+			let now = frame_system::Pallet::<T>::block_number();
+			GracePeriodEnd::<T>::put(now.saturating_add(T::ResumeGracePeriod::get()));
+			Self::deposit_event(Event::<T>::Resumed);
+			Ok(())
+		}
+
 		/// Force the metadata for an asset to some value.
 		///
 		/// Origin must be ForceOrigin.
@@ -561,40 +922,91 @@ pub mod pallet {
 		#[transactional]
 		pub fn deposit(origin: OriginFor<T>, asset_id: T::AssetId, units: T::Balance) -> DispatchResult {
 			let caller = T::AdminOrigin::ensure_origin(origin)?;
-			if units.is_zero() {
+			Self::do_deposit(caller, asset_id, units, T::Balance::zero())
+		}
+
+		/// Same as `deposit`, but fails if the given units of the asset are worth less than
+		/// `min_index_tokens` at the time of execution.
+		///
+		/// This protects the caller against price movements between submitting and executing the
+		/// deposit.
+		#[pallet::weight(T::WeightInfo::deposit())]
+		#[transactional]
+		pub fn deposit_with_min_out(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			units: T::Balance,
+			min_index_tokens: T::Balance,
+		) -> DispatchResult {
+			let caller = T::AdminOrigin::ensure_origin(origin)?;
+			Self::do_deposit(caller, asset_id, units, min_index_tokens)
+		}
+
+		/// Deposits multiple liquid assets into the index at once.
+		///
+		/// All assets are priced together using the latest available price pairs before any of
+		/// them is transferred, so the combined index token equivalent is independent of the order
+		/// of the assets. The combined value must lie within the configured deposit range and is
+		/// recorded as a single deposit.
+		///
+		/// Parameters:
+		/// - `assets`: The distinct liquid assets and their units to deposit, at least one and at
+		///   most `MaxAssetsPerCall` assets
+		#[pallet::weight(T::WeightInfo::deposit().saturating_mul((assets.len() as Weight).max(1)))]
+		#[transactional]
+		pub fn deposit_basket(origin: OriginFor<T>, assets: Vec<(T::AssetId, T::Balance)>) -> DispatchResult {
+			let caller = T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_not_paused()?;
+			ensure!(!assets.is_empty(), Error::<T>::EmptyBasket);
+			ensure!(assets.len() as u32 <= T::MaxAssetsPerCall::get(), Error::<T>::TooManyAssets);
+			ensure!(
+				assets
+					.iter()
+					.enumerate()
+					.all(|(idx, (asset, _))| assets[..idx].iter().all(|(other, _)| other != asset)),
+				Error::<T>::DuplicateAsset
+			);
+
+			let assets: Vec<_> = assets.into_iter().filter(|(_, units)| !units.is_zero()).collect();
+			if assets.is_empty() {
 				return Ok(());
 			}
 
-			// native asset can't be deposited here
-			Self::ensure_not_native_asset(&asset_id)?;
-			// only liquid assets can be deposited
-			Self::ensure_liquid_asset(&asset_id)?;
-
 			// can't calculate an exchange rate if the total supply of index tokens is 0
 			if Self::index_token_issuance().is_zero() {
 				return Err(Error::<T>::InsufficientIndexTokens.into());
 			}
 
-			// the amount of index token the given units of the liquid assets are worth
-			let index_tokens = Self::index_token_equivalent(asset_id, units)?;
+			// the combined amount of index token all the given units of the liquid assets are worth
+			let index_tokens =
+				assets.iter().try_fold(T::Balance::zero(), |total, (asset_id, units)| -> Result<_, DispatchError> {
+					// native asset can't be deposited here
+					Self::ensure_not_native_asset(asset_id)?;
+					// only liquid assets can be deposited
+					Self::ensure_liquid_asset(asset_id)?;
 
-			// ensure the index token equivalent worth is within the set bounds
+					let index_tokens = Self::index_token_equivalent(*asset_id, *units)?;
+					Ok(total.checked_add(&index_tokens).ok_or(ArithmeticError::Overflow)?)
+				})?;
+
+			// ensure the combined index token equivalent worth is within the set bounds
 			Self::ensure_deposit_in_bounds(index_tokens)?;
 
-			// transfer from the caller's sovereign account into the treasury's account
-			T::Currency::transfer(asset_id, &caller, &Self::treasury_account(), units)?;
+			for (asset_id, units) in assets.iter() {
+				// transfer from the caller's sovereign account into the treasury's account
+				T::Currency::transfer(*asset_id, &caller, &Self::treasury_account(), *units)?;
+				// tell the remote asset manager that assets are available to bond
+				T::RemoteAssetManager::deposit(*asset_id, *units);
+			}
 
 			// mint index token in caller's account
 			Self::do_mint_index_token(&caller, index_tokens);
 
-			// tell the remote asset manager that assets are available to bond
-			T::RemoteAssetManager::deposit(asset_id, units);
-
 			// insert new deposit
 			Deposits::<T>::try_append(&caller, (index_tokens, frame_system::Pallet::<T>::block_number()))
 				.map_err(|_| Error::<T>::TooManyDeposits)?;
 
-			Self::deposit_event(Event::Deposited(asset_id, units, caller, index_tokens));
+			Self::deposit_event(Event::BasketDeposited(assets, caller, index_tokens));
 			Ok(())
 		}
 
@@ -612,69 +1024,44 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::withdraw())]
 		#[transactional]
 		pub fn withdraw(origin: OriginFor<T>, amount: T::Balance) -> DispatchResult {
-			let caller = T::AdminOrigin::ensure_origin(origin.clone())?;
-			ensure!(amount >= T::MinimumRedemption::get(), Error::<T>::MinimumRedemption);
-
-			// update the locks of prior deposits
-			Self::do_update_index_token_locks(&caller);
-
-			let free_balance = T::IndexToken::free_balance(&caller);
-			T::IndexToken::ensure_can_withdraw(
-				&caller,
-				amount,
-				WithdrawReasons::all(),
-				free_balance.saturating_sub(amount),
-			)?;
-
-			// amount = fees + redeem
-			let fee = amount
-				.fee(T::BaseWithdrawalFee::get())
-				.ok_or(ArithmeticError::Overflow)?
-				.saturating_add(Self::do_consolidate_deposits(&caller, amount)?);
-			let redeem = amount.checked_sub(&fee).ok_or(Error::<T>::InsufficientDeposit)?.into();
-
-			// calculate the payout for each asset based on the redeem amount
-			let AssetRedemption { asset_amounts, redeemed_index_tokens } = Self::liquid_asset_redemptions(redeem)?;
-
-			// update the index balance by burning all of the redeemed tokens and the fee
-			// SAFETY: this is guaranteed to be lower than `amount`
-			let effectively_withdrawn = fee + redeemed_index_tokens;
-
-			// withdraw from caller balance
-			T::IndexToken::withdraw(
-				&caller,
-				effectively_withdrawn,
-				WithdrawReasons::all(),
-				ExistenceRequirement::AllowDeath,
-			)?;
-
-			// issue new tokens to compensate the fee and put it into the treasury
-			let fee = T::IndexToken::issue(fee);
-			T::IndexToken::resolve_creating(&Self::treasury_account(), fee);
-
-			let mut assets = Vec::with_capacity(asset_amounts.len());
-
-			// start the redemption process for each withdrawal
-			for (asset, units) in asset_amounts {
-				// announce the unbonding routine
-				T::RemoteAssetManager::announce_withdrawal(asset, units);
-				// reserve the funds in the treasury's account until the redemption period is
-				// over after which they can be transferred to the user account
-				// NOTE: this should always succeed due to the way the asset distribution is
-				// calculated
-				T::Currency::reserve(asset, &Self::treasury_account(), units)?;
-				assets.push(AssetWithdrawal { asset, units, reserved: units, withdrawn: false });
-			}
-
-			// after this block an asset withdrawal is allowed to advance to the transfer
-			// state
-			let end_block = frame_system::Pallet::<T>::block_number().saturating_add(T::WithdrawalPeriod::get());
+			let caller = T::AdminOrigin::ensure_origin(origin)?;
+			Self::do_withdraw(caller, amount, Vec::new(), None)
+		}
 
-			// lock the assets for the withdrawal period starting at current block
-			PendingWithdrawals::<T>::append(&caller, PendingRedemption { end_block, assets });
+		/// Same as `withdraw`, but fails if the payout of any of the given assets is less than the
+		/// requested minimum units at the time of execution.
+		///
+		/// Parameters:
+		/// - `amount`: The amount of index tokens to redeem
+		/// - `min_asset_units`: The minimum units of each listed asset the redemption must yield,
+		///   at most `MaxAssetsPerCall` assets
+		#[pallet::weight(T::WeightInfo::withdraw())]
+		#[transactional]
+		pub fn withdraw_with_min_out(
+			origin: OriginFor<T>,
+			amount: T::Balance,
+			min_asset_units: Vec<(T::AssetId, T::Balance)>,
+		) -> DispatchResult {
+			let caller = T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(min_asset_units.len() as u32 <= T::MaxAssetsPerCall::get(), Error::<T>::TooManyAssets);
+			Self::do_withdraw(caller, amount, min_asset_units, None)
+		}
 
-			Self::deposit_event(Event::WithdrawalInitiated(caller, effectively_withdrawn));
-			Ok(())
+		/// Starts the withdraw process for the given amount of PINT to redeem
+		/// for a single liquid asset instead of a distribution of all liquid assets.
+		///
+		/// In addition to the fees of `withdraw`, the `SingleAssetWithdrawalFee` is deducted from
+		/// the redeemed PINT. The redeemed units of the asset must not exceed the
+		/// `SingleAssetRedemptionCap` of the index' free balance of that asset.
+		///
+		/// Parameters:
+		/// - `amount`: The amount of index tokens to redeem
+		/// - `asset_id`: The liquid asset to redeem the index tokens for
+		#[pallet::weight(T::WeightInfo::withdraw())]
+		#[transactional]
+		pub fn withdraw_to_asset(origin: OriginFor<T>, amount: T::Balance, asset_id: T::AssetId) -> DispatchResult {
+			let caller = T::AdminOrigin::ensure_origin(origin)?;
+			Self::do_withdraw(caller, amount, Vec::new(), Some(asset_id))
 		}
 
 		/// Attempts to complete all currently pending redemption processes
@@ -701,33 +1088,7 @@ pub mod pallet {
 			let caller = T::AdminOrigin::ensure_origin(origin.clone())?;
 			let current_block = frame_system::Pallet::<T>::block_number();
 
-			PendingWithdrawals::<T>::try_mutate_exists(&caller, |maybe_pending| -> DispatchResult {
-				let pending = maybe_pending.take().ok_or(<Error<T>>::NoPendingWithdrawals)?;
-
-				// try to redeem each redemption, but only close it if all assets could be
-				// redeemed
-				let still_pending: Vec<_> = pending
-					.into_iter()
-					.filter_map(|mut redemption| {
-						// only try to close if the lockup period is over
-						if redemption.end_block >= current_block &&
-							Self::do_complete_redemption(&caller, &mut redemption.assets)
-						{
-							// all individual redemptions withdrawn, can remove them from storage
-							Self::deposit_event(Event::WithdrawalCompleted(caller.clone(), redemption.assets));
-							return None;
-						}
-						Some(redemption)
-					})
-					.collect();
-
-				if !still_pending.is_empty() {
-					// still have redemptions pending
-					*maybe_pending = Some(still_pending);
-				}
-
-				Ok(())
-			})
+			Self::do_complete_withdraw(&caller, |redemption| redemption.end_block <= current_block)
 		}
 
 		/// Updates the index token locks of the caller.
@@ -837,12 +1198,148 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Removes liquid assets
-		fn do_remove_asset(
-			who: T::AccountId,
+		/// Deposits the given units of the liquid asset into the index and mints the index token
+		/// equivalent, which must be at least `min_index_tokens`, into the caller's account.
+		fn do_deposit(
+			caller: T::AccountId,
 			asset_id: T::AssetId,
 			units: T::Balance,
-			recipient: Option<T::AccountId>,
+			min_index_tokens: T::Balance,
+		) -> DispatchResult {
+			Self::ensure_not_paused()?;
+			if units.is_zero() {
+				return Ok(());
+			}
+
+			// native asset can't be deposited here
+			Self::ensure_not_native_asset(&asset_id)?;
+			// only liquid assets can be deposited
+			Self::ensure_liquid_asset(&asset_id)?;
+
+			// can't calculate an exchange rate if the total supply of index tokens is 0
+			if Self::index_token_issuance().is_zero() {
+				return Err(Error::<T>::InsufficientIndexTokens.into());
+			}
+
+			// the amount of index token the given units of the liquid assets are worth
+			let index_tokens = Self::index_token_equivalent(asset_id, units)?;
+			ensure!(index_tokens >= min_index_tokens, Error::<T>::OutputBelowMinimum);
+
+			// ensure the index token equivalent worth is within the set bounds
+			Self::ensure_deposit_in_bounds(index_tokens)?;
+
+			// transfer from the caller's sovereign account into the treasury's account
+			T::Currency::transfer(asset_id, &caller, &Self::treasury_account(), units)?;
+
+			// mint index token in caller's account
+			Self::do_mint_index_token(&caller, index_tokens);
+
+			// tell the remote asset manager that assets are available to bond
+			T::RemoteAssetManager::deposit(asset_id, units);
+
+			// insert new deposit
+			Deposits::<T>::try_append(&caller, (index_tokens, frame_system::Pallet::<T>::block_number()))
+				.map_err(|_| Error::<T>::TooManyDeposits)?;
+
+			Self::deposit_event(Event::Deposited(asset_id, units, caller, index_tokens));
+			Ok(())
+		}
+
+		/// Starts the redemption of the given amount of index tokens, see `withdraw`.
+		///
+		/// If `redeem_into` is provided, the index tokens are redeemed for that asset only, see
+		/// `withdraw_to_asset`.
+		///
+		/// Fails if the payout of an asset in `min_asset_units` is less than its listed minimum.
+		fn do_withdraw(
+			caller: T::AccountId,
+			amount: T::Balance,
+			min_asset_units: Vec<(T::AssetId, T::Balance)>,
+			redeem_into: Option<T::AssetId>,
+		) -> DispatchResult {
+			Self::ensure_not_paused()?;
+			ensure!(amount >= T::MinimumRedemption::get(), Error::<T>::MinimumRedemption);
+
+			// update the locks of prior deposits
+			Self::do_update_index_token_locks(&caller);
+
+			let free_balance = T::IndexToken::free_balance(&caller);
+			T::IndexToken::ensure_can_withdraw(
+				&caller,
+				amount,
+				WithdrawReasons::all(),
+				free_balance.saturating_sub(amount),
+			)?;
+
+			// calculate the fees and the payout for each asset based on the redeem amount
+			let (
+				WithdrawalQuote {
+					base_fee,
+					redemption_fee,
+					single_asset_fee,
+					asset_amounts,
+					effectively_withdrawn,
+					end_block,
+				},
+				deposits,
+			) = Self::do_quote_withdraw(&caller, amount, redeem_into)?;
+
+			// ensure the payout of each asset meets the requested minimum
+			for (asset, min_units) in min_asset_units {
+				let units = asset_amounts
+					.iter()
+					.find(|(id, _)| *id == asset)
+					.map(|(_, units)| *units)
+					.unwrap_or_else(T::Balance::zero);
+				ensure!(units >= min_units, Error::<T>::OutputBelowMinimum);
+			}
+
+			// remove the deposits implicated by the withdrawn amount
+			if deposits.is_empty() {
+				Deposits::<T>::remove(&caller);
+			} else {
+				Deposits::<T>::insert(&caller, deposits);
+			}
+
+			// withdraw from caller balance
+			T::IndexToken::withdraw(
+				&caller,
+				effectively_withdrawn,
+				WithdrawReasons::all(),
+				ExistenceRequirement::AllowDeath,
+			)?;
+
+			// issue new tokens to compensate the fee and put it into the treasury
+			let fee = T::IndexToken::issue(base_fee.saturating_add(redemption_fee).saturating_add(single_asset_fee));
+			T::IndexToken::resolve_creating(&Self::treasury_account(), fee);
+
+			let mut assets = Vec::with_capacity(asset_amounts.len());
+
+			// start the redemption process for each withdrawal
+			for (asset, units) in asset_amounts {
+				// announce the unbonding routine
+				T::RemoteAssetManager::announce_withdrawal(asset, units);
+				// reserve the funds in the treasury's account until the redemption period is
+				// over after which they can be transferred to the user account
+				// NOTE: this should always succeed due to the way the asset distribution is
+				// calculated
+				T::Currency::reserve(asset, &Self::treasury_account(), units)?;
+				assets.push(AssetWithdrawal { asset, units, reserved: units, withdrawn: false });
+			}
+
+			// lock the assets for the withdrawal period starting at current block
+			PendingWithdrawals::<T>::append(&caller, PendingRedemption { end_block, assets });
+
+			Self::deposit_event(Event::WithdrawalInitiated(caller, effectively_withdrawn));
+			Ok(())
+		}
+
+		/// Removes liquid assets
+		fn do_remove_asset(
+			who: T::AccountId,
+			asset_id: T::AssetId,
+			units: T::Balance,
+			recipient: Option<T::AccountId>,
 		) -> DispatchResult {
 			if units.is_zero() {
 				return Ok(());
@@ -859,6 +1356,88 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Calculates the outcome of a `withdraw` of the given amount of index tokens for the given
+		/// account without altering any state.
+		///
+		/// This determines the fees, the payout for each liquid asset and the block after which the
+		/// redemption can be completed, exactly like `withdraw` does. It does not check whether the
+		/// account can actually transfer the given amount of index tokens.
+		pub fn quote_withdraw(
+			who: &T::AccountId,
+			amount: T::Balance,
+		) -> Result<WithdrawalQuote<T::AssetId, T::Balance, T::BlockNumber>, DispatchError> {
+			Self::do_quote_withdraw(who, amount, None).map(|(quote, _)| quote)
+		}
+
+		/// Calculates the outcome of a `withdraw_to_asset` of the given amount of index tokens for
+		/// the given account without altering any state, see `quote_withdraw`.
+		pub fn quote_withdraw_to_asset(
+			who: &T::AccountId,
+			amount: T::Balance,
+			asset_id: T::AssetId,
+		) -> Result<WithdrawalQuote<T::AssetId, T::Balance, T::BlockNumber>, DispatchError> {
+			Self::do_quote_withdraw(who, amount, Some(asset_id)).map(|(quote, _)| quote)
+		}
+
+		/// Calculates the outcome of a `withdraw` of the given amount together with the deposits
+		/// that remain after the withdrawn amount was consolidated.
+		///
+		/// If `redeem_into` is provided, the index tokens are redeemed for that asset only.
+		fn do_quote_withdraw(
+			who: &T::AccountId,
+			amount: T::Balance,
+			redeem_into: Option<T::AssetId>,
+		) -> Result<
+			(
+				WithdrawalQuote<T::AssetId, T::Balance, T::BlockNumber>,
+				BoundedVec<(T::Balance, T::BlockNumber), T::MaxActiveDeposits>,
+			),
+			DispatchError,
+		> {
+			ensure!(amount >= T::MinimumRedemption::get(), Error::<T>::MinimumRedemption);
+
+			let mut deposits = Deposits::<T>::get(who);
+			ensure!(!deposits.is_empty(), Error::<T>::NoDeposits);
+
+			// amount = fees + redeem
+			let base_fee = amount.fee(T::BaseWithdrawalFee::get()).ok_or(ArithmeticError::Overflow)?;
+			let redemption_fee = Self::consolidate_deposits(&mut deposits, amount)?;
+			let single_asset_fee = if redeem_into.is_some() {
+				amount.fee(T::SingleAssetWithdrawalFee::get()).ok_or(ArithmeticError::Overflow)?
+			} else {
+				T::Balance::zero()
+			};
+			let fee = base_fee.saturating_add(redemption_fee).saturating_add(single_asset_fee);
+			let redeem = amount.checked_sub(&fee).ok_or(Error::<T>::InsufficientDeposit)?.into();
+
+			// calculate the payout for each asset based on the redeem amount
+			let AssetRedemption { asset_amounts, redeemed_index_tokens } = if let Some(asset) = redeem_into {
+				Self::single_asset_redemption(asset, redeem)?
+			} else {
+				Self::liquid_asset_redemptions(redeem)?
+			};
+
+			// update the index balance by burning all of the redeemed tokens and the fee
+			// SAFETY: this is guaranteed to be lower than `amount`
+			let effectively_withdrawn = fee + redeemed_index_tokens;
+
+			// after this block an asset withdrawal is allowed to advance to the transfer
+			// state
+			let end_block = frame_system::Pallet::<T>::block_number().saturating_add(T::WithdrawalPeriod::get());
+
+			Ok((
+				WithdrawalQuote {
+					base_fee,
+					redemption_fee,
+					single_asset_fee,
+					asset_amounts,
+					effectively_withdrawn,
+					end_block,
+				},
+				deposits,
+			))
+		}
+
 		/// The fee model depends on how long LP contributions remained in the index.
 		/// Therefore, LP deposits in `deposit` are time-stamped (block number) so that fees can be
 		/// determined as a function of time spent in the index.
@@ -866,63 +1445,60 @@ pub mod pallet {
 		/// This function consolidates the oldest deposits and removes the deposits implicated by
 		/// the transferred withdrawal amount and returns the total redemption fee for the given
 		/// amount.
-		fn do_consolidate_deposits(caller: &T::AccountId, mut amount: T::Balance) -> Result<T::Balance, DispatchError> {
-			<Deposits<T>>::try_mutate_exists(&caller, |maybe_deposits| -> Result<T::Balance, DispatchError> {
-				let mut deposits = maybe_deposits.take().ok_or(<Error<T>>::NoDeposits)?;
-				let mut total_fee: T::Balance = T::Balance::zero();
-				let current_block = frame_system::Pallet::<T>::block_number();
-
-				let redemption_fee_range = RedemptionFee::<T>::get();
-				let mut calculate_redemption_fee_failed = false;
-				let mut rem: Option<(T::Balance, T::BlockNumber)> = None;
-				deposits.retain(|(index_tokens, block_number)| {
-					// how long this deposit spent in the index.
-					let time_spent = current_block.saturating_sub(*block_number);
-
-					if amount.is_zero() {
-						true
-					} else if amount >= *index_tokens {
-						amount = amount.saturating_sub(*index_tokens);
-						if let Some(fee) = redemption_fee_range.redemption_fee(time_spent, *index_tokens) {
-							total_fee = total_fee.saturating_add(fee);
-						} else {
-							calculate_redemption_fee_failed = true;
-						}
+		fn consolidate_deposits(
+			deposits: &mut BoundedVec<(T::Balance, T::BlockNumber), T::MaxActiveDeposits>,
+			mut amount: T::Balance,
+		) -> Result<T::Balance, DispatchError> {
+			let mut total_fee: T::Balance = T::Balance::zero();
+			let current_block = frame_system::Pallet::<T>::block_number();
 
-						false
+			let redemption_fee_range = RedemptionFee::<T>::get();
+			let mut calculate_redemption_fee_failed = false;
+			let mut rem: Option<(T::Balance, T::BlockNumber)> = None;
+			deposits.retain(|(index_tokens, block_number)| {
+				// how long this deposit spent in the index.
+				let time_spent = current_block.saturating_sub(*block_number);
+
+				if amount.is_zero() {
+					true
+				} else if amount >= *index_tokens {
+					amount = amount.saturating_sub(*index_tokens);
+					if let Some(fee) = redemption_fee_range.redemption_fee(time_spent, *index_tokens) {
+						total_fee = total_fee.saturating_add(fee);
 					} else {
-						// the remaining amount is less than the oldest deposit, so we are simply updating the value of
-						// the now oldest deposit
-						rem = Some((index_tokens.saturating_sub(amount), *block_number));
-						if let Some(fee) = redemption_fee_range.redemption_fee(time_spent, amount) {
-							total_fee = total_fee.saturating_add(fee);
-						} else {
-							calculate_redemption_fee_failed = true;
-						}
+						calculate_redemption_fee_failed = true;
+					}
 
-						amount = T::Balance::zero();
-						true
+					false
+				} else {
+					// the remaining amount is less than the oldest deposit, so we are simply updating the value of
+					// the now oldest deposit
+					rem = Some((index_tokens.saturating_sub(amount), *block_number));
+					if let Some(fee) = redemption_fee_range.redemption_fee(time_spent, amount) {
+						total_fee = total_fee.saturating_add(fee);
+					} else {
+						calculate_redemption_fee_failed = true;
 					}
-				});
 
-				if calculate_redemption_fee_failed {
-					return Err(Error::<T>::CalculateRedemptionFeeFailed.into());
+					amount = T::Balance::zero();
+					true
 				}
+			});
 
-				if !deposits.is_empty() {
-					if let Some(rem) = rem {
-						// update the oldest value
-						deposits[0] = rem;
-					}
-					*maybe_deposits = Some(deposits);
-				}
+			if calculate_redemption_fee_failed {
+				return Err(Error::<T>::CalculateRedemptionFeeFailed.into());
+			}
 
-				if !amount.is_zero() {
-					return Err(<Error<T>>::InsufficientDeposit.into());
-				}
+			if let Some(rem) = rem {
+				// update the oldest value
+				deposits[0] = rem;
+			}
 
-				Ok(total_fee)
-			})
+			if !amount.is_zero() {
+				return Err(<Error<T>>::InsufficientDeposit.into());
+			}
+
+			Ok(total_fee)
 		}
 
 		/// Returns the relative price pair NAV/Asset to calculate the asset equivalent value:
@@ -985,6 +1561,42 @@ pub mod pallet {
 			})
 		}
 
+		/// Calculates the asset redemption for the given amount of the index token to be redeemed
+		/// for the given liquid asset only.
+		///
+		/// *NOTE*:
+		///   - This does not account for fees
+		///   - This is a no-op for `redeem == 0`
+		pub fn single_asset_redemption(
+			asset: T::AssetId,
+			redeem: u128,
+		) -> Result<AssetRedemption<T::AssetId, T::Balance>, DispatchError> {
+			Self::ensure_not_native_asset(&asset)?;
+			Self::ensure_liquid_asset(&asset)?;
+			if redeem.is_zero() {
+				return Ok(Default::default());
+			}
+
+			// the total NAV is sum(liquid_nav + saft_nav) and represents the real value of a 1unit of index
+			// token
+			let nav_asset_price = Self::liquid_nav_price_pair(Self::nav()?, asset)?;
+			let units: T::Balance = nav_asset_price
+				.volume(redeem)
+				.and_then(|n| TryInto::<T::Balance>::try_into(n).ok())
+				.ok_or(ArithmeticError::Overflow)?;
+
+			// a single withdrawal must not drain the index' funds of the asset
+			ensure!(
+				units <= T::SingleAssetRedemptionCap::get().mul_floor(Self::index_free_asset_balance(asset)),
+				Error::<T>::SingleAssetRedemptionCapExceeded
+			);
+
+			Ok(AssetRedemption {
+				asset_amounts: vec![(asset, units)],
+				redeemed_index_tokens: redeem.try_into().map_err(|_| ArithmeticError::Overflow)?,
+			})
+		}
+
 		/// Ensures the given asset id is a liquid asset
 		fn ensure_liquid_asset(asset_id: &T::AssetId) -> DispatchResult {
 			Assets::<T>::get(asset_id)
@@ -1093,6 +1705,285 @@ pub mod pallet {
 			all_withdrawn
 		}
 
+		/// Tries to complete all pending redemptions of the account for which `is_ready` holds.
+		///
+		/// Redemptions are only removed from storage once all of their assets were transferred,
+		/// everything else remains pending.
+		fn do_complete_withdraw(
+			who: &T::AccountId,
+			is_ready: impl Fn(&PendingRedemption<T::AssetId, T::Balance, BlockNumberFor<T>>) -> bool,
+		) -> DispatchResult {
+			PendingWithdrawals::<T>::try_mutate_exists(who, |maybe_pending| -> DispatchResult {
+				let pending = maybe_pending.take().ok_or(<Error<T>>::NoPendingWithdrawals)?;
+
+				// try to redeem each redemption, but only close it if all assets could be
+				// redeemed
+				let still_pending: Vec<_> = pending
+					.into_iter()
+					.filter_map(|mut redemption| {
+						// only try to close if the lockup period is over
+						if is_ready(&redemption) && Self::do_complete_redemption(who, &mut redemption.assets) {
+							// all individual redemptions withdrawn, can remove them from storage
+							Self::deposit_event(Event::WithdrawalCompleted(who.clone(), redemption.assets));
+							return None;
+						}
+						Some(redemption)
+					})
+					.collect();
+
+				if !still_pending.is_empty() {
+					// still have redemptions pending
+					*maybe_pending = Some(still_pending);
+				}
+
+				Ok(())
+			})
+		}
+
+		/// Completes the matured redemptions of all accounts with pending withdrawals until
+		/// `max_weight` is exhausted and returns the consumed weight.
+		///
+		/// This continues after the account that was processed last, so that every account is
+		/// visited eventually even if `max_weight` only suffices for a few accounts per block.
+		/// Redemptions the treasury can not cover yet are left for a later block.
+		pub fn complete_matured_withdrawals(now: BlockNumberFor<T>, max_weight: Weight) -> Weight {
+			let per_account = T::WeightInfo::complete_withdraw();
+			let read = T::DbWeight::get().reads(1);
+			// reading and updating the cursor
+			let mut consumed = T::DbWeight::get().reads_writes(1, 1);
+			if consumed.saturating_add(read).saturating_add(per_account) > max_weight {
+				return 0;
+			}
+
+			let mut cursor = WithdrawalCursor::<T>::get();
+			// only the entry of the current key is mutated, which is safe while iterating
+			let mut accounts = match cursor.clone() {
+				Some(last) => PendingWithdrawals::<T>::iter_keys_from(last),
+				None => PendingWithdrawals::<T>::iter_keys(),
+			};
+			while consumed.saturating_add(read).saturating_add(per_account) <= max_weight {
+				consumed = consumed.saturating_add(read);
+				let who = match accounts.next() {
+					Some(who) => who,
+					None => {
+						// all accounts were visited, start over in the next block
+						cursor = None;
+						break;
+					}
+				};
+				consumed = consumed.saturating_add(per_account);
+				let _ = Self::do_complete_withdraw(&who, |redemption| redemption.end_block <= now);
+				cursor = Some(PendingWithdrawals::<T>::hashed_key_for(&who));
+			}
+			WithdrawalCursor::<T>::set(cursor);
+			consumed
+		}
+
+		/// Computes the trades required to move the liquid assets of the index back to their
+		/// target weights.
+		///
+		/// An asset is only included if its current proportion (see
+		/// [`NavProvider::asset_proportions`]) drifted at least `RebalanceThreshold` from its
+		/// target weight. Liquid assets without a target weight are treated as a target of zero,
+		/// SAFT can not be traded and are skipped. If no target weights are set, the plan is
+		/// empty.
+		pub fn rebalance_plan() -> Result<RebalancePlan<T::AssetId, T::Balance>, DispatchError> {
+			let mut plan = RebalancePlan::default();
+			if TargetWeights::<T>::iter().next().is_none() {
+				return Ok(plan);
+			}
+
+			let threshold = RebalanceThreshold::<T>::get();
+			let issuance: u128 = Self::index_token_issuance().into();
+			let AssetProportions { nav, proportions } = Self::asset_proportions()?;
+
+			for AssetProportion { asset, proportion } in proportions {
+				if !Self::is_liquid_asset(&asset) {
+					continue;
+				}
+				let target = TargetWeights::<T>::get(asset).unwrap_or_else(Ratio::zero);
+				let (drift, sell) = if proportion > target {
+					(proportion.saturating_sub(target), true)
+				} else {
+					(target.saturating_sub(proportion), false)
+				};
+				if drift.is_zero() || drift < threshold {
+					continue;
+				}
+
+				// the drift is a share of the index' total value, which is equivalent to that share of
+				// the total supply of index tokens
+				let units: T::Balance = Self::liquid_nav_price_pair(nav, asset)?
+					.volume(drift.saturating_mul_int(issuance))
+					.and_then(|n| TryInto::<T::Balance>::try_into(n).ok())
+					.ok_or(ArithmeticError::Overflow)?;
+				if units.is_zero() {
+					continue;
+				}
+
+				if sell {
+					plan.sells.push((asset, units));
+				} else {
+					plan.buys.push((asset, units));
+				}
+			}
+			Ok(plan)
+		}
+
+		/// Mints the management fee that accrued since the last accrual to the treasury and returns
+		/// the minted amount.
+		///
+		/// The fee is pro-rata to the current index token issuance:
+		/// `issuance * rate * elapsed_blocks / BlocksPerYear`.
+		/// If the fee for the elapsed blocks rounds down to zero, the blocks are carried over to
+		/// the next accrual.
+		pub fn accrue_management_fee(now: T::BlockNumber) -> T::Balance {
+			let rate = match ManagementFeeRate::<T>::get() {
+				Some(rate) if !rate.numerator.is_zero() => rate,
+				_ => return T::Balance::zero(),
+			};
+
+			let fee = match Self::pending_management_fee(rate, now) {
+				Some(fee) => fee,
+				None => {
+					// nothing to charge the fee on
+					LastManagementFeeAccrual::<T>::put(now);
+					return T::Balance::zero();
+				}
+			};
+			if fee.is_zero() {
+				return fee;
+			}
+
+			LastManagementFeeAccrual::<T>::put(now);
+			T::IndexToken::resolve_creating(&Self::treasury_account(), T::IndexToken::issue(fee));
+			AccruedManagementFees::<T>::mutate(|accrued| *accrued = accrued.saturating_add(fee));
+			Self::deposit_event(Event::<T>::ManagementFeeAccrued(fee));
+			fee
+		}
+
+		/// Pauses deposits and withdrawals if the price feed of a liquid asset is older than
+		/// `MaxPriceAge` blocks or if the given `NAV` moved more than `MaxNavChange` within the
+		/// current `NavChangeWindow`.
+		///
+		/// Only the price feeds of the next `MaxPriceFeedChecks` assets are checked, see
+		/// [`Pallet::find_stale_price_feed`]. The `NAV` is the one of the snapshot taken in this
+		/// block, so its deviation is only checked every `NavSnapshotInterval` blocks.
+		///
+		/// Returns the reason if the circuit breaker was tripped.
+		pub fn check_circuit_breaker(now: T::BlockNumber, nav: Option<Price>) -> Option<PauseReason<T::AssetId>> {
+			let reason = Self::find_stale_price_feed(now)
+				.map(PauseReason::StalePriceFeed)
+				.or_else(|| nav.and_then(|nav| Self::check_nav_deviation(now, nav)))?;
+
+			Paused::<T>::put(reason.clone());
+			Self::deposit_event(Event::<T>::Paused(reason.clone()));
+			Some(reason)
+		}
+
+		/// Checks the price feeds of the next `MaxPriceFeedChecks` assets after the
+		/// `PriceFeedCheckCursor` and returns the first liquid asset whose price is older than
+		/// `MaxPriceAge` blocks.
+		///
+		/// Assets without a price feed are not checked, as the `NAV` can not be determined without
+		/// them anyway.
+		fn find_stale_price_feed(now: T::BlockNumber) -> Option<T::AssetId> {
+			let mut assets = match PriceFeedCheckCursor::<T>::get() {
+				Some(last) => Assets::<T>::iter_from(last),
+				None => Assets::<T>::iter(),
+			};
+			for _ in 0..T::MaxPriceFeedChecks::get() {
+				let (asset, availability) = match assets.next() {
+					Some(next) => next,
+					None => {
+						// all assets were checked, start over in the next block
+						PriceFeedCheckCursor::<T>::kill();
+						return None;
+					}
+				};
+				PriceFeedCheckCursor::<T>::put(Assets::<T>::hashed_key_for(&asset));
+				if availability.is_liquid() &&
+					T::PriceFeed::price_updated_at(asset)
+						.map_or(false, |updated_at| now.saturating_sub(updated_at) > T::MaxPriceAge::get())
+				{
+					return Some(asset);
+				}
+			}
+			None
+		}
+
+		/// Compares the given current `NAV` with the `NAV` at the start of the current
+		/// `NavChangeWindow` and starts a new window once the current one is over.
+		fn check_nav_deviation(now: T::BlockNumber, current: Price) -> Option<PauseReason<T::AssetId>> {
+			match NavReference::<T>::get() {
+				Some((start, reference)) if !reference.is_zero() => {
+					let change = if current > reference {
+						current.saturating_sub(reference)
+					} else {
+						reference.saturating_sub(current)
+					};
+					let max_change = reference.saturating_mul(T::MaxNavChange::get().into());
+					if change > max_change {
+						return Some(PauseReason::NavDeviation { reference, current });
+					}
+					if now.saturating_sub(start) >= T::NavChangeWindow::get() {
+						NavReference::<T>::put((now, current));
+					}
+				}
+				_ => NavReference::<T>::put((now, current)),
+			}
+			None
+		}
+
+		/// Ensures deposits and withdrawals are not paused by the circuit breaker
+		fn ensure_not_paused() -> DispatchResult {
+			ensure!(!Paused::<T>::exists(), Error::<T>::IndexPaused);
+			Ok(())
+		}
+
+		/// Returns the recorded `NAV` snapshots, ordered from oldest to newest.
+		pub fn nav_snapshots() -> Vec<NavSnapshot<T::BlockNumber>> {
+			let head = NavSnapshotHead::<T>::get();
+			// once the buffer wrapped around, the oldest snapshot is stored in the head slot
+			let oldest = (head..T::MaxNavSnapshots::get()).filter_map(NavSnapshots::<T>::get);
+			oldest.chain((0..head).filter_map(NavSnapshots::<T>::get)).collect()
+		}
+
+		/// Records the current `NAV`s of the index token, replacing the oldest snapshot if
+		/// `MaxNavSnapshots` is reached.
+		///
+		/// Returns the recorded snapshot.
+		pub fn record_nav_snapshot(now: T::BlockNumber) -> Result<NavSnapshot<T::BlockNumber>, DispatchError> {
+			let (liquid_nav, saft_nav) = Self::navs()?;
+			let nav = liquid_nav.checked_add(&saft_nav).ok_or(ArithmeticError::Overflow)?;
+			let snapshot = NavSnapshot { block: now, nav, liquid_nav, saft_nav };
+
+			let head = NavSnapshotHead::<T>::get();
+			NavSnapshots::<T>::insert(head, &snapshot);
+			NavSnapshotHead::<T>::put(head.saturating_add(1) % T::MaxNavSnapshots::get().max(1));
+			Self::deposit_event(Event::<T>::NavSnapshotRecorded(now, nav));
+			Ok(snapshot)
+		}
+
+		/// Calculates the management fee accrued since the last accrual for the given rate.
+		///
+		/// Returns `None` if there are no index tokens in circulation.
+		fn pending_management_fee(rate: FeeRate, now: T::BlockNumber) -> Option<T::Balance> {
+			let issuance: u128 = Self::index_token_issuance().into();
+			if issuance.is_zero() {
+				return None;
+			}
+			let elapsed: u128 = now.saturating_sub(LastManagementFeeAccrual::<T>::get()).unique_saturated_into();
+			let blocks_per_year: u128 = T::BlocksPerYear::get().unique_saturated_into();
+
+			let share = Ratio::checked_from_rational(
+				(rate.numerator as u128).saturating_mul(elapsed),
+				(rate.denominator as u128).saturating_mul(blocks_per_year),
+			)
+			.unwrap_or_else(Ratio::zero);
+			Some(share.saturating_mul_int(issuance).try_into().unwrap_or_else(|_| T::Balance::max_value()))
+		}
+
 		/// Ensures the given lies within the configured deposit range
 		pub fn ensure_deposit_in_bounds(amount: T::Balance) -> DispatchResult {
 			let bounds = IndexTokenDepositRange::<T>::get();
@@ -1200,9 +2091,17 @@ pub mod pallet {
 			// the current index token equivalent value of the given saft nav
 			let index_token = Self::saft_equivalent(saft_nav)?;
 
-			ensure!(!Self::is_liquid_asset(&asset_id), Error::<T>::ExpectedSAFT);
 			ensure!(T::IndexToken::can_slash(who, index_token), Error::<T>::InsufficientDeposit);
 
+			// the units of SAFTs that were not converted with their liquid asset are locked
+			if Self::is_liquid_asset(&asset_id) {
+				LockedSaftUnits::<T>::try_mutate(asset_id, |locked| -> DispatchResult {
+					*locked = locked.checked_sub(&units).ok_or(Error::<T>::InsufficientLockedSAFTUnits)?;
+					Ok(())
+				})?;
+				T::Currency::unreserve(asset_id, &Self::treasury_account(), units);
+			}
+
 			// burn SAFT by withdrawing from the index
 			T::Currency::withdraw(asset_id, &Self::treasury_account(), units)?;
 			// burn index token accordingly, no index token changes in the meantime
@@ -1210,6 +2109,25 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		fn lock_saft_units(asset_id: T::AssetId, units: T::Balance) -> DispatchResult {
+			ensure!(Self::is_liquid_asset(&asset_id), Error::<T>::ExpectedLiquid);
+			// reserved funds are not part of the liquid value of the index
+			T::Currency::reserve(asset_id, &Self::treasury_account(), units)?;
+			LockedSaftUnits::<T>::mutate(asset_id, |locked| *locked = locked.saturating_add(units));
+			Ok(())
+		}
+
+		fn unlock_saft_units(asset_id: T::AssetId, units: T::Balance) -> DispatchResult {
+			ensure!(Self::is_liquid_asset(&asset_id), Error::<T>::ExpectedLiquid);
+			// only units locked for SAFTs can be released, not the reserves of pending withdrawals
+			LockedSaftUnits::<T>::try_mutate(asset_id, |locked| -> DispatchResult {
+				*locked = locked.checked_sub(&units).ok_or(Error::<T>::InsufficientLockedSAFTUnits)?;
+				Ok(())
+			})?;
+			T::Currency::unreserve(asset_id, &Self::treasury_account(), units);
+			Ok(())
+		}
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -1349,7 +2267,8 @@ pub mod pallet {
 		}
 
 		fn total_net_saft_value() -> Result<U256, DispatchError> {
-			Self::saft_assets().into_iter().try_fold(U256::zero(), |worth, asset| -> Result<_, DispatchError> {
+			// liquid assets may still be partially secured by SAFTs that did not vest yet
+			Assets::<T>::iter_keys().try_fold(U256::zero(), |worth, asset| -> Result<_, DispatchError> {
 				worth
 					.checked_add(U256::from(Self::net_saft_value(asset).into()))
 					.ok_or_else(|| ArithmeticError::Overflow.into())
@@ -1357,19 +2276,19 @@ pub mod pallet {
 		}
 
 		fn total_net_asset_value() -> Result<U256, DispatchError> {
-			Assets::<T>::iter().try_fold(U256::zero(), |value, (asset, availability)| -> Result<_, DispatchError> {
-				if availability.is_liquid() {
-					value.checked_add(U256::from(Self::net_liquid_value(asset)?.into()))
-				} else {
-					value.checked_add(U256::from(Self::net_saft_value(asset).into()))
-				}
-				.ok_or_else(|| ArithmeticError::Overflow.into())
+			Assets::<T>::iter_keys().try_fold(U256::zero(), |value, asset| -> Result<_, DispatchError> {
+				value
+					.checked_add(U256::from(Self::net_asset_value(asset)?.into()))
+					.ok_or_else(|| ArithmeticError::Overflow.into())
 			})
 		}
 
 		fn net_asset_value(asset: T::AssetId) -> Result<T::Balance, DispatchError> {
 			if Self::is_liquid_asset(&asset) {
-				Self::calculate_net_liquid_value(asset, Self::asset_balance(asset))
+				// the units of a liquid asset that are still secured by SAFTs are valued by their NAV
+				Self::calculate_net_liquid_value(asset, Self::asset_balance(asset))?
+					.checked_add(&Self::net_saft_value(asset))
+					.ok_or_else(|| ArithmeticError::Overflow.into())
 			} else {
 				Ok(Self::net_saft_value(asset))
 			}
@@ -1381,10 +2300,8 @@ pub mod pallet {
 				return Ok(Price::zero());
 			}
 
-			Assets::<T>::iter().try_fold(Price::zero(), |nav, (asset, availability)| -> Result<_, DispatchError> {
-				let value =
-					if availability.is_liquid() { Self::net_liquid_value(asset)? } else { Self::net_saft_value(asset) };
-
+			Assets::<T>::iter_keys().try_fold(Price::zero(), |nav, asset| -> Result<_, DispatchError> {
+				let value = Self::net_asset_value(asset)?;
 				let proportion = Ratio::checked_from_rational(value.into(), total_issuance.into())
 					.ok_or(ArithmeticError::Overflow)?;
 				Ok(nav.checked_add(&proportion).ok_or(ArithmeticError::Overflow)?)
@@ -1409,7 +2326,8 @@ pub mod pallet {
 			if total_issuance.is_zero() {
 				return Ok(Price::zero());
 			}
-			Self::saft_assets().try_fold(Price::zero(), |nav, asset| -> Result<_, DispatchError> {
+			// liquid assets may still be partially secured by SAFTs that did not vest yet
+			Assets::<T>::iter_keys().try_fold(Price::zero(), |nav, asset| -> Result<_, DispatchError> {
 				let value = Self::net_saft_value(asset);
 				let proportion = Ratio::checked_from_rational(value.into(), total_issuance.into())
 					.ok_or(ArithmeticError::Overflow)?;
@@ -1482,6 +2400,16 @@ pub mod pallet {
 		fn set_deposit_range() -> Weight;
 		fn set_lockup_period() -> Weight;
 		fn update_redemption_fees() -> Weight;
+		fn set_target_weights(n: u32) -> Weight;
+		fn set_rebalance_threshold() -> Weight;
+		fn announce_rebalance() -> Weight;
+		fn complete_rebalance() -> Weight;
+		fn set_management_fee_rate() -> Weight;
+		fn accrue_management_fee() -> Weight;
+		fn record_nav_snapshot() -> Weight;
+		fn check_circuit_breaker(n: u32) -> Weight;
+		fn resume() -> Weight;
+		fn pause() -> Weight;
 	}
 
 	/// For backwards compatibility and tests
@@ -1529,5 +2457,45 @@ pub mod pallet {
 		fn update_redemption_fees() -> Weight {
 			Default::default()
 		}
+
+		fn set_target_weights(_n: u32) -> Weight {
+			Default::default()
+		}
+
+		fn set_rebalance_threshold() -> Weight {
+			Default::default()
+		}
+
+		fn announce_rebalance() -> Weight {
+			Default::default()
+		}
+
+		fn complete_rebalance() -> Weight {
+			Default::default()
+		}
+
+		fn set_management_fee_rate() -> Weight {
+			Default::default()
+		}
+
+		fn accrue_management_fee() -> Weight {
+			Default::default()
+		}
+
+		fn record_nav_snapshot() -> Weight {
+			Default::default()
+		}
+
+		fn check_circuit_breaker(_n: u32) -> Weight {
+			Default::default()
+		}
+
+		fn resume() -> Weight {
+			Default::default()
+		}
+
+		fn pause() -> Weight {
+			Default::default()
+		}
 	}
 }
@@ -15,7 +15,7 @@ use frame_support::{
 	sp_runtime::{
 		testing::Header,
 		traits::{BlakeTwo256, IdentityLookup, Zero},
-		DispatchError,
+		DispatchError, Permill,
 	},
 	sp_std::{cell::RefCell, marker::PhantomData, ops::Range},
 	traits::{Everything, GenesisBuild, LockIdentifier},
@@ -23,7 +23,7 @@ use frame_support::{
 };
 use frame_system as system;
 use orml_traits::parameter_type_with_key;
-use pallet_price_feed::PriceFeed;
+use pallet_price_feed::{PriceFeed, PriceFeedAge};
 use primitives::{
 	fee::{FeeRate, RedemptionFeeRange},
 	AssetPricePair, Price,
@@ -48,6 +48,7 @@ frame_support::construct_runtime!(
 		AssetIndex: pallet_asset_index::{Pallet, Call, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Currency: orml_tokens::{Pallet, Event<T>},
+		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
 	}
 );
 
@@ -143,6 +144,22 @@ impl pallet_saft_registry::Config for Test {
 	type Balance = Balance;
 	type AssetRecorder = AssetIndex;
 	type AssetId = AssetId;
+	type MaxAttestedNavChange = MaxAttestedNavChange;
+	type MaxSaftNavAge = MaxSaftNavAge;
+	type MaxSaftConversions = MaxSaftConversions;
+	type MinAttestationInterval = MinAttestationInterval;
+	type Time = Timestamp;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const MinimumPeriod: u64 = 1000;
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
 	type WeightInfo = ();
 }
 
@@ -162,6 +179,22 @@ parameter_types! {
 	};
 	// No fees for now
 	pub const BaseWithdrawalFee: FeeRate = FeeRate{ numerator: 0, denominator: 1_000,};
+	pub const SingleAssetWithdrawalFee: FeeRate = FeeRate{ numerator: 1, denominator: 100,};
+	pub const SingleAssetRedemptionCap: Permill = Permill::from_percent(50);
+	pub const MaxAssetsPerCall: u32 = 2;
+	pub const MaxTargets: u32 = 2;
+	pub const BlocksPerYear: <Test as system::Config>::BlockNumber = 1_000;
+	pub const NavSnapshotInterval: <Test as system::Config>::BlockNumber = 10;
+	pub const MaxNavSnapshots: u32 = 3;
+	pub const MaxNavChange: Permill = Permill::from_percent(20);
+	pub const NavChangeWindow: <Test as system::Config>::BlockNumber = 10;
+	pub const MaxPriceAge: <Test as system::Config>::BlockNumber = 100;
+	pub const MaxPriceFeedChecks: u32 = 1;
+	pub const ResumeGracePeriod: <Test as system::Config>::BlockNumber = 10;
+	pub const MaxAttestedNavChange: Permill = Permill::from_percent(10);
+	pub const MaxSaftNavAge: <Test as system::Config>::BlockNumber = 100;
+	pub const MaxSaftConversions: u32 = 2;
+	pub const MinAttestationInterval: <Test as system::Config>::BlockNumber = 10;
 }
 
 /// Range of lockup period
@@ -198,6 +231,18 @@ impl pallet_asset_index::Config for Test {
 	type PriceFeedBenchmarks = MockPriceFeed;
 	type SaftRegistry = SaftRegistry;
 	type BaseWithdrawalFee = BaseWithdrawalFee;
+	type SingleAssetWithdrawalFee = SingleAssetWithdrawalFee;
+	type SingleAssetRedemptionCap = SingleAssetRedemptionCap;
+	type MaxAssetsPerCall = MaxAssetsPerCall;
+	type MaxTargets = MaxTargets;
+	type BlocksPerYear = BlocksPerYear;
+	type NavSnapshotInterval = NavSnapshotInterval;
+	type MaxNavSnapshots = MaxNavSnapshots;
+	type MaxNavChange = MaxNavChange;
+	type NavChangeWindow = NavChangeWindow;
+	type MaxPriceAge = MaxPriceAge;
+	type MaxPriceFeedChecks = MaxPriceFeedChecks;
+	type ResumeGracePeriod = ResumeGracePeriod;
 	type TreasuryPalletId = TreasuryPalletId;
 	type Event = Event;
 	type StringLimit = StringLimit;
@@ -217,6 +262,7 @@ pub const ASSET_B_PRICE_MULTIPLIER: Balance = 3;
 
 thread_local! {
 	pub static PRICES: RefCell<HashMap<AssetId, Price>> = RefCell::new(HashMap::new());
+	pub static PRICES_UPDATED_AT: RefCell<HashMap<AssetId, BlockNumber>> = RefCell::new(HashMap::new());
 }
 
 pub struct MockPriceFeed;
@@ -226,6 +272,11 @@ impl MockPriceFeed {
 		PRICES.with(|v| *v.borrow_mut() = prices.into_iter().collect());
 	}
 
+	/// Sets the block at which the price of the asset was last updated
+	pub fn set_price_updated_at(asset: AssetId, block: BlockNumber) {
+		PRICES_UPDATED_AT.with(|v| v.borrow_mut().insert(asset, block));
+	}
+
 	/// Use some random prices for the given assets
 	pub fn set_random_prices(assets: impl IntoIterator<Item = AssetId>, range: Range<u128>) {
 		let mut rng = thread_rng();
@@ -253,6 +304,16 @@ impl PriceFeed<AssetId> for MockPriceFeed {
 	}
 }
 
+impl PriceFeedAge<AssetId, BlockNumber> for MockPriceFeed {
+	// prices are considered up to date unless set otherwise
+	fn price_updated_at(asset: AssetId) -> Result<BlockNumber, DispatchError> {
+		Self::get_price(asset)?;
+		Ok(PRICES_UPDATED_AT
+			.with(|v| v.borrow().get(&asset).cloned())
+			.unwrap_or_else(frame_system::Pallet::<Test>::block_number))
+	}
+}
+
 pub struct ExtBuilder {
 	balances: Vec<(AccountId, AssetId, Balance)>,
 }
@@ -1,20 +1,28 @@
 // Copyright 2021 ChainSafe Systems
 // SPDX-License-Identifier: LGPL-3.0-only
 
-use frame_support::{assert_noop, assert_ok, traits::Currency as _};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Currency as _, Hooks},
+	weights::Weight,
+};
 use orml_traits::{GetByKey, MultiCurrency};
 use rand::Rng;
-use sp_runtime::{traits::Zero, FixedPointNumber};
+use sp_runtime::{
+	traits::{BadOrigin, One, Zero},
+	FixedPointNumber, PerThing,
+};
 use xcm::v1::MultiLocation;
 
 use pallet_price_feed::PriceFeed;
 use primitives::{
+	fee::FeeRate,
 	traits::{AssetRecorder, NavProvider},
-	AssetAvailability, Price,
+	AssetAvailability, Price, Ratio,
 };
 
 use crate as pallet;
-use crate::{mock::*, traits::LockupPeriodRange as _, types::DepositRange};
+use crate::{mock::*, traits::LockupPeriodRange as _, types, types::DepositRange};
 
 #[test]
 fn can_register_asset() {
@@ -391,6 +399,29 @@ fn deposit_works_with_user_balance() {
 	});
 }
 
+#[test]
+fn deposit_with_min_out_fails_below_minimum() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetIndex::register_asset(
+			Origin::signed(ACCOUNT_ID),
+			ASSET_A_ID,
+			AssetAvailability::Liquid(MultiLocation::default())
+		));
+		assert_ok!(AssetIndex::add_asset(Origin::signed(ACCOUNT_ID), ASSET_A_ID, 100, 1_000));
+
+		let deposit = 1_000;
+		assert_ok!(Currency::deposit(ASSET_A_ID, &ASHLEY, deposit));
+		let expected = AssetIndex::index_token_equivalent(ASSET_A_ID, deposit).unwrap();
+
+		assert_noop!(
+			AssetIndex::deposit_with_min_out(Origin::signed(ASHLEY), ASSET_A_ID, deposit, expected + 1),
+			pallet::Error::<Test>::OutputBelowMinimum
+		);
+		assert_ok!(AssetIndex::deposit_with_min_out(Origin::signed(ASHLEY), ASSET_A_ID, deposit, expected));
+		assert_eq!(AssetIndex::index_token_balance(&ASHLEY), expected);
+	});
+}
+
 #[test]
 fn deposit_fail_for_unsupported_assets() {
 	let balance = vec![(ACCOUNT_ID, UNKNOWN_ASSET_ID, 1000)];
@@ -496,6 +527,67 @@ fn deposit_fails_on_invalid_deposit_range() {
 	})
 }
 
+#[test]
+fn can_deposit_basket() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetIndex::register_asset(
+			Origin::signed(ACCOUNT_ID),
+			ASSET_A_ID,
+			AssetAvailability::Liquid(MultiLocation::default())
+		));
+		assert_ok!(AssetIndex::register_asset(
+			Origin::signed(ACCOUNT_ID),
+			ASSET_B_ID,
+			AssetAvailability::Liquid(MultiLocation::default())
+		));
+		assert_ok!(AssetIndex::add_asset(Origin::signed(ACCOUNT_ID), ASSET_A_ID, 100, 500));
+		assert_ok!(AssetIndex::add_asset(Origin::signed(ACCOUNT_ID), ASSET_B_ID, 3000, 100));
+
+		assert_ok!(Currency::deposit(ASSET_A_ID, &ASHLEY, 1_000));
+		assert_ok!(Currency::deposit(ASSET_B_ID, &ASHLEY, 2_000));
+		let expected = AssetIndex::index_token_equivalent(ASSET_A_ID, 1_000).unwrap() +
+			AssetIndex::index_token_equivalent(ASSET_B_ID, 2_000).unwrap();
+
+		// only liquid assets can be deposited
+		assert_noop!(
+			AssetIndex::deposit_basket(Origin::signed(ASHLEY), vec![(ASSET_A_ID, 1_000), (UNKNOWN_ASSET_ID, 1)]),
+			pallet::Error::<Test>::UnsupportedAsset
+		);
+		assert_noop!(AssetIndex::deposit_basket(Origin::signed(ASHLEY), vec![]), pallet::Error::<Test>::EmptyBasket);
+		assert_noop!(
+			AssetIndex::deposit_basket(Origin::signed(ASHLEY), vec![(ASSET_A_ID, 500), (ASSET_A_ID, 500)]),
+			pallet::Error::<Test>::DuplicateAsset
+		);
+		assert_noop!(
+			AssetIndex::deposit_basket(
+				Origin::signed(ASHLEY),
+				vec![(ASSET_A_ID, 1_000), (ASSET_B_ID, 2_000), (UNKNOWN_ASSET_ID, 1)]
+			),
+			pallet::Error::<Test>::TooManyAssets
+		);
+
+		// the combined value must be within the deposit range
+		assert_ok!(AssetIndex::set_deposit_range(
+			Origin::signed(ACCOUNT_ID),
+			DepositRange { minimum: 1, maximum: expected - 1 }
+		));
+		assert_noop!(
+			AssetIndex::deposit_basket(Origin::signed(ASHLEY), vec![(ASSET_A_ID, 1_000), (ASSET_B_ID, 2_000)]),
+			pallet::Error::<Test>::DepositExceedsMaximum
+		);
+		assert_ok!(AssetIndex::set_deposit_range(
+			Origin::signed(ACCOUNT_ID),
+			DepositRange { minimum: 1, maximum: expected }
+		));
+
+		assert_ok!(AssetIndex::deposit_basket(Origin::signed(ASHLEY), vec![(ASSET_A_ID, 1_000), (ASSET_B_ID, 2_000)]));
+		assert_eq!(Currency::total_balance(ASSET_A_ID, &ASHLEY), 0);
+		assert_eq!(Currency::total_balance(ASSET_B_ID, &ASHLEY), 0);
+		assert_eq!(AssetIndex::index_token_balance(&ASHLEY), expected);
+		assert_eq!(pallet::Deposits::<Test>::get(&ASHLEY).into_inner(), vec![(expected, 1)]);
+	})
+}
+
 #[test]
 fn deposit_fails_on_exceeding_limit() {
 	let deposit = 1_000;
@@ -552,6 +644,12 @@ fn redemption_fee_works_on_completing_withdraw() {
 		let new_block_number = LockupPeriod::get() + 1;
 		frame_system::Pallet::<Test>::set_block_number(new_block_number);
 		assert_ok!(AssetIndex::withdraw(Origin::signed(ASHLEY), total * 99 / 100));
+
+		// nothing to complete before the withdrawal period is over
+		assert_ok!(AssetIndex::complete_withdraw(Origin::signed(ASHLEY)));
+		assert_eq!(Currency::total_balance(ASSET_A_ID, &ASHLEY), 0);
+
+		frame_system::Pallet::<Test>::set_block_number(new_block_number + WithdrawalPeriod::get());
 		assert_ok!(AssetIndex::complete_withdraw(Origin::signed(ASHLEY)));
 
 		// times * deposit_amount * Rate.0 (1 / 10)
@@ -653,6 +751,515 @@ fn can_withdraw() {
 	})
 }
 
+#[test]
+fn on_idle_completes_matured_withdrawals() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetIndex::register_asset(
+			Origin::signed(ACCOUNT_ID),
+			ASSET_A_ID,
+			AssetAvailability::Liquid(MultiLocation::default())
+		));
+		assert_ok!(AssetIndex::add_asset(Origin::signed(ACCOUNT_ID), ASSET_A_ID, 100, 500));
+		assert_ok!(Currency::deposit(ASSET_A_ID, &ASHLEY, 1_000));
+		assert_ok!(AssetIndex::deposit(Origin::signed(ASHLEY), ASSET_A_ID, 1_000));
+
+		let withdraw_block = LockupPeriod::get() + 1;
+		frame_system::Pallet::<Test>::set_block_number(withdraw_block);
+		assert_ok!(AssetIndex::withdraw(Origin::signed(ASHLEY), AssetIndex::index_token_balance(&ASHLEY)));
+
+		// nothing to complete before the withdrawal period is over
+		AssetIndex::on_idle(withdraw_block, Weight::MAX);
+		assert!(pallet::PendingWithdrawals::<Test>::get(&ASHLEY).is_some());
+		assert_eq!(Currency::total_balance(ASSET_A_ID, &ASHLEY), 0);
+
+		let end_block = withdraw_block + WithdrawalPeriod::get();
+		frame_system::Pallet::<Test>::set_block_number(end_block);
+		AssetIndex::on_idle(end_block, Weight::MAX);
+		assert_eq!(pallet::PendingWithdrawals::<Test>::get(&ASHLEY), None);
+		assert!(Currency::total_balance(ASSET_A_ID, &ASHLEY) > 0);
+		// all accounts were visited, the next block starts over
+		assert_eq!(pallet::WithdrawalCursor::<Test>::get(), None);
+	})
+}
+
+#[test]
+fn quote_withdraw_matches_withdraw() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetIndex::register_asset(
+			Origin::signed(ACCOUNT_ID),
+			ASSET_A_ID,
+			AssetAvailability::Liquid(MultiLocation::default())
+		));
+		assert_ok!(AssetIndex::register_asset(
+			Origin::signed(ACCOUNT_ID),
+			ASSET_B_ID,
+			AssetAvailability::Liquid(MultiLocation::default())
+		));
+		assert_ok!(AssetIndex::add_asset(Origin::signed(ACCOUNT_ID), ASSET_A_ID, 100, 500));
+		assert_ok!(AssetIndex::add_asset(Origin::signed(ACCOUNT_ID), ASSET_B_ID, 3000, 100));
+
+		assert_ok!(Currency::deposit(ASSET_A_ID, &ASHLEY, 1_000));
+		assert_ok!(AssetIndex::deposit(Origin::signed(ASHLEY), ASSET_A_ID, 1_000));
+
+		// no deposits to redeem
+		assert_noop!(AssetIndex::quote_withdraw(&ACCOUNT_ID, 1_000), pallet::Error::<Test>::NoDeposits);
+		assert_noop!(AssetIndex::quote_withdraw(&ASHLEY, 1), pallet::Error::<Test>::MinimumRedemption);
+
+		frame_system::Pallet::<Test>::set_block_number(LockupPeriod::get() + 1);
+
+		let balance = AssetIndex::index_token_balance(&ASHLEY);
+		let amount = balance / 2;
+		let quote = AssetIndex::quote_withdraw(&ASHLEY, amount).expect("quote should succeed");
+		assert_eq!(quote.asset_amounts.len(), 2);
+		assert!(quote.effectively_withdrawn <= amount);
+
+		// quoting does not alter any state
+		assert_eq!(pallet::Deposits::<Test>::get(&ASHLEY).len(), 1);
+
+		assert_ok!(AssetIndex::withdraw(Origin::signed(ASHLEY), amount));
+		assert_eq!(AssetIndex::index_token_balance(&ASHLEY), balance - quote.effectively_withdrawn);
+
+		let pending =
+			pallet::PendingWithdrawals::<Test>::get(&ASHLEY).expect("pending withdrawals should be present").remove(0);
+		assert_eq!(pending.end_block, quote.end_block);
+		assert_eq!(
+			pending.assets.into_iter().map(|withdrawal| (withdrawal.asset, withdrawal.units)).collect::<Vec<_>>(),
+			quote.asset_amounts
+		);
+	})
+}
+
+#[test]
+fn withdraw_with_min_out_fails_below_minimum() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetIndex::register_asset(
+			Origin::signed(ACCOUNT_ID),
+			ASSET_A_ID,
+			AssetAvailability::Liquid(MultiLocation::default())
+		));
+		assert_ok!(AssetIndex::add_asset(Origin::signed(ACCOUNT_ID), ASSET_A_ID, 100, 500));
+
+		assert_ok!(Currency::deposit(ASSET_A_ID, &ASHLEY, 1_000));
+		assert_ok!(AssetIndex::deposit(Origin::signed(ASHLEY), ASSET_A_ID, 1_000));
+		frame_system::Pallet::<Test>::set_block_number(LockupPeriod::get() + 1);
+
+		let amount = AssetIndex::index_token_balance(&ASHLEY);
+		let quote = AssetIndex::quote_withdraw(&ASHLEY, amount).unwrap();
+		let (asset, units) = quote.asset_amounts[0];
+
+		assert_noop!(
+			AssetIndex::withdraw_with_min_out(Origin::signed(ASHLEY), amount, vec![(asset, units + 1)]),
+			pallet::Error::<Test>::OutputBelowMinimum
+		);
+		// assets that are not part of the payout yield nothing
+		assert_noop!(
+			AssetIndex::withdraw_with_min_out(Origin::signed(ASHLEY), amount, vec![(UNKNOWN_ASSET_ID, 1)]),
+			pallet::Error::<Test>::OutputBelowMinimum
+		);
+		assert_noop!(
+			AssetIndex::withdraw_with_min_out(Origin::signed(ASHLEY), amount, vec![(asset, 1); 3]),
+			pallet::Error::<Test>::TooManyAssets
+		);
+		assert_ok!(AssetIndex::withdraw_with_min_out(Origin::signed(ASHLEY), amount, vec![(asset, units)]));
+		assert!(pallet::PendingWithdrawals::<Test>::get(&ASHLEY).is_some());
+	});
+}
+
+#[test]
+fn can_withdraw_to_single_asset() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetIndex::register_asset(
+			Origin::signed(ACCOUNT_ID),
+			ASSET_A_ID,
+			AssetAvailability::Liquid(MultiLocation::default())
+		));
+		assert_ok!(AssetIndex::register_asset(
+			Origin::signed(ACCOUNT_ID),
+			ASSET_B_ID,
+			AssetAvailability::Liquid(MultiLocation::default())
+		));
+		assert_ok!(AssetIndex::add_asset(Origin::signed(ACCOUNT_ID), ASSET_A_ID, 100, 500));
+		assert_ok!(AssetIndex::add_asset(Origin::signed(ACCOUNT_ID), ASSET_B_ID, 3000, 100));
+
+		assert_ok!(Currency::deposit(ASSET_A_ID, &ASHLEY, 1_000));
+		assert_ok!(AssetIndex::deposit(Origin::signed(ASHLEY), ASSET_A_ID, 1_000));
+		frame_system::Pallet::<Test>::set_block_number(LockupPeriod::get() + 1);
+
+		let amount = AssetIndex::index_token_balance(&ASHLEY);
+
+		// only liquid assets can be redeemed
+		assert_noop!(
+			AssetIndex::withdraw_to_asset(Origin::signed(ASHLEY), amount, UNKNOWN_ASSET_ID),
+			pallet::Error::<Test>::UnsupportedAsset
+		);
+
+		let quote = AssetIndex::quote_withdraw_to_asset(&ASHLEY, amount, ASSET_B_ID).unwrap();
+		assert!(!quote.single_asset_fee.is_zero());
+		assert_eq!(quote.single_asset_fee, amount / 100);
+		assert_eq!(quote.asset_amounts.len(), 1);
+		assert_eq!(quote.asset_amounts[0].0, ASSET_B_ID);
+
+		assert_ok!(AssetIndex::withdraw_to_asset(Origin::signed(ASHLEY), amount, ASSET_B_ID));
+		let pending =
+			pallet::PendingWithdrawals::<Test>::get(&ASHLEY).expect("pending withdrawals should be present").remove(0);
+		assert_eq!(pending.assets.len(), 1);
+		assert_eq!(pending.assets[0].asset, ASSET_B_ID);
+		assert_eq!(pending.assets[0].units, quote.asset_amounts[0].1);
+	})
+}
+
+#[test]
+fn withdraw_to_asset_respects_redemption_cap() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetIndex::register_asset(
+			Origin::signed(ACCOUNT_ID),
+			ASSET_A_ID,
+			AssetAvailability::Liquid(MultiLocation::default())
+		));
+		assert_ok!(AssetIndex::register_asset(
+			Origin::signed(ACCOUNT_ID),
+			ASSET_B_ID,
+			AssetAvailability::Liquid(MultiLocation::default())
+		));
+		assert_ok!(AssetIndex::add_asset(Origin::signed(ACCOUNT_ID), ASSET_A_ID, 100, 500));
+		assert_ok!(AssetIndex::add_asset(Origin::signed(ACCOUNT_ID), ASSET_B_ID, 10, 5));
+
+		assert_ok!(Currency::deposit(ASSET_A_ID, &ASHLEY, 1_000));
+		assert_ok!(AssetIndex::deposit(Origin::signed(ASHLEY), ASSET_A_ID, 1_000));
+		frame_system::Pallet::<Test>::set_block_number(LockupPeriod::get() + 1);
+
+		// the index holds too few units of asset B to cover the redemption
+		let amount = AssetIndex::index_token_balance(&ASHLEY);
+		assert_noop!(
+			AssetIndex::withdraw_to_asset(Origin::signed(ASHLEY), amount, ASSET_B_ID),
+			pallet::Error::<Test>::SingleAssetRedemptionCapExceeded
+		);
+		// redeeming the index token equivalent of the capped units of asset B stays within the cap
+		let cap = SingleAssetRedemptionCap::get().mul_floor(AssetIndex::index_free_asset_balance(ASSET_B_ID));
+		let within_cap = AssetIndex::index_token_equivalent(ASSET_B_ID, cap).unwrap();
+		assert_ok!(AssetIndex::withdraw_to_asset(Origin::signed(ASHLEY), within_cap, ASSET_B_ID));
+		let pending =
+			pallet::PendingWithdrawals::<Test>::get(&ASHLEY).expect("pending withdrawals should be present").remove(0);
+		assert_eq!(pending.assets.len(), 1);
+		assert_eq!(pending.assets[0].asset, ASSET_B_ID);
+		assert!(!pending.assets[0].units.is_zero());
+		assert!(pending.assets[0].units <= cap);
+	})
+}
+
+#[test]
+fn can_set_target_weights() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetIndex::register_asset(
+			Origin::signed(ACCOUNT_ID),
+			ASSET_A_ID,
+			AssetAvailability::Liquid(MultiLocation::default())
+		));
+		assert_ok!(AssetIndex::register_asset(
+			Origin::signed(ACCOUNT_ID),
+			ASSET_B_ID,
+			AssetAvailability::Liquid(MultiLocation::default())
+		));
+
+		let half = Ratio::saturating_from_rational(1, 2);
+		assert_noop!(
+			AssetIndex::set_target_weights(Origin::signed(ACCOUNT_ID), vec![(UNKNOWN_ASSET_ID, Ratio::one())]),
+			pallet::Error::<Test>::UnsupportedAsset
+		);
+		assert_noop!(
+			AssetIndex::set_target_weights(Origin::signed(ACCOUNT_ID), vec![(ASSET_A_ID, half)]),
+			pallet::Error::<Test>::InvalidTargetWeights
+		);
+		assert_noop!(
+			AssetIndex::set_target_weights(Origin::signed(ACCOUNT_ID), vec![(ASSET_A_ID, half), (ASSET_A_ID, half)]),
+			pallet::Error::<Test>::DuplicateAsset
+		);
+		assert_noop!(
+			AssetIndex::set_target_weights(Origin::signed(ACCOUNT_ID), vec![(ASSET_A_ID, Ratio::zero()); 3]),
+			pallet::Error::<Test>::TooManyTargets
+		);
+
+		assert_ok!(AssetIndex::set_target_weights(Origin::signed(ACCOUNT_ID), vec![(ASSET_A_ID, Ratio::one())]));
+		assert_ok!(AssetIndex::set_target_weights(
+			Origin::signed(ACCOUNT_ID),
+			vec![(ASSET_A_ID, half), (ASSET_B_ID, half)]
+		));
+		assert_eq!(AssetIndex::target_weight(ASSET_A_ID), Some(half));
+		assert_eq!(AssetIndex::target_weight(ASSET_B_ID), Some(half));
+
+		// replaces all previous targets
+		assert_ok!(AssetIndex::set_target_weights(Origin::signed(ACCOUNT_ID), vec![(ASSET_B_ID, Ratio::one())]));
+		assert_eq!(AssetIndex::target_weight(ASSET_A_ID), None);
+	})
+}
+
+#[test]
+fn rebalance_plan_reflects_drift() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetIndex::register_asset(
+			Origin::signed(ACCOUNT_ID),
+			ASSET_A_ID,
+			AssetAvailability::Liquid(MultiLocation::default())
+		));
+		assert_ok!(AssetIndex::register_asset(
+			Origin::signed(ACCOUNT_ID),
+			ASSET_B_ID,
+			AssetAvailability::Liquid(MultiLocation::default())
+		));
+		// both assets are worth the same
+		assert_ok!(AssetIndex::add_asset(Origin::signed(ACCOUNT_ID), ASSET_A_ID, 3_000, 500));
+		assert_ok!(AssetIndex::add_asset(Origin::signed(ACCOUNT_ID), ASSET_B_ID, 2_000, 500));
+
+		// no targets, nothing to rebalance
+		assert!(AssetIndex::rebalance_plan().unwrap().is_empty());
+		assert_noop!(
+			AssetIndex::announce_rebalance(Origin::signed(ACCOUNT_ID)),
+			pallet::Error::<Test>::NoRebalanceRequired
+		);
+
+		let half = Ratio::saturating_from_rational(1, 2);
+		assert_ok!(AssetIndex::set_target_weights(
+			Origin::signed(ACCOUNT_ID),
+			vec![(ASSET_A_ID, half), (ASSET_B_ID, half)]
+		));
+		assert!(AssetIndex::rebalance_plan().unwrap().is_empty());
+
+		// target 25% A and 75% B: sell a quarter of the index' value in A and buy it in B
+		assert_ok!(AssetIndex::set_target_weights(
+			Origin::signed(ACCOUNT_ID),
+			vec![
+				(ASSET_A_ID, Ratio::saturating_from_rational(1, 4)),
+				(ASSET_B_ID, Ratio::saturating_from_rational(3, 4))
+			]
+		));
+		let plan = AssetIndex::rebalance_plan().unwrap();
+		assert_eq!(plan.sells, vec![(ASSET_A_ID, 1_500)]);
+		assert_eq!(plan.buys, vec![(ASSET_B_ID, 1_000)]);
+
+		// a drift below the threshold is tolerated
+		assert_ok!(AssetIndex::set_rebalance_threshold(
+			Origin::signed(ACCOUNT_ID),
+			Ratio::saturating_from_rational(3, 10)
+		));
+		assert!(AssetIndex::rebalance_plan().unwrap().is_empty());
+
+		assert_ok!(AssetIndex::set_rebalance_threshold(
+			Origin::signed(ACCOUNT_ID),
+			Ratio::saturating_from_rational(1, 10)
+		));
+		assert_noop!(
+			AssetIndex::complete_rebalance(Origin::signed(ACCOUNT_ID)),
+			pallet::Error::<Test>::NoPendingRebalance
+		);
+		let plan = AssetIndex::rebalance_plan().unwrap();
+		assert_ok!(AssetIndex::announce_rebalance(Origin::signed(ACCOUNT_ID)));
+		assert_eq!(AssetIndex::pending_rebalance(), Some(plan));
+		assert_noop!(
+			AssetIndex::announce_rebalance(Origin::signed(ACCOUNT_ID)),
+			pallet::Error::<Test>::RebalancePending
+		);
+
+		assert_ok!(AssetIndex::complete_rebalance(Origin::signed(ACCOUNT_ID)));
+		assert_eq!(AssetIndex::pending_rebalance(), None);
+	})
+}
+
+#[test]
+fn can_accrue_management_fee() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetIndex::set_management_fee_rate(Origin::signed(ACCOUNT_ID), FeeRate { numerator: 2, denominator: 1 }),
+			pallet::Error::<Test>::InvalidManagementFeeRate
+		);
+
+		assert_ok!(AssetIndex::register_asset(
+			Origin::signed(ACCOUNT_ID),
+			ASSET_A_ID,
+			AssetAvailability::Liquid(MultiLocation::default())
+		));
+		assert_ok!(AssetIndex::add_asset(Origin::signed(ACCOUNT_ID), ASSET_A_ID, 1_000, 1_000));
+
+		// no fee accrues without a rate
+		AssetIndex::on_initialize(2);
+		assert_eq!(AssetIndex::accrued_management_fees(), 0);
+
+		// 10% per year
+		assert_ok!(AssetIndex::set_management_fee_rate(
+			Origin::signed(ACCOUNT_ID),
+			FeeRate { numerator: 1, denominator: 10 }
+		));
+		let start = frame_system::Pallet::<Test>::block_number();
+
+		// a tenth of a year passed
+		let now = start + BlocksPerYear::get() / 10;
+		AssetIndex::on_initialize(now);
+		assert_eq!(AssetIndex::accrued_management_fees(), 10);
+		assert_eq!(AssetIndex::index_token_balance(&AssetIndex::treasury_account()), 10);
+		assert_eq!(AssetIndex::index_token_issuance(), 1_010);
+
+		// already accrued for this block
+		assert_eq!(AssetIndex::accrue_management_fee(now), 0);
+
+		// fees that round down to zero are carried over
+		assert_eq!(AssetIndex::accrue_management_fee(now + 1), 0);
+		assert_eq!(AssetIndex::accrue_management_fee(now + 10), 1);
+		assert_eq!(AssetIndex::accrued_management_fees(), 11);
+	})
+}
+
+#[test]
+fn records_nav_snapshots() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetIndex::register_asset(
+			Origin::signed(ACCOUNT_ID),
+			ASSET_A_ID,
+			AssetAvailability::Liquid(MultiLocation::default())
+		));
+		assert_ok!(AssetIndex::add_asset(Origin::signed(ACCOUNT_ID), ASSET_A_ID, 1_000, 1_000));
+
+		// only recorded at the interval
+		AssetIndex::on_initialize(NavSnapshotInterval::get() - 1);
+		assert!(AssetIndex::nav_snapshots().is_empty());
+
+		for i in 1..=MaxNavSnapshots::get() as u64 + 1 {
+			AssetIndex::on_initialize(NavSnapshotInterval::get() * i);
+		}
+
+		// the oldest snapshot was replaced
+		let snapshots = AssetIndex::nav_snapshots();
+		assert_eq!(snapshots.len() as u32, MaxNavSnapshots::get());
+		assert_eq!(snapshots[0].block, NavSnapshotInterval::get() * 2);
+		assert_eq!(snapshots.last().unwrap().block, NavSnapshotInterval::get() * (MaxNavSnapshots::get() as u64 + 1));
+
+		let snapshot = snapshots.last().unwrap();
+		assert_eq!(snapshot.nav, AssetIndex::nav().unwrap());
+		assert_eq!(snapshot.liquid_nav, AssetIndex::liquid_nav().unwrap());
+		assert_eq!(snapshot.saft_nav, AssetIndex::saft_nav().unwrap());
+	})
+}
+
+#[test]
+fn admin_can_pause_the_index() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetIndex::register_asset(
+			Origin::signed(ACCOUNT_ID),
+			ASSET_A_ID,
+			AssetAvailability::Liquid(MultiLocation::default())
+		));
+		assert_noop!(AssetIndex::pause(Origin::signed(ASHLEY)), BadOrigin);
+
+		assert_ok!(AssetIndex::pause(Origin::signed(ACCOUNT_ID)));
+		assert_eq!(AssetIndex::pause_reason(), Some(types::PauseReason::Manual));
+		assert_noop!(AssetIndex::pause(Origin::signed(ACCOUNT_ID)), pallet::Error::<Test>::AlreadyPaused);
+
+		assert_ok!(Currency::deposit(ASSET_A_ID, &ASHLEY, 1_000));
+		assert_noop!(
+			AssetIndex::deposit(Origin::signed(ASHLEY), ASSET_A_ID, 1_000),
+			pallet::Error::<Test>::IndexPaused
+		);
+
+		assert_ok!(AssetIndex::resume(Origin::signed(ACCOUNT_ID)));
+		assert_eq!(AssetIndex::pause_reason(), None);
+	})
+}
+
+#[test]
+fn circuit_breaker_pauses_on_stale_price_feed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetIndex::register_asset(
+			Origin::signed(ACCOUNT_ID),
+			ASSET_A_ID,
+			AssetAvailability::Liquid(MultiLocation::default())
+		));
+		assert_ok!(AssetIndex::add_asset(Origin::signed(ACCOUNT_ID), ASSET_A_ID, 1_000, 1_000));
+		assert_noop!(AssetIndex::resume(Origin::signed(ACCOUNT_ID)), pallet::Error::<Test>::NotPaused);
+
+		let now = MaxPriceAge::get() + 2;
+		MockPriceFeed::set_price_updated_at(ASSET_A_ID, 1);
+		frame_system::Pallet::<Test>::set_block_number(now);
+		AssetIndex::on_initialize(now);
+		assert_eq!(AssetIndex::pause_reason(), Some(types::PauseReason::StalePriceFeed(ASSET_A_ID)));
+
+		assert_ok!(Currency::deposit(ASSET_A_ID, &ASHLEY, 1_000));
+		assert_noop!(
+			AssetIndex::deposit(Origin::signed(ASHLEY), ASSET_A_ID, 1_000),
+			pallet::Error::<Test>::IndexPaused
+		);
+		assert_noop!(AssetIndex::withdraw(Origin::signed(ACCOUNT_ID), 100), pallet::Error::<Test>::IndexPaused);
+
+		assert_ok!(AssetIndex::resume(Origin::signed(ACCOUNT_ID)));
+		assert_eq!(AssetIndex::pause_reason(), None);
+		assert_ok!(AssetIndex::deposit(Origin::signed(ASHLEY), ASSET_A_ID, 1_000));
+
+		// the circuit breaker is not checked within the grace period
+		AssetIndex::on_initialize(now + 1);
+		assert_eq!(AssetIndex::pause_reason(), None);
+
+		// but trips again once it is over and the feed is still stale
+		let grace_end = now + ResumeGracePeriod::get();
+		AssetIndex::on_initialize(grace_end);
+		AssetIndex::on_initialize(grace_end + 1);
+		assert_eq!(AssetIndex::pause_reason(), Some(types::PauseReason::StalePriceFeed(ASSET_A_ID)));
+	})
+}
+
+#[test]
+fn circuit_breaker_ignores_assets_without_price_feed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetIndex::register_asset(
+			Origin::signed(ACCOUNT_ID),
+			ASSET_A_ID,
+			AssetAvailability::Liquid(MultiLocation::default())
+		));
+		assert_ok!(AssetIndex::add_asset(Origin::signed(ACCOUNT_ID), ASSET_A_ID, 1_000, 1_000));
+		assert_ok!(AssetIndex::register_asset(
+			Origin::signed(ACCOUNT_ID),
+			UNKNOWN_ASSET_ID,
+			AssetAvailability::Liquid(MultiLocation::default())
+		));
+
+		// cycles through all assets, one price feed per block
+		for now in 1..=3 {
+			AssetIndex::on_initialize(now);
+			assert_eq!(AssetIndex::pause_reason(), None);
+		}
+	})
+}
+
+#[test]
+fn circuit_breaker_pauses_on_nav_deviation() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetIndex::register_asset(
+			Origin::signed(ACCOUNT_ID),
+			ASSET_A_ID,
+			AssetAvailability::Liquid(MultiLocation::default())
+		));
+		assert_ok!(AssetIndex::add_asset(Origin::signed(ACCOUNT_ID), ASSET_A_ID, 1_000, 1_000));
+
+		// the NAV is only checked when a snapshot is taken, the first one starts the window
+		let interval = NavSnapshotInterval::get();
+		AssetIndex::on_initialize(interval);
+		assert_eq!(pallet::NavReference::<Test>::get(), Some((interval, AssetIndex::nav().unwrap())));
+
+		// a 10% move is tolerated, the next window starts at the moved NAV
+		MockPriceFeed::set_prices(vec![(ASSET_A_ID, Price::saturating_from_rational(22, 10))]);
+		AssetIndex::on_initialize(2 * interval);
+		assert_eq!(AssetIndex::pause_reason(), None);
+		let reference = AssetIndex::nav().unwrap();
+
+		// a larger move is not checked before the next snapshot
+		MockPriceFeed::set_prices(vec![(ASSET_A_ID, Price::from(3))]);
+		AssetIndex::on_initialize(2 * interval + 1);
+		assert_eq!(AssetIndex::pause_reason(), None);
+
+		AssetIndex::on_initialize(3 * interval);
+		assert_eq!(
+			AssetIndex::pause_reason(),
+			Some(types::PauseReason::NavDeviation { reference, current: AssetIndex::nav().unwrap() })
+		);
+	})
+}
+
 #[test]
 fn add_asset_less_than_existential_deposit_is_noop() {
 	new_test_ext_with_balance(vec![]).execute_with(|| {
@@ -10,6 +10,10 @@ use frame_support::{
 	sp_std::vec::Vec,
 };
 
+pub use primitives::types::{
+	AssetWithdrawal, NavSnapshot, PauseReason, PendingRedemption, RebalancePlan, WithdrawalQuote,
+};
+
 /// Abstraction over the lock of minted index token that are locked up for
 /// `LockupPeriod`
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
@@ -28,28 +32,6 @@ pub struct AssetMetadata<BoundedString> {
 	pub decimals: u8,
 }
 
-/// Represents a single asset being withdrawn
-#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
-pub struct AssetWithdrawal<AssetId, Balance> {
-	/// The identifier of the asset
-	pub asset: AssetId,
-	/// The amount of asset units about to be transferred to the LP.
-	pub units: Balance,
-	/// The amount still reserved for this withdrawal.
-	pub reserved: Balance,
-	/// Whether this withdrawal was already been closed.
-	pub withdrawn: bool,
-}
-
-#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
-/// Describes an in progress withdrawal of a collection of assets from the index
-pub struct PendingRedemption<AssetId, Balance, BlockNumber> {
-	/// The block after which the redemption process is over.
-	pub end_block: BlockNumber,
-	/// All the withdrawals resulted from the redemption.
-	pub assets: Vec<AssetWithdrawal<AssetId, Balance>>,
-}
-
 /// Represents the redemption of a given pint amount based on the
 /// `AssetDistribution`.
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
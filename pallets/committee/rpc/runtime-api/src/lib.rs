@@ -0,0 +1,30 @@
+// Copyright 2021 ChainSafe Systems
+// SPDX-License-Identifier: LGPL-3.0-only
+
+//! Runtime API definition for the committee pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use sp_runtime::DispatchError;
+use sp_std::prelude::*;
+
+use primitives::{ProposalInfo, VotingWindow};
+
+sp_api::decl_runtime_apis! {
+	pub trait CommitteeApi<AccountId, Hash, BlockNumber> where
+		AccountId: Codec,
+		Hash: Codec,
+		BlockNumber: Codec,
+	{
+		/// All proposals that are currently active, with their live tally
+		fn get_active_proposals() -> Vec<ProposalInfo<AccountId, Hash, BlockNumber>>;
+
+		/// The proposal with the given hash, with its live tally
+		fn get_proposal(proposal_hash: Hash) -> Option<ProposalInfo<AccountId, Hash, BlockNumber>>;
+
+		/// The boundaries of the current submission and voting periods
+		fn get_voting_window() -> Result<VotingWindow<BlockNumber>, DispatchError>;
+	}
+}
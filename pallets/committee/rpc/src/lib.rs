@@ -0,0 +1,135 @@
+// Copyright 2021 ChainSafe Systems
+// SPDX-License-Identifier: LGPL-3.0-only
+
+//! RPC interface for the committee pallet.
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use primitives::{ProposalInfo, VotingWindow};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT, DispatchError};
+use std::sync::Arc;
+
+pub use self::gen_client::Client as CommitteeClient;
+pub use pallet_committee_rpc_runtime_api::CommitteeApi as CommitteeRuntimeApi;
+
+/// Committee state API
+#[rpc]
+pub trait CommitteeApi<BlockHash, AccountId, Hash, BlockNumber> {
+	#[rpc(name = "committee_getActiveProposals")]
+	fn get_active_proposals(&self, at: Option<BlockHash>) -> Result<Vec<ProposalInfo<AccountId, Hash, BlockNumber>>>;
+
+	#[rpc(name = "committee_getProposal")]
+	fn get_proposal(
+		&self,
+		proposal_hash: Hash,
+		at: Option<BlockHash>,
+	) -> Result<Option<ProposalInfo<AccountId, Hash, BlockNumber>>>;
+
+	#[rpc(name = "committee_getVotingWindow")]
+	fn get_voting_window(&self, at: Option<BlockHash>) -> Result<VotingWindow<BlockNumber>>;
+}
+
+/// A struct that implements the [`CommitteeApi`].
+pub struct CommitteeBackend<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> CommitteeBackend<C, B> {
+	/// Create new `Committee` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		CommitteeBackend { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block> CommitteeBackend<C, Block>
+where
+	Block: BlockT,
+	C: HeaderBackend<Block>,
+{
+	/// Returns the block for the given hash or the best block, if no hash was supplied.
+	fn block_id(&self, at: Option<<Block as BlockT>::Hash>) -> BlockId<Block> {
+		BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		))
+	}
+}
+
+pub enum Error {
+	RuntimeError,
+	DispatchError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::DispatchError => 2,
+		}
+	}
+}
+
+/// Converts a runtime api error into an rpc error
+fn runtime_error(message: &str, e: impl std::fmt::Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(Error::RuntimeError.into()),
+		message: message.into(),
+		data: Some(format!("{:?}", e).into()),
+	}
+}
+
+/// Converts an error that was returned by the runtime call into an rpc error
+fn dispatch_error(message: &str, e: DispatchError) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(Error::DispatchError.into()),
+		message: message.into(),
+		data: Some(format!("{:?}", e).into()),
+	}
+}
+
+impl<C, Block, AccountId, Hash, BlockNumber> CommitteeApi<<Block as BlockT>::Hash, AccountId, Hash, BlockNumber>
+	for CommitteeBackend<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: CommitteeRuntimeApi<Block, AccountId, Hash, BlockNumber>,
+	AccountId: Codec,
+	Hash: Codec,
+	BlockNumber: Codec,
+{
+	fn get_active_proposals(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<ProposalInfo<AccountId, Hash, BlockNumber>>> {
+		let at = self.block_id(at);
+		self.client
+			.runtime_api()
+			.get_active_proposals(&at)
+			.map_err(|e| runtime_error("Unable to get active proposals.", e))
+	}
+
+	fn get_proposal(
+		&self,
+		proposal_hash: Hash,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<ProposalInfo<AccountId, Hash, BlockNumber>>> {
+		let at = self.block_id(at);
+		self.client
+			.runtime_api()
+			.get_proposal(&at, proposal_hash)
+			.map_err(|e| runtime_error("Unable to get proposal.", e))
+	}
+
+	fn get_voting_window(&self, at: Option<<Block as BlockT>::Hash>) -> Result<VotingWindow<BlockNumber>> {
+		let at = self.block_id(at);
+		self.client
+			.runtime_api()
+			.get_voting_window(&at)
+			.map_err(|e| runtime_error("Unable to get voting window.", e))?
+			.map_err(|e| dispatch_error("Failed to compute voting window.", e))
+	}
+}
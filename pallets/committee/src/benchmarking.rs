@@ -4,7 +4,8 @@ use super::*;
 use frame_benchmarking::{account, benchmarks, vec, Box};
 use frame_support::{
 	assert_noop, assert_ok,
-	traits::{EnsureOrigin, Get, Hooks, UnfilteredDispatchable},
+	sp_runtime::Percent,
+	traits::{Currency, EnsureOrigin, Get, Hooks, UnfilteredDispatchable},
 };
 use frame_system::{ensure_signed, Call as SystemCall, Pallet as System, RawOrigin as SystemOrigin};
 
@@ -14,6 +15,10 @@ fn submit_proposal<T: Config>(origin: <T as frame_system::Config>::Origin) -> pa
 
 	let account_id = ensure_signed(origin.clone()).unwrap();
 	assert_ok!(<Pallet<T>>::add_constituent(SystemOrigin::Root.into(), account_id.clone()));
+	T::Currency::make_free_balance_be(
+		&account_id,
+		T::Currency::minimum_balance() + T::ProposalDeposit::get() * 10_u32.into(),
+	);
 	<System<T>>::set_block_number(
 		<System<T>>::block_number() +
 			<T as Config>::VotingPeriod::get() +
@@ -21,12 +26,18 @@ fn submit_proposal<T: Config>(origin: <T as frame_system::Config>::Origin) -> pa
 			1_u32.into(),
 	);
 
-	let call = Call::<T>::propose { action: Box::new(action.clone()) };
+	let call = Call::<T>::propose { action: Box::new(action.clone()), metadata: Default::default() };
 	assert_ok!(call.dispatch_bypass_filter(origin));
 
 	pallet::Proposal::<T>::new(action, account_id, expected_nonce, ProposalStatus::Active)
 }
 
+/// Metadata of the maximum length
+fn max_metadata<T: Config>() -> ProposalMetadata<Vec<u8>> {
+	let limit = T::StringLimit::get() as usize;
+	ProposalMetadata { title: vec![b'a'; limit], description: vec![b'a'; limit] }
+}
+
 fn run_to_block<T: Config>(n: T::BlockNumber) {
 	while System::<T>::block_number() < n {
 		System::<T>::set_block_number(System::<T>::block_number() + 1u32.into());
@@ -38,13 +49,28 @@ benchmarks! {
 	propose {
 		let origin = T::ProposalSubmissionOrigin::successful_origin();
 		let proposal = submit_proposal::<T>(origin.clone());
-		let call = Call::<T>::propose{action: Box::new(SystemCall::<T>::remark{remark:vec![0; 0]}.into())};
+		let call = Call::<T>::propose{action: Box::new(SystemCall::<T>::remark{remark:vec![0; 0]}.into()), metadata: max_metadata::<T>()};
 	}: {
 		call.dispatch_bypass_filter(origin)?
 	} verify {
 		assert!(<Pallet<T>>::get_proposal(&proposal.hash()) == Some(proposal));
 	}
 
+	propose_batch {
+		let n in 1 .. T::MaxBatchSize::get();
+		let origin = T::ProposalSubmissionOrigin::successful_origin();
+		let _ = submit_proposal::<T>(origin.clone());
+		let actions: Vec<T::Action> = (0..n).map(|_| SystemCall::<T>::remark{remark:vec![0; 0]}.into()).collect();
+		let expected_nonce = pallet::ProposalCount::<T>::get();
+		let call = Call::<T>::propose_batch{actions: actions.clone(), metadata: max_metadata::<T>()};
+	}: {
+		call.dispatch_bypass_filter(origin.clone())?
+	} verify {
+		let issuer = ensure_signed(origin).unwrap();
+		let batch = pallet::Proposal::<T>::batch(actions, issuer, expected_nonce, ProposalStatus::Active);
+		assert!(<Pallet<T>>::get_proposal(&batch.hash()) == Some(batch));
+	}
+
 	vote {
 		let origin = T::ProposalSubmissionOrigin::successful_origin();
 		let proposal = submit_proposal::<T>(origin.clone());
@@ -68,6 +94,7 @@ benchmarks! {
 	}
 
 	close {
+		let d in 0 .. T::MaxDelegations::get();
 		let proposal: pallet::Proposal<T> = submit_proposal::<T>(T::ProposalSubmissionOrigin::successful_origin());
 
 		// vote
@@ -85,6 +112,12 @@ benchmarks! {
 			}));
 		}
 
+		// constituents that delegated their vote to a voter
+		for i in 0 .. d {
+			let delegator: T::AccountId = account("delegator", i, 0);
+			<pallet::Delegations<T>>::insert(delegator, account::<T::AccountId>("voter", 0, 0));
+		}
+
 		// run out of voting period
 		<System<T>>::set_block_number(
 			<System<T>>::block_number()
@@ -104,6 +137,58 @@ benchmarks! {
 		);
 	}
 
+	withdraw_proposal {
+		let origin = T::ProposalSubmissionOrigin::successful_origin();
+		let proposal = submit_proposal::<T>(origin.clone());
+		let call = Call::<T>::withdraw_proposal{ proposal_hash: proposal.hash() };
+	}: {
+		call.dispatch_bypass_filter(origin)?
+	} verify {
+		assert_eq!(
+			<Pallet<T>>::get_proposal(&proposal.hash()).map(|proposal| proposal.status),
+			Some(ProposalStatus::Cancelled)
+		);
+	}
+
+	delegate {
+		let delegator: T::AccountId = account("constituent", 0, 0);
+		let delegate: T::AccountId = account("council", 0, 0);
+		assert_ok!(<Pallet<T>>::add_constituent(SystemOrigin::Root.into(), delegator.clone()));
+		<pallet::VotingEligibility<T>>::insert(&delegator, <System<T>>::block_number());
+		<pallet::Members<T>>::insert(&delegate, MemberType::Council);
+	}: _(
+		SystemOrigin::Signed(delegator.clone()),
+		delegate.clone()
+	) verify {
+		assert_eq!(<pallet::Delegations<T>>::get(delegator), Some(delegate));
+	}
+
+	undelegate {
+		let delegator: T::AccountId = account("constituent", 0, 0);
+		let delegate: T::AccountId = account("council", 0, 0);
+		assert_ok!(<Pallet<T>>::add_constituent(SystemOrigin::Root.into(), delegator.clone()));
+		<pallet::VotingEligibility<T>>::insert(&delegator, <System<T>>::block_number());
+		<pallet::Members<T>>::insert(&delegate, MemberType::Council);
+		assert_ok!(<Pallet<T>>::delegate(SystemOrigin::Signed(delegator.clone()).into(), delegate));
+	}: _(
+		SystemOrigin::Signed(delegator.clone())
+	) verify {
+		assert!(!<pallet::Delegations<T>>::contains_key(delegator));
+	}
+
+	cancel_queued_proposal {
+		let proposal = submit_proposal::<T>(T::ProposalSubmissionOrigin::successful_origin());
+		let at = <System<T>>::block_number() + <T as Config>::EnactmentDelay::get();
+		let votes = <Pallet<T>>::get_votes_for(&proposal.hash()).unwrap();
+		<pallet::EnactmentAgenda<T>>::append(at, proposal.hash());
+		<pallet::QueuedProposals<T>>::insert(proposal.hash(), Enactment { at, votes });
+	}: _(
+		SystemOrigin::Root,
+		proposal.hash()
+	) verify {
+		assert!(<Pallet<T>>::queued_proposals().is_empty());
+	}
+
 	add_constituent {
 		let constituent: T::AccountId = account("constituent", 0, 0);
 	}: _(
@@ -132,4 +217,57 @@ benchmarks! {
 		run_to_block::<T>(<T as Config>::VotingPeriod::get());
 		assert_eq!(pallet::VotingPeriod::<T>::get(), two_weeks);
 	}
+
+	nominate {
+		let candidate: T::AccountId = account("candidate", 0, 0);
+		assert_ok!(<Pallet<T>>::add_constituent(SystemOrigin::Root.into(), candidate.clone()));
+	}: _(
+		SystemOrigin::Signed(candidate.clone())
+	) verify {
+		assert_eq!(<pallet::CouncilCandidates<T>>::get(candidate), Some(0));
+	}
+
+	vote_candidate {
+		let candidate: T::AccountId = account("candidate", 0, 0);
+		assert_ok!(<Pallet<T>>::add_constituent(SystemOrigin::Root.into(), candidate.clone()));
+		assert_ok!(<Pallet<T>>::nominate(SystemOrigin::Signed(candidate.clone()).into()));
+
+		// vote for another candidate first so that the vote is moved
+		let other: T::AccountId = account("candidate", 1, 0);
+		assert_ok!(<Pallet<T>>::add_constituent(SystemOrigin::Root.into(), other.clone()));
+		assert_ok!(<Pallet<T>>::nominate(SystemOrigin::Signed(other.clone()).into()));
+
+		let voter: T::AccountId = account("constituent", 0, 0);
+		assert_ok!(<Pallet<T>>::add_constituent(SystemOrigin::Root.into(), voter.clone()));
+		<pallet::VotingEligibility<T>>::insert(&voter, <System<T>>::block_number());
+		assert_ok!(<Pallet<T>>::vote_candidate(SystemOrigin::Signed(voter.clone()).into(), other.clone()));
+	}: _(
+		SystemOrigin::Signed(voter.clone()),
+		candidate.clone()
+	) verify {
+		assert_eq!(<pallet::CouncilCandidates<T>>::get(candidate), Some(1));
+		assert_eq!(<pallet::CouncilCandidates<T>>::get(other), Some(0));
+	}
+
+	set_vote_threshold {
+		let threshold = VoteThreshold {
+			quorum: Quorum::Percent(Percent::from_percent(50)),
+			approval: Approval::Unanimous,
+			constituent_veto: false,
+		};
+	}: _(
+		SystemOrigin::Root,
+		(0, 0),
+		Some(threshold.clone())
+	) verify {
+		assert_eq!(<pallet::VoteThresholds<T>>::get((0, 0)), Some(threshold));
+	}
+
+	set_vote_weighting {
+	}: _(
+		SystemOrigin::Root,
+		VoteWeighting::LockedIndexTokens
+	) verify {
+		assert_eq!(<pallet::ConstituentVoteWeighting<T>>::get(), VoteWeighting::LockedIndexTokens);
+	}
 }
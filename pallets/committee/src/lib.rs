@@ -8,6 +8,10 @@
 //! the Governance Committee. Members may be added, removed or swapped
 //! with new members. There is no bound on how many members may exist
 //! in the committee.
+//!
+//! The council is re-elected at the end of every council term, which spans a
+//! fixed number of voting epochs. Members nominate themselves as candidates
+//! and constituents vote for the candidate they want to see on the council.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -15,6 +19,7 @@ pub use pallet::*;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migrations;
 #[cfg(test)]
 mod mock;
 
@@ -37,7 +42,15 @@ pub mod pallet {
 		dispatch::{Codec, DispatchResultWithPostInfo},
 		pallet_prelude::*,
 		sp_runtime::traits::{CheckedAdd, Dispatchable, One, Saturating, Zero},
-		sp_std::{boxed::Box, prelude::*, vec::Vec},
+		sp_std::{
+			boxed::Box,
+			convert::{TryFrom, TryInto},
+			prelude::*,
+			slice,
+			vec::Vec,
+		},
+		storage::{with_transaction, TransactionOutcome},
+		traits::{Contains, Currency, GetCallMetadata, PalletInfoAccess, ReservableCurrency},
 		transactional,
 		weights::{GetDispatchInfo, PostDispatchInfo},
 	};
@@ -46,6 +59,7 @@ pub mod pallet {
 	type AccountIdFor<T> = <T as frame_system::Config>::AccountId;
 	type HashFor<T> = <T as frame_system::Config>::Hash;
 	type BlockNumberFor<T> = <T as frame_system::Config>::BlockNumber;
+	type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdFor<T>>>::Balance;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -69,6 +83,12 @@ pub mod pallet {
 			+ CheckedAdd
 			+ scale_info::TypeInfo;
 
+		/// Maximum number of actions a single batch proposal may contain
+		type MaxBatchSize: Get<u32>;
+
+		/// The maximum length of the title and the description of a proposal
+		type StringLimit: Get<u32>;
+
 		/// Duration (in blocks) of the proposal submission period
 		type ProposalSubmissionPeriod: Get<Self::BlockNumber>;
 
@@ -82,6 +102,79 @@ pub mod pallet {
 		/// passed
 		type MinCouncilVotes: Get<usize>;
 
+		/// Maximum number of council members elected at the end of a council
+		/// term
+		type CouncilSeats: Get<u32>;
+
+		/// Maximum number of members that can stand for a council election
+		type MaxCouncilCandidates: Get<u32>;
+
+		/// Duration of a council term, in voting epochs (a proposal submission
+		/// period followed by a voting period)
+		type CouncilTermLength: Get<u32>;
+
+		/// The currency used for proposal deposits
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The amount reserved from the issuer when submitting a proposal,
+		/// refunded once the proposal is pruned
+		type ProposalDeposit: Get<BalanceOf<Self>>;
+
+		/// Duration (in blocks) finished proposals are kept after the end of
+		/// their voting period before they are pruned. This should exceed the
+		/// voting period, so that accepted proposals can still be closed.
+		type ProposalRetentionPeriod: Get<Self::BlockNumber>;
+
+		/// Maximum number of inactive proposals that are checked for pruning
+		/// per voting cycle
+		type MaxPrunedProposals: Get<u32>;
+
+		/// Maximum number of constituents that can delegate their vote at the
+		/// same time
+		type MaxDelegations: Get<u32>;
+
+		/// Maximum number of cached constituent weights of expired voting
+		/// periods that are removed per block
+		type MaxWeightRemovals: Get<u32>;
+
+		/// Maximum number of constituents, which bounds the constituent weights
+		/// snapshot at the start of every voting period
+		type MaxConstituents: Get<u32>;
+
+		/// Duration (in blocks) accepted proposals are queued before they are
+		/// executed. Zero executes accepted proposals when they are closed.
+		///
+		/// Cancelling a queued proposal requires another proposal, so this
+		/// must exceed the longest voting period plus the proposal submission
+		/// period.
+		type EnactmentDelay: Get<Self::BlockNumber>;
+
+		/// Maximum number of queued proposals that are executed per block, the
+		/// remaining proposals are carried over to the next block
+		type MaxEnactmentsPerBlock: Get<u32>;
+
+		/// The threshold a proposal must satisfy to skip the enactment delay,
+		/// in addition to the threshold of its action
+		type ExpeditedThreshold: Get<VoteThreshold>;
+
+		/// The protective actions that can be proposed as emergency proposals
+		type EmergencyCalls: Contains<Self::Action>;
+
+		/// Duration (in blocks) of the voting period of emergency proposals,
+		/// which starts as soon as they are proposed
+		type EmergencyVotingPeriod: Get<Self::BlockNumber>;
+
+		/// The threshold an emergency proposal must satisfy once its voting
+		/// period is over, in addition to the threshold of its action
+		///
+		/// Before the end of the voting period, emergency proposals can only be
+		/// closed with the ayes of two thirds of the whole council.
+		type EmergencyThreshold: Get<VoteThreshold>;
+
+		/// The index token holdings used to weigh constituent votes, unless
+		/// all votes are weighted equally
+		type IndexTokenHoldings: IndexTokenHoldings<Self::AccountId>;
+
 		/// Origin that is permitted to create proposals
 		type ProposalSubmissionOrigin: EnsureOrigin<
 			<Self as frame_system::Config>::Origin,
@@ -106,8 +199,12 @@ pub mod pallet {
 	#[pallet::origin]
 	pub type Origin<T> = CommitteeOrigin<AccountIdFor<T>, BlockNumberFor<T>>;
 
+	/// The current storage version
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	// Storage defs
@@ -126,10 +223,97 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Proposals<T: Config> = StorageMap<_, Identity, HashFor<T>, Proposal<T>, OptionQuery>;
 
+	/// Stores the hashes of all existing emergency proposals
+	#[pallet::storage]
+	pub type EmergencyProposals<T: Config> = StorageMap<_, Identity, HashFor<T>, (), OptionQuery>;
+
+	/// Store a mapping (hash) -> ProposalMetadata for all existing proposals.
+	#[pallet::storage]
+	pub type Metadata<T: Config> =
+		StorageMap<_, Identity, HashFor<T>, ProposalMetadata<BoundedVec<u8, T::StringLimit>>, OptionQuery>;
+
+	/// A queue of the hashes of proposals that can no longer be voted on, they
+	/// are pruned once their retention period is over
+	///
+	/// (Position) -> Hash
+	#[pallet::storage]
+	pub type InactiveProposals<T: Config> = StorageMap<_, Twox64Concat, u32, HashFor<T>, OptionQuery>;
+
+	/// The positions of the first and one past the last entry of the
+	/// `InactiveProposals` queue
+	#[pallet::storage]
+	pub type InactiveProposalsRange<T: Config> = StorageValue<_, (u32, u32), ValueQuery>;
+
+	/// Store a mapping (hash) -> deposit reserved from the issuer of the
+	/// proposal
+	#[pallet::storage]
+	pub type ProposalDeposits<T: Config> = StorageMap<_, Identity, HashFor<T>, BalanceOf<T>, ValueQuery>;
+
+	/// Maps constituents to the member their vote is delegated to
+	#[pallet::storage]
+	pub type Delegations<T: Config> = StorageMap<_, Blake2_128Concat, AccountIdFor<T>, AccountIdFor<T>, OptionQuery>;
+
+	/// The number of constituents that currently delegate their vote
+	#[pallet::storage]
+	pub type DelegationCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Snapshots of all `(delegator, delegate)` pairs taken at the end of the
+	/// last two voting periods, keyed by the end of the voting period
+	#[pallet::storage]
+	pub type DelegationSnapshots<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<(AccountIdFor<T>, AccountIdFor<T>), T::MaxDelegations>,
+		OptionQuery,
+	>;
+
+	/// The end blocks of the voting periods that have a delegation snapshot
+	#[pallet::storage]
+	pub type DelegationSnapshotEnds<T: Config> = StorageValue<_, Vec<BlockNumberFor<T>>, ValueQuery>;
+
+	/// The weighting of constituent votes, applied from the next voting period
+	/// on
+	#[pallet::storage]
+	pub type ConstituentVoteWeighting<T: Config> = StorageValue<_, VoteWeighting, ValueQuery>;
+
+	/// The constituent vote weightings snapshot at the start of every voting
+	/// period whose proposals can still be closed, keyed by the end of the
+	/// voting period
+	#[pallet::storage]
+	pub type VoteWeightingSnapshots<T: Config> =
+		StorageMap<_, Twox64Concat, BlockNumberFor<T>, VoteWeighting, OptionQuery>;
+
+	/// Store a mapping (voting period end, constituent) -> weight of the
+	/// constituent's votes, snapshot when the voting period starts
+	#[pallet::storage]
+	pub type ConstituentWeights<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Blake2_128Concat, AccountIdFor<T>, u128, ValueQuery>;
+
+	/// The ends of the expired voting periods whose cached constituent weights
+	/// are still being removed
+	#[pallet::storage]
+	pub type ExpiredWeightSnapshots<T: Config> = StorageValue<_, Vec<BlockNumberFor<T>>, ValueQuery>;
+
+	/// Store a mapping (hash) -> Enactment for all accepted proposals that
+	/// wait for their enactment delay to pass
+	#[pallet::storage]
+	pub type QueuedProposals<T: Config> =
+		StorageMap<_, Identity, HashFor<T>, Enactment<AccountIdFor<T>, BlockNumberFor<T>>, OptionQuery>;
+
+	/// Store a mapping (block number) -> hashes of the queued proposals that
+	/// are executed at that block
+	#[pallet::storage]
+	pub type EnactmentAgenda<T: Config> = StorageMap<_, Twox64Concat, BlockNumberFor<T>, Vec<HashFor<T>>, ValueQuery>;
+
 	/// Maps accountIDs to their member type (council or constituent)
 	#[pallet::storage]
 	pub type Members<T: Config> = StorageMap<_, Blake2_128Concat, AccountIdFor<T>, MemberType, OptionQuery>;
 
+	/// The number of constituents
+	#[pallet::storage]
+	pub type ConstituentCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
 	/// Store a mapping (hash) -> VoteAggregate for all existing proposals.
 	#[pallet::storage]
 	pub type Votes<T: Config> =
@@ -152,6 +336,39 @@ pub mod pallet {
 	pub type VotingEligibility<T: Config> =
 		StorageMap<_, Blake2_128Concat, AccountIdFor<T>, BlockNumberFor<T>, OptionQuery>;
 
+	/// Accounts that stand for the next council election, mapped to the number
+	/// of constituent votes they received so far
+	#[pallet::storage]
+	pub type CouncilCandidates<T: Config> = StorageMap<_, Blake2_128Concat, AccountIdFor<T>, u32, OptionQuery>;
+
+	/// The number of accounts in `CouncilCandidates`
+	#[pallet::storage]
+	pub type CouncilCandidateCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Maps constituents to the council election they voted in and the
+	/// candidate they voted for. Votes of earlier elections are ignored.
+	#[pallet::storage]
+	pub type CouncilVotes<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdFor<T>, (u32, AccountIdFor<T>), OptionQuery>;
+
+	/// The number of council elections that were concluded so far
+	#[pallet::storage]
+	pub type CouncilElection<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The current council members
+	#[pallet::storage]
+	pub type Council<T: Config> = StorageValue<_, Vec<AccountIdFor<T>>, ValueQuery>;
+
+	/// The number of voting epochs that have started since the current council
+	/// term began
+	#[pallet::storage]
+	pub type CouncilTermEpochs<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Approval thresholds for proposals, keyed by the call index of the
+	/// proposed action. Actions without an entry use the default threshold.
+	#[pallet::storage]
+	pub type VoteThresholds<T: Config> = StorageMap<_, Blake2_128Concat, CallIndex, VoteThreshold, OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub council_members: Vec<T::AccountId>,
@@ -174,11 +391,13 @@ pub mod pallet {
 				Members::<T>::insert(member, MemberType::Council);
 				VotingEligibility::<T>::insert(member, T::BlockNumber::zero());
 			}
+			Council::<T>::put(self.council_members.clone());
 
 			for member in &self.constituent_members {
 				Members::<T>::insert(member, MemberType::Constituent);
 				VotingEligibility::<T>::insert(member, T::BlockNumber::zero());
 			}
+			ConstituentCount::<T>::put(self.constituent_members.len() as u32);
 		}
 	}
 
@@ -188,15 +407,16 @@ pub mod pallet {
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// A new proposal has been created
-		/// \[proposer_address, proposal_nonce, proposal_hash\]
-		Proposed(AccountIdFor<T>, T::ProposalNonce, T::Hash),
+		/// \[proposer_address, proposal_nonce, proposal_hash, metadata\]
+		Proposed(AccountIdFor<T>, T::ProposalNonce, T::Hash, ProposalMetadata<Vec<u8>>),
 		/// A vote was cast
 		/// \[voter_address, proposal_hash, vote\]
 		VoteCast(CommitteeMember<AccountIdFor<T>>, T::Hash, VoteKind),
-		/// A proposal was closed and executed. Any errors for calling the
-		/// proposal action are included
-		/// \[proposal_hash, result\]
-		ClosedAndExecutedProposal(T::Hash, DispatchResult),
+		/// A proposal was closed and executed. Contains the result of every
+		/// dispatched action, dispatching stops at the first failing action
+		/// and reverts the entire proposal
+		/// \[proposal_hash, results\]
+		ClosedAndExecutedProposal(T::Hash, Vec<DispatchResult>),
 		/// A new consituent has been added
 		/// \[constituent_address]
 		NewConstituent(AccountIdFor<T>),
@@ -209,6 +429,49 @@ pub mod pallet {
 		/// A new voting period has been set
 		/// \[block_number]
 		NewVotingPeriod(BlockNumberFor<T>),
+		/// A member has nominated themselves for the next council election
+		/// \[candidate_address\]
+		CouncilCandidateNominated(AccountIdFor<T>),
+		/// A constituent has voted for a council candidate
+		/// \[voter_address, candidate_address\]
+		CouncilVoteCast(AccountIdFor<T>, AccountIdFor<T>),
+		/// A new council has been elected at the end of a council term
+		/// \[council_members\]
+		NewCouncil(Vec<AccountIdFor<T>>),
+		/// Not enough candidates were elected at the end of a council term, the
+		/// current council stays in office for another term
+		/// \[elected_candidates\]
+		CouncilElectionFailed(u32),
+		/// The approval threshold for an action has been updated, `None` if the
+		/// action uses the default threshold again
+		/// \[call_index, threshold\]
+		VoteThresholdUpdated(CallIndex, Option<VoteThreshold>),
+		/// The weighting of constituent votes has been updated, it applies
+		/// from the next voting period on
+		/// \[weighting\]
+		VoteWeightingUpdated(VoteWeighting),
+		/// A proposal has been withdrawn by its issuer
+		/// \[proposal_hash\]
+		ProposalWithdrawn(T::Hash),
+		/// A finished proposal has been pruned and its deposit refunded
+		/// \[proposal_hash\]
+		ProposalPruned(T::Hash),
+		/// A constituent has delegated their vote
+		/// \[delegator_address, delegate_address\]
+		Delegated(AccountIdFor<T>, AccountIdFor<T>),
+		/// A constituent has revoked their delegation
+		/// \[delegator_address\]
+		Undelegated(AccountIdFor<T>),
+		/// A new emergency proposal has been created, it can be voted on
+		/// until the end block
+		/// \[proposal_hash, end_block\]
+		EmergencyProposed(T::Hash, BlockNumberFor<T>),
+		/// An accepted proposal has been queued for execution
+		/// \[proposal_hash, enactment_block\]
+		ProposalQueued(T::Hash, BlockNumberFor<T>),
+		/// A queued proposal has been cancelled by the committee
+		/// \[proposal_hash\]
+		QueuedProposalCancelled(T::Hash),
 	}
 
 	#[pallet::error]
@@ -253,6 +516,48 @@ pub mod pallet {
 		InvalidOperationInEndBlockComputation,
 		/// Attempted to set VotingPeriod out of the range of 7 days ~ 28 days
 		InvalidVotingPeriod,
+		/// Attempted to nominate a member that already stands for the next
+		/// council election
+		AlreadyCouncilCandidate,
+		/// Attempted to vote for an account that does not stand for the next
+		/// council election
+		NotCouncilCandidate,
+		/// Only constituents can vote in council elections
+		NotConstituentMember,
+		/// Attempted to set an approval threshold that cannot be satisfied
+		InvalidVoteThreshold,
+		/// Only the issuer of a proposal can withdraw it
+		NotProposalIssuer,
+		/// Attempted to withdraw a proposal after its voting period started
+		ProposalVotingStarted,
+		/// Attempted to vote on or execute a proposal that has been withdrawn
+		ProposalCancelled,
+		/// Attempted to revoke a delegation that does not exist
+		NotDelegating,
+		/// Attempted to close a proposal that is already queued for execution
+		ProposalAlreadyQueued,
+		/// Attempted to cancel a proposal that is not queued for execution
+		ProposalNotQueued,
+		/// Attempted to propose a batch without any actions
+		EmptyBatch,
+		/// Attempted to propose a batch with more than `MaxBatchSize` actions
+		BatchTooLarge,
+		/// Attempted to nominate a member while `MaxCouncilCandidates` members
+		/// already stand for the next council election
+		TooManyCouncilCandidates,
+		/// Attempted to delegate while `MaxDelegations` constituents already
+		/// delegate their vote
+		TooManyDelegations,
+		/// The title or description of a proposal exceeds the `StringLimit`
+		BadMetadata,
+		/// Attempted to propose an action as emergency proposal that is not
+		/// one of the `EmergencyCalls`
+		NotEmergencyCall,
+		/// Attempted to add a constituent while `MaxConstituents` constituents
+		/// are already members
+		TooManyConstituents,
+		/// Attempted to delegate to a member that is not on the council
+		NotCouncilMember,
 	}
 
 	impl<T> From<VoteRejectionReason> for Error<T> {
@@ -267,12 +572,40 @@ pub mod pallet {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn integrity_test() {
+			// a queued proposal must remain cancellable by a proposal that is
+			// submitted after it was queued
+			let delay = T::EnactmentDelay::get();
+			assert!(
+				delay.is_zero() ||
+					delay > T::VotingPeriodRange::max().saturating_add(T::ProposalSubmissionPeriod::get()),
+				"EnactmentDelay must exceed the longest voting period plus the proposal submission period"
+			);
+			assert!(T::MaxEnactmentsPerBlock::get() > 0, "MaxEnactmentsPerBlock must not be zero");
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::migrate_to_v1::<T>().saturating_add(crate::migrations::migrate_to_v2::<T>())
+		}
+
 		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			// execute queued proposals whose enactment delay is over
+			let weight = Self::enact_proposals(n);
+
+			// remove the cached constituent weights of expired voting periods
+			let weight = weight.saturating_add(Self::clear_expired_weights());
+
 			// perform upkeep only at the start of a new cycle
 			match Self::get_next_voting_period_end(&n) {
 				Ok(end) => {
-					if end == n + VotingPeriod::<T>::get() + T::ProposalSubmissionPeriod::get() {
-						return Self::upkeep(n);
+					let epoch = VotingPeriod::<T>::get() + T::ProposalSubmissionPeriod::get();
+					if end == n + epoch {
+						return weight.saturating_add(Self::upkeep(n));
+					}
+					// snapshot the vote weighting when a voting period starts
+					if end == n + epoch + VotingPeriod::<T>::get() {
+						let end = n.saturating_add(VotingPeriod::<T>::get());
+						return weight.saturating_add(Self::snapshot_vote_weighting(end));
 					}
 				}
 				Err(err) => {
@@ -280,7 +613,7 @@ pub mod pallet {
 					log::error!("Failed to determine next voting period end: {:?}", err);
 				}
 			}
-			0
+			weight
 		}
 	}
 
@@ -330,20 +663,60 @@ pub mod pallet {
 		///
 		/// Returns the consumed weight:
 		///
-		/// `Storage: ActiveProposals (r:1 w:1) + Votes (r1) * len(proposals)`
+		/// `Storage: ActiveProposals (r:1 w:1) + Votes (r1) * len(proposals) +
+		/// Delegations (r1) * len(delegations) + DelegationSnapshotEnds (r:1 w:1) +
+		/// DelegationSnapshots (w:2) + VoteWeightingSnapshots (r1) * len(snapshots) +
+		/// VoteWeightingSnapshots (w1) * len(expired) + ExpiredWeightSnapshots (r:1 w:1) +
+		/// CouncilTermEpochs (r:1 w:1)` plus the weight of pruning
+		/// inactive proposals and of the council election at the end of a council term
 		fn upkeep(n: BlockNumberFor<T>) -> Weight {
 			// ActiveProposals.retain (r:1 w:1)
 			let mut reads: Weight = 1;
 			let mut writes: Weight = 1;
 
 			// clear out proposals that are no longer active
+			let mut inactive = Vec::new();
 			ActiveProposals::<T>::mutate(|proposals| {
 				// consumed weight for all `Storage: Votes (r1)` lookups
 				reads = reads.saturating_add(proposals.len() as Weight);
 
-				proposals.retain(|hash| if let Some(votes) = Self::get_votes_for(hash) { votes.end > n } else { false })
+				proposals.retain(|hash| {
+					let active = if let Some(votes) = Self::get_votes_for(hash) { votes.end > n } else { false };
+					if !active {
+						inactive.push(*hash);
+					}
+					active
+				})
+			});
+
+			let prune_weight = Self::prune_proposals(n, inactive);
+
+			// snapshot the delegations for the voting period that just ended
+			// the number of delegations is bounded by `DelegationCount`
+			let delegations: Vec<_> = Delegations::<T>::iter().take(T::MaxDelegations::get() as usize).collect();
+			reads = reads.saturating_add(delegations.len() as Weight).saturating_add(1);
+			writes = writes.saturating_add(3);
+			DelegationSnapshots::<T>::insert(n, BoundedVec::try_from(delegations).unwrap_or_default());
+			DelegationSnapshotEnds::<T>::mutate(|ends| {
+				ends.push(n);
+				while ends.len() > 2 {
+					DelegationSnapshots::<T>::remove(ends.remove(0));
+				}
 			});
 
+			// expire the weight snapshots of voting periods whose proposals can no longer be
+			// closed, their cached constituent weights are removed over the next blocks
+			let ends: Vec<_> = VoteWeightingSnapshots::<T>::iter_keys().collect();
+			reads = reads.saturating_add(ends.len() as Weight).saturating_add(1);
+			writes = writes.saturating_add(1);
+			let expired: Vec<_> =
+				ends.into_iter().filter(|end| end.saturating_add(VotingPeriod::<T>::get()) <= n).collect();
+			for end in expired.iter() {
+				writes = writes.saturating_add(1);
+				VoteWeightingSnapshots::<T>::remove(end);
+			}
+			ExpiredWeightSnapshots::<T>::mutate(|ends| ends.extend(expired));
+
 			// reset the voting period if has pending voting period
 			if let Some(pending_voting_period) = PendingVotingPeriod::<T>::get() {
 				writes = writes.saturating_add(1);
@@ -352,9 +725,575 @@ pub mod pallet {
 				Self::deposit_event(Event::NewVotingPeriod(pending_voting_period));
 			}
 
+			// conclude the council election once the current term is over
+			reads = reads.saturating_add(1);
+			writes = writes.saturating_add(1);
+			let term_epochs = CouncilTermEpochs::<T>::mutate(|epochs| {
+				*epochs = epochs.saturating_add(1);
+				*epochs
+			});
+
+			let mut election_weight: Weight = 0;
+			if term_epochs >= T::CouncilTermLength::get() {
+				match Self::elect_council(n) {
+					Ok(weight) => {
+						CouncilTermEpochs::<T>::kill();
+						election_weight = weight;
+					}
+					Err(err) => {
+						// this can only happen due to misconfig, in which case we log the error
+						log::error!("Failed to elect the council: {:?}", err);
+					}
+				}
+			}
+
+			T::DbWeight::get().reads_writes(reads, writes).saturating_add(prune_weight).saturating_add(election_weight)
+		}
+
+		/// Adds the given proposals to the inactive proposals and checks the
+		/// next `MaxPrunedProposals` inactive proposals, those whose retention
+		/// period is over are pruned and the deposit is refunded to their
+		/// issuer, the others are moved to the back of the queue.
+		///
+		/// Proposals that can still be closed or are queued for execution are
+		/// never pruned.
+		///
+		/// Returns the consumed weight:
+		///
+		/// `Storage: InactiveProposalsRange (r:1 w:1) + InactiveProposals (w1) *
+		/// len(newly_inactive) + (InactiveProposals (r1 w2) + Proposals (r1) + Votes (r1)) *
+		/// MaxPrunedProposals + (Proposals (w1) + Votes (w1) + ProposalDeposits (r1 w1) + System
+		/// Account (r1 w1)) * len(pruned)`
+		fn prune_proposals(n: BlockNumberFor<T>, newly_inactive: Vec<HashFor<T>>) -> Weight {
+			let mut reads: Weight = 1;
+			let mut writes: Weight = 1;
+
+			let (mut head, mut tail) = InactiveProposalsRange::<T>::get();
+			for hash in newly_inactive {
+				writes = writes.saturating_add(1);
+				InactiveProposals::<T>::insert(tail, hash);
+				tail = tail.wrapping_add(1);
+			}
+
+			let checks = tail.wrapping_sub(head).min(T::MaxPrunedProposals::get());
+			for _ in 0..checks {
+				reads = reads.saturating_add(3);
+				writes = writes.saturating_add(1);
+				let next = InactiveProposals::<T>::take(head);
+				head = head.wrapping_add(1);
+				let hash = match next {
+					Some(hash) => hash,
+					None => continue,
+				};
+
+				let (proposal, votes) = match (Self::get_proposal(&hash), Self::get_votes_for(&hash)) {
+					(Some(proposal), Some(votes)) => (proposal, votes),
+					// nothing left to prune
+					_ => continue,
+				};
+
+				let pending = match proposal.status {
+					ProposalStatus::Active => n.saturating_sub(votes.end) < VotingPeriod::<T>::get(),
+					ProposalStatus::Queued => true,
+					_ => false,
+				};
+				if pending || n < votes.end.saturating_add(T::ProposalRetentionPeriod::get()) {
+					// check again in one of the next voting cycles
+					writes = writes.saturating_add(1);
+					InactiveProposals::<T>::insert(tail, hash);
+					tail = tail.wrapping_add(1);
+					continue;
+				}
+
+				reads = reads.saturating_add(2);
+				writes = writes.saturating_add(6);
+				Proposals::<T>::remove(&hash);
+				Votes::<T>::remove(&hash);
+				Metadata::<T>::remove(&hash);
+				EmergencyProposals::<T>::remove(&hash);
+				T::Currency::unreserve(&proposal.issuer, ProposalDeposits::<T>::take(&hash));
+
+				Self::deposit_event(Event::ProposalPruned(hash));
+			}
+			InactiveProposalsRange::<T>::put((head, tail));
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		/// Concludes the council election at the end of a council term.
+		///
+		/// Candidates are ranked by the number of constituent votes they received
+		/// and the top `CouncilSeats` candidates with at least one vote form the
+		/// new council. If fewer than `MinCouncilVotes` candidates were elected the
+		/// current council stays in office for another term.
+		///
+		/// Outgoing council members become constituents, newly seated council
+		/// members are eligible to vote after the same delay as new constituents.
+		///
+		/// The votes of this election are not removed, they are ignored once
+		/// the `CouncilElection` moved on.
+		///
+		/// Returns the consumed weight:
+		///
+		/// `Storage: CouncilCandidates (r1 w1) * len(candidates) + CouncilCandidateCount (w1) +
+		/// CouncilElection (r1 w1) + Council (r1 w1) + ConstituentCount (r1 w1) + (Members (w1) +
+		/// VotingEligibility (w1)) * len(seat changes)`
+		fn elect_council(n: BlockNumberFor<T>) -> Result<Weight, DispatchError> {
+			let eligible_from = n + Self::get_next_voting_period_end(&n)?;
+
+			// bounded by `MaxCouncilCandidates`
+			let mut candidates: Vec<_> = CouncilCandidates::<T>::drain().collect();
+			CouncilCandidateCount::<T>::kill();
+			CouncilElection::<T>::mutate(|election| *election = election.saturating_add(1));
+			let mut reads = (candidates.len() as Weight).saturating_add(1);
+			let mut writes = reads.saturating_add(1);
+
+			// rank by votes, ties are broken by account to keep the outcome deterministic
+			candidates.sort_by(|(a, a_votes), (b, b_votes)| b_votes.cmp(a_votes).then_with(|| a.cmp(b)));
+			let elected: Vec<AccountIdFor<T>> = candidates
+				.into_iter()
+				.filter(|(_, votes)| *votes > 0)
+				.take(T::CouncilSeats::get() as usize)
+				.map(|(candidate, _)| candidate)
+				.collect();
+
+			if elected.len() < T::MinCouncilVotes::get() {
+				Self::deposit_event(Event::CouncilElectionFailed(elected.len() as u32));
+				return Ok(T::DbWeight::get().reads_writes(reads, writes));
+			}
+
+			let outgoing = Self::council_members();
+			reads = reads.saturating_add(1);
+			writes = writes.saturating_add(1);
+
+			// the number of constituents changes by the number of seats that changed hands, which
+			// keeps the number of members unchanged
+			let mut constituents = ConstituentCount::<T>::get();
+			reads = reads.saturating_add(1);
+			writes = writes.saturating_add(1);
+
+			for member in outgoing.iter().filter(|member| !elected.contains(member)) {
+				writes = writes.saturating_add(1);
+				constituents = constituents.saturating_add(1);
+				Members::<T>::insert(member, MemberType::Constituent);
+			}
+
+			for member in elected.iter().filter(|member| !outgoing.contains(member)) {
+				writes = writes.saturating_add(2);
+				constituents = constituents.saturating_sub(1);
+				Members::<T>::insert(member, MemberType::Council);
+				VotingEligibility::<T>::insert(member, eligible_from);
+			}
+			Council::<T>::put(elected.clone());
+			ConstituentCount::<T>::put(constituents);
+
+			Self::deposit_event(Event::NewCouncil(elected));
+			Ok(T::DbWeight::get().reads_writes(reads, writes))
+		}
+
+		/// Returns the call index of the given action
+		pub fn call_index(action: &T::Action) -> CallIndex {
+			action.using_encoded(|bytes| {
+				(bytes.get(0).copied().unwrap_or_default(), bytes.get(1).copied().unwrap_or_default())
+			})
+		}
+
+		/// Returns the approval threshold for actions with the given call index
+		pub fn vote_threshold(call_index: CallIndex) -> VoteThreshold {
+			VoteThresholds::<T>::get(call_index).unwrap_or_default()
+		}
+
+		/// Ensures the votes satisfy the approval threshold of the action with
+		/// the given call index
+		pub fn ensure_accepted(
+			votes: &VoteAggregate<AccountIdFor<T>, BlockNumberFor<T>>,
+			call_index: CallIndex,
+		) -> Result<(), VoteRejectionReason> {
+			votes.is_accepted_with(&Self::vote_threshold(call_index), T::MinCouncilVotes::get(), || {
+				Self::council_members().len()
+			})
+		}
+
+		/// Snapshots the constituent vote weighting and the weights of all
+		/// constituents for the voting period that ends at the given block.
+		///
+		/// Taking the weights up front prevents index tokens from being counted
+		/// more than once by moving them to another constituent that has not
+		/// voted yet.
+		///
+		/// Returns the consumed weight:
+		///
+		/// `Storage: VoteWeightingSnapshots (r:1 w:1) + ConstituentVoteWeighting (r:1 w:0) +
+		/// Members (r1) * len(members) + ConstituentWeights (w1) * len(constituents)`
+		fn snapshot_vote_weighting(end: BlockNumberFor<T>) -> Weight {
+			if VoteWeightingSnapshots::<T>::contains_key(end) {
+				return T::DbWeight::get().reads(1);
+			}
+			let weighting = ConstituentVoteWeighting::<T>::get();
+			VoteWeightingSnapshots::<T>::insert(end, &weighting);
+			let mut reads: Weight = 2;
+			let mut writes: Weight = 1;
+
+			// equally weighted votes do not need cached weights
+			if weighting != VoteWeighting::Equal {
+				// the number of members is bounded by `MaxConstituents` and `CouncilSeats`
+				let members = T::MaxConstituents::get().saturating_add(T::CouncilSeats::get());
+				for (member, ty) in Members::<T>::iter().take(members as usize) {
+					reads = reads.saturating_add(1);
+					if ty == MemberType::Constituent {
+						writes = writes.saturating_add(1);
+						ConstituentWeights::<T>::insert(end, &member, Self::holdings_weight(&weighting, &member));
+					}
+				}
+			}
+
 			T::DbWeight::get().reads_writes(reads, writes)
 		}
 
+		/// Removes up to `MaxWeightRemovals` cached constituent weights of the
+		/// oldest expired voting period.
+		///
+		/// Returns the consumed weight:
+		///
+		/// `Storage: ExpiredWeightSnapshots (r:1 w:1) + ConstituentWeights (r:1) +
+		/// ConstituentWeights (w1) * MaxWeightRemovals`
+		fn clear_expired_weights() -> Weight {
+			let mut ends = ExpiredWeightSnapshots::<T>::get();
+			let end = match ends.first() {
+				Some(end) => *end,
+				None => return T::DbWeight::get().reads(1),
+			};
+
+			let limit = T::MaxWeightRemovals::get();
+			ConstituentWeights::<T>::remove_prefix(end, Some(limit));
+			if ConstituentWeights::<T>::iter_key_prefix(end).next().is_none() {
+				ends.remove(0);
+				ExpiredWeightSnapshots::<T>::put(ends);
+			}
+
+			T::DbWeight::get().reads_writes(2, (limit as Weight).saturating_add(1))
+		}
+
+		/// Returns the weight of the given account's votes under the given
+		/// weighting, based on its current index token holdings
+		fn holdings_weight(weighting: &VoteWeighting, who: &AccountIdFor<T>) -> u128 {
+			match weighting {
+				VoteWeighting::Equal => 1,
+				VoteWeighting::IndexTokenBalance => T::IndexTokenHoldings::balance(who),
+				VoteWeighting::LockedIndexTokens => T::IndexTokenHoldings::locked(who),
+			}
+		}
+
+		/// Returns the weight of the given constituent's votes on proposals
+		/// whose voting period ends at the given block.
+		///
+		/// This is the weight snapshot when the voting period started, which is
+		/// zero for constituents that were not members at the time, or the
+		/// current weight if the voting period has not started yet.
+		pub fn constituent_weight(who: &AccountIdFor<T>, end: BlockNumberFor<T>) -> u128 {
+			match VoteWeightingSnapshots::<T>::get(end) {
+				Some(VoteWeighting::Equal) => 1,
+				Some(_) => ConstituentWeights::<T>::get(end, who),
+				None => Self::holdings_weight(&ConstituentVoteWeighting::<T>::get(), who),
+			}
+		}
+
+		/// Returns the delegations that apply to a voting period ending at the
+		/// given block.
+		///
+		/// This is the snapshot taken at the end of the voting period, or the
+		/// current delegations if no snapshot was taken for it (yet).
+		pub fn delegations_at(end: BlockNumberFor<T>) -> Vec<(AccountIdFor<T>, AccountIdFor<T>)> {
+			match DelegationSnapshots::<T>::get(end) {
+				Some(delegations) => delegations.into_inner(),
+				None => Delegations::<T>::iter().collect(),
+			}
+		}
+
+		/// Executes the queued proposals whose enactment delay ends at the given
+		/// block.
+		///
+		/// At most `MaxEnactmentsPerBlock` proposals are executed, the remaining
+		/// proposals are carried over to the next block.
+		///
+		/// Returns the consumed weight:
+		///
+		/// `Storage: EnactmentAgenda (r:1 w:1) + (QueuedProposals (r1 w1) + Proposals (r1 w1)) *
+		/// len(executed) + EnactmentAgenda (r:1 w:1) + QueuedProposals (r1 w1) * len(carried)` plus
+		/// the weight of the executed actions
+		fn enact_proposals(n: BlockNumberFor<T>) -> Weight {
+			let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+			let mut agenda = EnactmentAgenda::<T>::take(n);
+			let max = T::MaxEnactmentsPerBlock::get() as usize;
+			if agenda.len() > max {
+				let next = n.saturating_add(One::one());
+				let carried = agenda.split_off(max);
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(
+					(carried.len() as Weight).saturating_add(1),
+					(carried.len() as Weight).saturating_add(1),
+				));
+
+				// cancelled proposals are dropped, the others are rescheduled
+				let mut carried: Vec<_> = carried
+					.into_iter()
+					.filter(|hash| {
+						QueuedProposals::<T>::mutate(hash, |maybe_enactment| match maybe_enactment {
+							Some(enactment) => {
+								enactment.at = next;
+								true
+							}
+							None => false,
+						})
+					})
+					.collect();
+				// carried over proposals are executed before the ones queued for the next block
+				EnactmentAgenda::<T>::mutate(next, |queued| {
+					carried.append(queued);
+					*queued = carried;
+				});
+			}
+
+			for proposal_hash in agenda {
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 2));
+				// cancelled proposals are removed from the queue
+				let enactment = match QueuedProposals::<T>::take(&proposal_hash) {
+					Some(enactment) => enactment,
+					None => continue,
+				};
+
+				Proposals::<T>::mutate(&proposal_hash, |maybe_proposal| {
+					if let Some(proposal) = maybe_proposal {
+						weight = weight.saturating_add(Self::actions_weight(&proposal.actions));
+						Self::execute_proposal(proposal_hash, proposal, enactment.votes);
+					}
+				});
+			}
+
+			weight
+		}
+
+		/// Dispatches the actions of an accepted proposal with the committee
+		/// origin and marks the proposal as executed
+		///
+		/// The actions are dispatched in order until one of them fails, in which
+		/// case the changes of all actions are reverted.
+		fn execute_proposal(
+			proposal_hash: HashFor<T>,
+			proposal: &mut Proposal<T>,
+			votes: VoteAggregate<AccountIdFor<T>, BlockNumberFor<T>>,
+		) {
+			let results = with_transaction(|| {
+				let mut results = Vec::with_capacity(proposal.actions.len());
+				for action in proposal.actions.iter().cloned() {
+					let origin = Origin::<T>::ApprovedByCommittee(
+						proposal.issuer.clone(),
+						votes.clone(),
+						Self::call_index(&action),
+					);
+					let result = action.dispatch(origin.into()).map(|_| ()).map_err(|e| e.error);
+					let failed = result.is_err();
+					results.push(result);
+					if failed {
+						return TransactionOutcome::Rollback(results);
+					}
+				}
+				TransactionOutcome::Commit(results)
+			});
+
+			proposal.status = ProposalStatus::Executed;
+
+			Self::deposit_event(Event::ClosedAndExecutedProposal(proposal_hash, results));
+		}
+
+		/// Returns the combined dispatch weight of the given actions
+		fn actions_weight(actions: &[T::Action]) -> Weight {
+			actions.iter().fold(0, |weight, action| weight.saturating_add(action.get_dispatch_info().weight))
+		}
+
+		/// Returns the combined dispatch weight of the actions of the proposal
+		/// with the given hash, or zero if there is no such proposal
+		fn proposal_actions_weight(proposal_hash: &HashFor<T>) -> Weight {
+			Proposals::<T>::get(proposal_hash).map_or(0, |proposal| Self::actions_weight(&proposal.actions))
+		}
+
+		/// Creates a new proposal with the given actions and reserves the
+		/// deposit of the proposer, the proposal can be voted on until the given
+		/// end block
+		fn do_propose(
+			proposer: AccountIdFor<T>,
+			actions: Vec<T::Action>,
+			metadata: ProposalMetadata<Vec<u8>>,
+			end: BlockNumberFor<T>,
+		) -> Result<HashFor<T>, DispatchError> {
+			let bounded_metadata = ProposalMetadata::<BoundedVec<u8, T::StringLimit>> {
+				title: metadata.title.clone().try_into().map_err(|_| Error::<T>::BadMetadata)?,
+				description: metadata.description.clone().try_into().map_err(|_| Error::<T>::BadMetadata)?,
+			};
+
+			// Create a new proposal with a unique nonce
+			let nonce = Self::take_and_increment_nonce()?;
+			let proposal = Proposal::<T>::batch(actions, proposer.clone(), nonce.clone(), ProposalStatus::Active);
+
+			let proposal_hash = proposal.hash();
+
+			// Reserve the deposit, which is refunded once the proposal is pruned
+			let deposit = T::ProposalDeposit::get();
+			T::Currency::reserve(&proposer, deposit)?;
+			ProposalDeposits::<T>::insert(proposal_hash, deposit);
+
+			// Store the proposal and its metadata by its hash.
+			Proposals::<T>::insert(proposal_hash, proposal);
+			Metadata::<T>::insert(proposal_hash, bounded_metadata);
+
+			// Add the proposal to the active proposals and set the initial votes
+			ActiveProposals::<T>::append(&proposal_hash);
+			Votes::<T>::insert(proposal_hash, VoteAggregate::new_with_end(end));
+
+			Self::deposit_event(Event::Proposed(proposer, nonce, proposal_hash, metadata));
+			Ok(proposal_hash)
+		}
+
+		/// Returns the block at the end of the voting period of proposals that
+		/// are created at the current block
+		fn next_voting_period_end() -> Result<BlockNumberFor<T>, DispatchError> {
+			Self::get_next_voting_period_end(&frame_system::Pallet::<T>::block_number())
+		}
+
+		/// Whether an accepted proposal is executed right away instead of being
+		/// queued for the enactment delay.
+		///
+		/// This is the case if there is no enactment delay, the proposal only
+		/// cancels queued proposals, or the votes satisfy the expedited
+		/// threshold.
+		fn is_expedited(proposal: &Proposal<T>, votes: &VoteAggregate<AccountIdFor<T>, BlockNumberFor<T>>) -> bool {
+			let cancel_call_index = (
+				<Self as PalletInfoAccess>::index() as u8,
+				Call::<T>::cancel_queued_proposal { proposal_hash: Default::default() }.using_encoded(|bytes| bytes[0]),
+			);
+
+			T::EnactmentDelay::get().is_zero() ||
+				proposal.actions.iter().all(|action| Self::call_index(action) == cancel_call_index) ||
+				votes
+					.is_accepted_with(&T::ExpeditedThreshold::get(), T::MinCouncilVotes::get(), || {
+						Self::council_members().len()
+					})
+					.is_ok()
+		}
+
+		/// Returns the title and description of the proposal with the given hash
+		pub fn proposal_metadata(proposal_hash: &HashFor<T>) -> Option<ProposalMetadata<Vec<u8>>> {
+			Metadata::<T>::get(proposal_hash).map(|metadata| ProposalMetadata {
+				title: metadata.title.into_inner(),
+				description: metadata.description.into_inner(),
+			})
+		}
+
+		/// Returns the state of the proposal with the given hash, including the
+		/// live tally and whether it would currently be accepted
+		pub fn proposal_info(
+			proposal_hash: HashFor<T>,
+		) -> Option<ProposalInfo<AccountIdFor<T>, HashFor<T>, BlockNumberFor<T>>>
+		where
+			T::Action: GetCallMetadata,
+		{
+			let proposal = Self::get_proposal(&proposal_hash)?;
+			let mut votes = Self::get_votes_for(&proposal_hash)?;
+			let end = votes.end;
+			votes.apply_delegations(&Self::delegations_at(end), |who| Self::constituent_weight(who, end));
+
+			let count = |member_type| {
+				let (ayes, nays, abstentions) = votes.tally(Some(&member_type));
+				VoteCount { ayes: ayes as u32, nays: nays as u32, abstentions: abstentions as u32 }
+			};
+			let (ayes, nays, abstentions) = votes.weighted_tally(&MemberType::Constituent);
+			let tally = VoteTally {
+				council: count(MemberType::Council),
+				constituents: count(MemberType::Constituent),
+				constituent_weights: VoteCount { ayes, nays, abstentions },
+			};
+			let accepted =
+				proposal.actions.iter().all(|action| Self::ensure_accepted(&votes, Self::call_index(action)).is_ok());
+			let actions = proposal
+				.actions
+				.iter()
+				.map(|action| {
+					let metadata = action.get_call_metadata();
+					ProposedAction {
+						call_index: Self::call_index(action),
+						pallet_name: metadata.pallet_name.as_bytes().to_vec(),
+						function_name: metadata.function_name.as_bytes().to_vec(),
+						encoded: action.encode(),
+					}
+				})
+				.collect();
+
+			Some(ProposalInfo {
+				hash: proposal_hash,
+				issuer: proposal.issuer,
+				status: proposal.status,
+				metadata: Self::proposal_metadata(&proposal_hash).unwrap_or_default(),
+				actions,
+				end: votes.end,
+				tally,
+				accepted,
+			})
+		}
+
+		/// Returns the state of all active proposals
+		pub fn active_proposal_infos() -> Vec<ProposalInfo<AccountIdFor<T>, HashFor<T>, BlockNumberFor<T>>>
+		where
+			T::Action: GetCallMetadata,
+		{
+			Self::active_proposals().into_iter().filter_map(Self::proposal_info).collect()
+		}
+
+		/// Returns the boundaries of the current submission and voting periods
+		pub fn voting_window() -> Result<VotingWindow<BlockNumberFor<T>>, DispatchError> {
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let voting_period = VotingPeriod::<T>::get();
+			let epoch = voting_period.saturating_add(T::ProposalSubmissionPeriod::get());
+
+			// the next voting period ends one voting period after the next epoch starts
+			let submission_end = Self::get_next_voting_period_end(&current_block)?.saturating_sub(voting_period);
+			let voting_start = submission_end.saturating_sub(epoch);
+			let voting_end = voting_start.saturating_add(voting_period);
+
+			Ok(VotingWindow { voting_start, voting_end, submission_start: voting_end, submission_end })
+		}
+
+		/// Returns all proposals that are queued for execution
+		pub fn queued_proposals() -> Vec<(HashFor<T>, Enactment<AccountIdFor<T>, BlockNumberFor<T>>)> {
+			QueuedProposals::<T>::iter().collect()
+		}
+
+		/// Returns all current council members
+		pub fn council_members() -> Vec<AccountIdFor<T>> {
+			Council::<T>::get()
+		}
+
+		/// Removes the council election vote of the given constituent, if any
+		fn retract_council_vote(voter: &AccountIdFor<T>) {
+			match CouncilVotes::<T>::take(voter) {
+				Some((election, candidate)) if election == CouncilElection::<T>::get() => {
+					CouncilCandidates::<T>::mutate(&candidate, |maybe_votes| {
+						if let Some(votes) = maybe_votes {
+							*votes = votes.saturating_sub(1);
+						}
+					});
+				}
+				_ => {}
+			}
+		}
+
+		/// Removes the given account from the candidates of the next council
+		/// election, if it stands for it
+		fn withdraw_candidacy(candidate: &AccountIdFor<T>) {
+			if CouncilCandidates::<T>::take(candidate).is_some() {
+				CouncilCandidateCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+			}
+		}
+
 		/// Used to check if an origin is signed and the signer is a member of
 		/// the committee
 		pub fn ensure_member(origin: OriginFor<T>) -> Result<CommitteeMember<AccountIdFor<T>>, DispatchError> {
@@ -372,32 +1311,87 @@ pub mod pallet {
 		/// Requires the sender to be `ProposalSubmissionOrigin`
 		///
 		/// The provided action will be turned into a proposal and added to the list of current
-		/// active proposals to be voted on in the next voting period.
+		/// active proposals to be voted on in the next voting period. The `ProposalDeposit` is
+		/// reserved from the sender until the proposal is pruned.
+		///
+		/// The `metadata` holds the title of the proposal and a link to or the content hash of its
+		/// full description, each limited in length by `StringLimit`.
+		#[transactional]
 		#[pallet::weight((
 			T::WeightInfo::propose().saturating_add(action.get_dispatch_info().weight),
 			DispatchClass::Operational
 		))]
-		pub fn propose(origin: OriginFor<T>, action: Box<T::Action>) -> DispatchResultWithPostInfo {
+		pub fn propose(
+			origin: OriginFor<T>,
+			action: Box<T::Action>,
+			metadata: ProposalMetadata<Vec<u8>>,
+		) -> DispatchResultWithPostInfo {
 			let proposer = T::ProposalSubmissionOrigin::ensure_origin(origin)?;
+			// Set the end block number to the end of the next voting period
+			Self::do_propose(proposer, vec![*action], metadata, Self::next_voting_period_end()?)?;
+			Ok(().into())
+		}
 
-			// Create a new proposal with a unique nonce
-			let nonce = Self::take_and_increment_nonce()?;
-			let proposal = Proposal::<T>::new(*action, proposer.clone(), nonce.clone(), ProposalStatus::Active);
-
-			let proposal_hash = proposal.hash();
-
-			// Store the proposal by its hash.
-			Proposals::<T>::insert(proposal_hash, proposal);
+		/// Extrinsic to propose a batch of actions to be voted upon once in the
+		/// next voting period.
+		///
+		/// Requires the sender to be `ProposalSubmissionOrigin`
+		///
+		/// Once accepted, the actions are dispatched in order and all-or-nothing: if one of them
+		/// fails, the changes of the entire batch are reverted. Every action must satisfy its own
+		/// approval threshold.
+		#[transactional]
+		#[pallet::weight((
+			T::WeightInfo::propose_batch(actions.len() as u32).saturating_add(Pallet::<T>::actions_weight(actions)),
+			DispatchClass::Operational
+		))]
+		pub fn propose_batch(
+			origin: OriginFor<T>,
+			actions: Vec<T::Action>,
+			metadata: ProposalMetadata<Vec<u8>>,
+		) -> DispatchResultWithPostInfo {
+			let proposer = T::ProposalSubmissionOrigin::ensure_origin(origin)?;
+			ensure!(!actions.is_empty(), Error::<T>::EmptyBatch);
+			ensure!(actions.len() <= T::MaxBatchSize::get() as usize, Error::<T>::BatchTooLarge);
 
-			// Add the proposal to the active proposals and set the initial votes
-			// Set the end block number to the end of the next voting period
-			ActiveProposals::<T>::append(&proposal_hash);
-			let end = Self::get_next_voting_period_end(&frame_system::Pallet::<T>::block_number())?;
+			Self::do_propose(proposer, actions, metadata, Self::next_voting_period_end()?)?;
+			Ok(().into())
+		}
 
-			Votes::<T>::insert(proposal_hash, VoteAggregate::new_with_end(end));
+		/// Extrinsic to propose a protective action in an emergency.
+		///
+		/// Requires the sender to be `ProposalSubmissionOrigin` and the action to be one of the
+		/// `EmergencyCalls`.
+		///
+		/// Voting on emergency proposals opens immediately and lasts for the
+		/// `EmergencyVotingPeriod`, the vote weighting is snapshot when they are proposed. They can
+		/// be closed as soon as two thirds of the whole council vote aye, or after the voting
+		/// period if they satisfy the `EmergencyThreshold`. Accepted emergency proposals are
+		/// executed without enactment delay.
+		#[transactional]
+		#[pallet::weight((
+			T::WeightInfo::propose()
+				.saturating_add(action.get_dispatch_info().weight)
+				.saturating_add(T::DbWeight::get().reads_writes(
+					T::MaxConstituents::get().saturating_add(T::CouncilSeats::get()).saturating_add(2).into(),
+					T::MaxConstituents::get().saturating_add(1).into()
+				)),
+			DispatchClass::Operational
+		))]
+		pub fn propose_emergency(
+			origin: OriginFor<T>,
+			action: Box<T::Action>,
+			metadata: ProposalMetadata<Vec<u8>>,
+		) -> DispatchResultWithPostInfo {
+			let proposer = T::ProposalSubmissionOrigin::ensure_origin(origin)?;
+			ensure!(T::EmergencyCalls::contains(&action), Error::<T>::NotEmergencyCall);
 
-			Self::deposit_event(Event::Proposed(proposer, nonce, proposal_hash));
+			let end = frame_system::Pallet::<T>::block_number().saturating_add(T::EmergencyVotingPeriod::get());
+			let proposal_hash = Self::do_propose(proposer, vec![*action], metadata, end)?;
+			EmergencyProposals::<T>::insert(proposal_hash, ());
+			Self::snapshot_vote_weighting(end);
 
+			Self::deposit_event(Event::EmergencyProposed(proposal_hash, end));
 			Ok(().into())
 		}
 
@@ -407,7 +1401,8 @@ pub mod pallet {
 		///
 		/// New members are eligible to vote after 1 voting period has passed from the block they
 		/// were added to the members set. Successfully cast votes will be recorded in the state and
-		/// a proposal meeting voting requirements can be executed.
+		/// a proposal meeting voting requirements can be executed. Members can change their vote
+		/// until the voting period ends.
 		#[transactional]
 		#[pallet::weight((T::WeightInfo::vote(), DispatchClass::Operational))]
 		pub fn vote(origin: OriginFor<T>, proposal_hash: HashFor<T>, vote: VoteKind) -> DispatchResult {
@@ -417,14 +1412,31 @@ pub mod pallet {
 				.filter(|block_number| frame_system::Pallet::<T>::block_number() >= *block_number)
 				.ok_or(Error::<T>::NotEligibileToVoteYet)?;
 
+			let status = Self::get_proposal(&proposal_hash).map(|proposal| proposal.status);
+			ensure!(status != Some(ProposalStatus::Cancelled), Error::<T>::ProposalCancelled);
+			// emergency proposals can be executed before their voting period is over
+			ensure!(status != Some(ProposalStatus::Executed), Error::<T>::ProposalAlreadyExecuted);
+
 			Votes::<T>::try_mutate(&proposal_hash, |maybe_votes| -> DispatchResult {
 				let votes = maybe_votes.as_mut().ok_or(Error::<T>::NoProposalWithHash)?;
 
-				// Can only vote within the allowed range of blocks for this proposal
-				ensure!(Self::within_voting_period(votes), Error::<T>::NotInVotingPeriod);
-				// members can vote only once
-				ensure!(!votes.has_voted(&voter.account_id), Error::<T>::DuplicateVote);
-				votes.cast_vote(MemberVote::new(voter.clone(), vote.clone())); // mutates votes in place
+				// Can only vote within the allowed range of blocks for this proposal, voting on
+				// emergency proposals opens when they are proposed
+				let within_voting_period = if EmergencyProposals::<T>::contains_key(&proposal_hash) {
+					frame_system::Pallet::<T>::block_number() < votes.end
+				} else {
+					Self::within_voting_period(votes)
+				};
+				ensure!(within_voting_period, Error::<T>::NotInVotingPeriod);
+				// members can change their vote but not cast the same vote twice
+				ensure!(votes.vote_of(&voter.account_id) != Some(&vote), Error::<T>::DuplicateVote);
+				votes.remove_voters(slice::from_ref(&voter.account_id));
+				// constituent votes are weighted by the constituent's weight for this voting period
+				let mut member_vote = MemberVote::new(voter.clone(), vote.clone());
+				if voter.member_type == MemberType::Constituent {
+					member_vote = member_vote.with_weight(Self::constituent_weight(&voter.account_id, votes.end));
+				}
+				votes.cast_vote(member_vote); // mutates votes in place
 
 				Self::deposit_event(Event::VoteCast(voter, proposal_hash, vote));
 				Ok(())
@@ -433,17 +1445,25 @@ pub mod pallet {
 
 		/// Extrinsic to close and execute a proposal.
 		///
-		/// Proposal must have been voted on and have majority approval.
+		/// Proposal must have been voted on and have majority approval. Accepted proposals are
+		/// queued for the `EnactmentDelay`, unless they satisfy the `ExpeditedThreshold` or are
+		/// emergency proposals.
 		///
-		/// Only the proposal execution origin can execute.
-		#[pallet::weight((T::WeightInfo::close(), DispatchClass::Operational))]
+		/// Only the proposal execution origin can execute. The weight of the actions of the
+		/// proposal and of the delegations that were not snapshot is refunded unless the actions
+		/// are executed.
+		#[pallet::weight((
+			T::WeightInfo::close(T::MaxDelegations::get())
+				.saturating_add(Pallet::<T>::proposal_actions_weight(proposal_hash)),
+			DispatchClass::Operational
+		))]
 		pub fn close(origin: OriginFor<T>, proposal_hash: HashFor<T>) -> DispatchResultWithPostInfo {
 			T::ProposalExecutionOrigin::ensure_origin(origin)?;
 
 			// register that this proposal has been executed
 			Proposals::<T>::try_mutate_exists(&proposal_hash, |maybe_proposal| -> DispatchResultWithPostInfo {
 				let mut proposal = maybe_proposal.take().ok_or(Error::<T>::NoProposalWithHash)?;
-				let votes = Self::get_votes_for(&proposal_hash).ok_or(Error::<T>::NoProposalWithHash)?;
+				let mut votes = Self::get_votes_for(&proposal_hash).ok_or(Error::<T>::NoProposalWithHash)?;
 				let current_block = frame_system::Pallet::<T>::block_number();
 
 				// ensure proposal has not already been executed
@@ -453,41 +1473,125 @@ pub mod pallet {
 						if current_block.saturating_sub(votes.end) >= VotingPeriod::<T>::get() {
 							proposal.status = ProposalStatus::Timeout;
 							*maybe_proposal = Some(proposal);
-							return Ok(().into());
+							return Ok(Some(T::WeightInfo::close(0)).into());
 						}
 
 						Ok(())
 					}
 					ProposalStatus::Timeout => Err(Error::<T>::ProposalTimeout),
 					ProposalStatus::Executed => Err(Error::<T>::ProposalAlreadyExecuted),
+					ProposalStatus::Cancelled => Err(Error::<T>::ProposalCancelled),
+					ProposalStatus::Queued => Err(Error::<T>::ProposalAlreadyQueued),
 				})?;
 
-				// Ensure voting period is over
-				ensure!(current_block > votes.end, Error::<T>::VotingPeriodNotElapsed);
+				// Ensure voting period is over, emergency proposals can be closed as soon as they
+				// are accepted
+				let emergency = EmergencyProposals::<T>::contains_key(&proposal_hash);
+				ensure!(emergency || current_block > votes.end, Error::<T>::VotingPeriodNotElapsed);
 
-				// Ensure voting has accepted proposal
-				votes.is_accepted(T::MinCouncilVotes::get()).map_err(Into::<Error<T>>::into)?;
+				// Count the votes of constituents that delegated their vote
+				let end = votes.end;
+				let delegations = Self::delegations_at(end);
+				votes.apply_delegations(&delegations, |who| Self::constituent_weight(who, end));
 
-				// Execute the proposal
-				let result = proposal
-					.action
-					.clone()
-					.dispatch(Origin::<T>::ApprovedByCommittee(proposal.issuer.clone(), votes).into());
+				// Ensure voting has accepted every action of the proposal
+				for action in proposal.actions.iter() {
+					Self::ensure_accepted(&votes, Self::call_index(action)).map_err(Into::<Error<T>>::into)?;
+				}
+				if emergency && current_block <= votes.end {
+					// closing early requires the ayes of two thirds of the whole council
+					let (ayes, _, _) = votes.tally(Some(&MemberType::Council));
+					let council_size = Self::council_members().len();
+					ensure!(
+						ayes > 0 && ayes.saturating_mul(3) >= council_size.saturating_mul(2),
+						Error::<T>::ProposalNotAcceptedInsufficientVotes
+					);
+				} else if emergency {
+					votes
+						.is_accepted_with(&T::EmergencyThreshold::get(), T::MinCouncilVotes::get(), || {
+							Self::council_members().len()
+						})
+						.map_err(Into::<Error<T>>::into)?;
+				}
 
-				proposal.status = ProposalStatus::Executed;
+				let actual_weight = if emergency || Self::is_expedited(&proposal, &votes) {
+					// Execute the proposal
+					Self::execute_proposal(proposal_hash, &mut proposal, votes);
+					None
+				} else {
+					// Queue the proposal until the enactment delay is over
+					let at = current_block.saturating_add(T::EnactmentDelay::get());
+					EnactmentAgenda::<T>::append(at, proposal_hash);
+					QueuedProposals::<T>::insert(proposal_hash, Enactment { at, votes });
+					proposal.status = ProposalStatus::Queued;
+
+					Self::deposit_event(Event::ProposalQueued(proposal_hash, at));
+					Some(T::WeightInfo::close(delegations.len() as u32))
+				};
 				*maybe_proposal = Some(proposal);
 
-				Self::deposit_event(Event::ClosedAndExecutedProposal(
-					proposal_hash,
-					result.map(|_| ()).map_err(|e| e.error),
-				));
-
-				Ok(().into())
+				Ok(actual_weight.into())
 			})
 		}
 
+		/// Withdraw a proposal before its voting period starts
+		///
+		/// Only the issuer of the proposal can withdraw it. The proposal is marked as cancelled and
+		/// its deposit is refunded once the proposal is pruned.
+		#[pallet::weight(T::WeightInfo::withdraw_proposal())]
+		pub fn withdraw_proposal(origin: OriginFor<T>, proposal_hash: HashFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Proposals::<T>::try_mutate(&proposal_hash, |maybe_proposal| -> DispatchResult {
+				let proposal = maybe_proposal.as_mut().ok_or(Error::<T>::NoProposalWithHash)?;
+				ensure!(proposal.issuer == who, Error::<T>::NotProposalIssuer);
+				ensure!(proposal.status != ProposalStatus::Cancelled, Error::<T>::ProposalCancelled);
+
+				// emergency proposals can be voted on as soon as they are proposed
+				ensure!(!EmergencyProposals::<T>::contains_key(&proposal_hash), Error::<T>::ProposalVotingStarted);
+				let votes = Self::get_votes_for(&proposal_hash).ok_or(Error::<T>::NoProposalWithHash)?;
+				// the voting period of the proposal has not started as long as it is the next one
+				let now = frame_system::Pallet::<T>::block_number();
+				ensure!(Self::get_next_voting_period_end(&now)? == votes.end, Error::<T>::ProposalVotingStarted);
+
+				proposal.status = ProposalStatus::Cancelled;
+				Ok(())
+			})?;
+
+			ActiveProposals::<T>::mutate(|proposals| proposals.retain(|hash| *hash != proposal_hash));
+			InactiveProposalsRange::<T>::mutate(|(_, tail)| {
+				InactiveProposals::<T>::insert(*tail, proposal_hash);
+				*tail = tail.wrapping_add(1);
+			});
+
+			Self::deposit_event(Event::ProposalWithdrawn(proposal_hash));
+			Ok(())
+		}
+
+		/// Cancel a proposal that is queued for execution
+		///
+		/// This call can only be called after the approval of the committee, it is never
+		/// delayed itself.
+		#[pallet::weight(T::WeightInfo::cancel_queued_proposal())]
+		pub fn cancel_queued_proposal(origin: OriginFor<T>, proposal_hash: HashFor<T>) -> DispatchResult {
+			T::ApprovedByCommitteeOrigin::ensure_origin(origin)?;
+
+			let enactment = QueuedProposals::<T>::take(&proposal_hash).ok_or(Error::<T>::ProposalNotQueued)?;
+			EnactmentAgenda::<T>::mutate(enactment.at, |agenda| agenda.retain(|hash| *hash != proposal_hash));
+			Proposals::<T>::mutate(&proposal_hash, |maybe_proposal| {
+				if let Some(proposal) = maybe_proposal {
+					proposal.status = ProposalStatus::Cancelled;
+				}
+			});
+
+			Self::deposit_event(Event::QueuedProposalCancelled(proposal_hash));
+			Ok(())
+		}
+
 		/// Add new constituent to the committee
 		///
+		/// At most `MaxConstituents` constituents can be members at the same time.
+		///
 		/// This call can only be called after the approval of the committee
 		#[pallet::weight(T::WeightInfo::add_constituent())]
 		pub fn add_constituent(origin: OriginFor<T>, constituent: AccountIdFor<T>) -> DispatchResult {
@@ -501,6 +1605,11 @@ pub mod pallet {
 					}
 					.into())
 				} else {
+					ConstituentCount::<T>::try_mutate(|count| -> DispatchResult {
+						ensure!(*count < T::MaxConstituents::get(), Error::<T>::TooManyConstituents);
+						*count += 1;
+						Ok(())
+					})?;
 					*member = Some(MemberType::Constituent);
 					Ok(())
 				}
@@ -527,15 +1636,23 @@ pub mod pallet {
 				let ty = maybe_member.take().ok_or(Error::<T>::NotMember)?;
 
 				// Check if have enough council members
-				if ty == MemberType::Constituent ||
-					Members::<T>::iter_values().filter(|m| *m == MemberType::Council).count() >
-						T::MinCouncilVotes::get()
-				{
-					VotingEligibility::<T>::take(&member);
-					Ok(ty)
+				if ty == MemberType::Council {
+					Council::<T>::try_mutate(|council| -> DispatchResult {
+						ensure!(council.len() > T::MinCouncilVotes::get(), Error::<T>::MinimalCouncilMembers);
+						council.retain(|m| *m != member);
+						Ok(())
+					})?;
 				} else {
-					Err(Error::<T>::MinimalCouncilMembers.into())
+					ConstituentCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+				}
+
+				VotingEligibility::<T>::take(&member);
+				if Delegations::<T>::take(&member).is_some() {
+					DelegationCount::<T>::mutate(|count| *count = count.saturating_sub(1));
 				}
+				Self::withdraw_candidacy(&member);
+				Self::retract_council_vote(&member);
+				Ok(ty)
 			})?;
 
 			Self::deposit_event(Event::RemoveMember(member, ty));
@@ -563,16 +1680,154 @@ pub mod pallet {
 			));
 			Ok(())
 		}
+
+		/// Set the approval threshold for proposals of the action with the given
+		/// call index, `None` resets it to the default threshold
+		///
+		/// This call can only be called after the approval of the committee
+		#[pallet::weight(T::WeightInfo::set_vote_threshold())]
+		pub fn set_vote_threshold(
+			origin: OriginFor<T>,
+			call_index: CallIndex,
+			threshold: Option<VoteThreshold>,
+		) -> DispatchResult {
+			T::ApprovedByCommitteeOrigin::ensure_origin(origin)?;
+
+			if let Some(ref threshold) = threshold {
+				ensure!(threshold.is_valid(), Error::<T>::InvalidVoteThreshold);
+				VoteThresholds::<T>::insert(call_index, threshold);
+			} else {
+				VoteThresholds::<T>::remove(call_index);
+			}
+
+			Self::deposit_event(Event::VoteThresholdUpdated(call_index, threshold));
+			Ok(())
+		}
+
+		/// Set the weighting of constituent votes
+		///
+		/// The weighting applies from the next voting period on, the weights of
+		/// constituents are snapshot when the voting period starts.
+		///
+		/// This call can only be called after the approval of the committee
+		#[pallet::weight(T::WeightInfo::set_vote_weighting())]
+		pub fn set_vote_weighting(origin: OriginFor<T>, weighting: VoteWeighting) -> DispatchResult {
+			T::ApprovedByCommitteeOrigin::ensure_origin(origin)?;
+			ConstituentVoteWeighting::<T>::put(weighting.clone());
+
+			Self::deposit_event(Event::VoteWeightingUpdated(weighting));
+			Ok(())
+		}
+
+		/// Delegate the sender's vote to a member of the council
+		///
+		/// This can only be called by constituents that are eligible to vote. For every proposal
+		/// the constituent does not vote on, their vote follows the vote of the delegate, as long
+		/// as the delegate is on the council. Delegations are snapshot at the end of every voting
+		/// period, later changes do not affect proposals whose voting period already ended. At
+		/// most `MaxDelegations` constituents can delegate their vote at the same time.
+		#[pallet::weight(T::WeightInfo::delegate())]
+		pub fn delegate(origin: OriginFor<T>, delegate: AccountIdFor<T>) -> DispatchResult {
+			let delegator = Self::ensure_member(origin)?;
+			ensure!(delegator.member_type == MemberType::Constituent, Error::<T>::NotConstituentMember);
+			// only constituents delegate, so delegations can not form chains or cycles
+			ensure!(Members::<T>::get(&delegate) == Some(MemberType::Council), Error::<T>::NotCouncilMember);
+
+			VotingEligibility::<T>::get(&delegator.account_id)
+				.filter(|block_number| frame_system::Pallet::<T>::block_number() >= *block_number)
+				.ok_or(Error::<T>::NotEligibileToVoteYet)?;
+
+			if !Delegations::<T>::contains_key(&delegator.account_id) {
+				DelegationCount::<T>::try_mutate(|count| -> DispatchResult {
+					ensure!(*count < T::MaxDelegations::get(), Error::<T>::TooManyDelegations);
+					*count += 1;
+					Ok(())
+				})?;
+			}
+			Delegations::<T>::insert(&delegator.account_id, &delegate);
+
+			Self::deposit_event(Event::Delegated(delegator.account_id, delegate));
+			Ok(())
+		}
+
+		/// Revoke the sender's delegation
+		#[pallet::weight(T::WeightInfo::undelegate())]
+		pub fn undelegate(origin: OriginFor<T>) -> DispatchResult {
+			let delegator = ensure_signed(origin)?;
+			Delegations::<T>::take(&delegator).ok_or(Error::<T>::NotDelegating)?;
+			DelegationCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+
+			Self::deposit_event(Event::Undelegated(delegator));
+			Ok(())
+		}
+
+		/// Nominate the sender for the next council election
+		///
+		/// Only members of the committee can stand for election. Sitting council
+		/// members have to nominate themselves again to be re-elected. At most
+		/// `MaxCouncilCandidates` members can stand for an election.
+		#[pallet::weight(T::WeightInfo::nominate())]
+		pub fn nominate(origin: OriginFor<T>) -> DispatchResult {
+			let candidate = Self::ensure_member(origin)?.account_id;
+			ensure!(!CouncilCandidates::<T>::contains_key(&candidate), Error::<T>::AlreadyCouncilCandidate);
+			CouncilCandidateCount::<T>::try_mutate(|count| -> DispatchResult {
+				ensure!(*count < T::MaxCouncilCandidates::get(), Error::<T>::TooManyCouncilCandidates);
+				*count += 1;
+				Ok(())
+			})?;
+
+			CouncilCandidates::<T>::insert(&candidate, 0);
+
+			Self::deposit_event(Event::CouncilCandidateNominated(candidate));
+			Ok(())
+		}
+
+		/// Vote for a candidate in the next council election
+		///
+		/// This can only be called by constituents that are eligible to vote. Each
+		/// constituent has a single vote, voting again moves the vote to the new
+		/// candidate.
+		#[transactional]
+		#[pallet::weight(T::WeightInfo::vote_candidate())]
+		pub fn vote_candidate(origin: OriginFor<T>, candidate: AccountIdFor<T>) -> DispatchResult {
+			let voter = Self::ensure_member(origin)?;
+			ensure!(voter.member_type == MemberType::Constituent, Error::<T>::NotConstituentMember);
+
+			VotingEligibility::<T>::get(&voter.account_id)
+				.filter(|block_number| frame_system::Pallet::<T>::block_number() >= *block_number)
+				.ok_or(Error::<T>::NotEligibileToVoteYet)?;
+
+			ensure!(CouncilCandidates::<T>::contains_key(&candidate), Error::<T>::NotCouncilCandidate);
+			Self::retract_council_vote(&voter.account_id);
+			CouncilCandidates::<T>::mutate(&candidate, |maybe_votes| {
+				if let Some(votes) = maybe_votes {
+					*votes = votes.saturating_add(1);
+				}
+			});
+			CouncilVotes::<T>::insert(&voter.account_id, (CouncilElection::<T>::get(), candidate.clone()));
+
+			Self::deposit_event(Event::CouncilVoteCast(voter.account_id, candidate));
+			Ok(())
+		}
 	}
 
 	/// Trait for the asset-index pallet extrinsic weights.
 	pub trait WeightInfo {
 		fn propose() -> Weight;
 		fn vote() -> Weight;
-		fn close() -> Weight;
+		fn close(d: u32) -> Weight;
 		fn add_constituent() -> Weight;
 		fn remove_member() -> Weight;
 		fn set_voting_period() -> Weight;
+		fn nominate() -> Weight;
+		fn vote_candidate() -> Weight;
+		fn set_vote_threshold() -> Weight;
+		fn withdraw_proposal() -> Weight;
+		fn delegate() -> Weight;
+		fn undelegate() -> Weight;
+		fn cancel_queued_proposal() -> Weight;
+		fn set_vote_weighting() -> Weight;
+		fn propose_batch(n: u32) -> Weight;
 	}
 
 	/// For backwards compatibility and tests
@@ -585,7 +1840,7 @@ pub mod pallet {
 			Default::default()
 		}
 
-		fn close() -> Weight {
+		fn close(_d: u32) -> Weight {
 			Default::default()
 		}
 
@@ -600,5 +1855,41 @@ pub mod pallet {
 		fn set_voting_period() -> Weight {
 			Default::default()
 		}
+
+		fn nominate() -> Weight {
+			Default::default()
+		}
+
+		fn vote_candidate() -> Weight {
+			Default::default()
+		}
+
+		fn set_vote_threshold() -> Weight {
+			Default::default()
+		}
+
+		fn withdraw_proposal() -> Weight {
+			Default::default()
+		}
+
+		fn delegate() -> Weight {
+			Default::default()
+		}
+
+		fn undelegate() -> Weight {
+			Default::default()
+		}
+
+		fn cancel_queued_proposal() -> Weight {
+			Default::default()
+		}
+
+		fn set_vote_weighting() -> Weight {
+			Default::default()
+		}
+
+		fn propose_batch(_n: u32) -> Weight {
+			Default::default()
+		}
 	}
 }
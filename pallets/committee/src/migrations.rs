@@ -0,0 +1,101 @@
+// Copyright 2021 ChainSafe Systems
+// SPDX-License-Identifier: LGPL-3.0-only
+
+//! Storage migrations of the committee pallet
+
+use crate::{
+	CommitteeMember, Config, ConstituentCount, Council, MemberType, MemberVote, Members, Pallet, Proposal,
+	ProposalStatus, Proposals, VoteAggregate, VoteKind, Votes,
+};
+use frame_support::{
+	pallet_prelude::*,
+	sp_std::prelude::*,
+	traits::{GetStorageVersion, StorageVersion},
+};
+
+/// The encoding of a proposal before proposals could hold a batch of actions
+#[derive(Decode)]
+struct SingleActionProposal<T: Config> {
+	action: T::Action,
+	issuer: T::AccountId,
+	nonce: T::ProposalNonce,
+	status: ProposalStatus,
+}
+
+/// The encoding of a vote before constituent votes were weighted
+#[derive(Decode)]
+struct UnweightedMemberVote<AccountId> {
+	member: CommitteeMember<AccountId>,
+	vote: VoteKind,
+}
+
+/// The encoding of the votes on a proposal before constituent votes were
+/// weighted
+#[derive(Decode)]
+struct UnweightedVoteAggregate<AccountId, BlockNumber> {
+	votes: Vec<UnweightedMemberVote<AccountId>>,
+	end: BlockNumber,
+}
+
+/// Migrates the stored single action proposals to proposals with a batch of one
+/// action, and seeds the `Council` with the council members.
+///
+/// The proposals are kept under their original hash, so that their votes,
+/// deposits, metadata and enactments still apply to them.
+pub fn migrate_to_v1<T: Config>() -> Weight {
+	if Pallet::<T>::on_chain_storage_version() >= 1 {
+		return T::DbWeight::get().reads(1);
+	}
+
+	let mut translated: Weight = 0;
+	Proposals::<T>::translate::<SingleActionProposal<T>, _>(|_, proposal| {
+		translated = translated.saturating_add(1);
+		Some(Proposal::new(proposal.action, proposal.issuer, proposal.nonce, proposal.status))
+	});
+
+	// the council members were only tracked in `Members` before
+	let mut members: Weight = 0;
+	let council: Vec<_> = Members::<T>::iter()
+		.inspect(|_| members = members.saturating_add(1))
+		.filter(|(_, member_type)| *member_type == MemberType::Council)
+		.map(|(member, _)| member)
+		.collect();
+	log::info!("Seeded the committee council with {} members", council.len());
+	Council::<T>::put(council);
+	StorageVersion::new(1).put::<Pallet<T>>();
+
+	log::info!("Migrated {} committee proposals to batches", translated);
+	T::DbWeight::get().reads_writes(translated.saturating_add(members).saturating_add(1), translated.saturating_add(2))
+}
+
+/// Migrates the stored votes to weighted votes, and seeds the
+/// `ConstituentCount` with the number of constituents.
+///
+/// Votes cast before constituent votes were weighted keep the same weight,
+/// like under the default `VoteWeighting::Equal`.
+pub fn migrate_to_v2<T: Config>() -> Weight {
+	if Pallet::<T>::on_chain_storage_version() >= 2 {
+		return T::DbWeight::get().reads(1);
+	}
+
+	let mut translated: Weight = 0;
+	Votes::<T>::translate::<UnweightedVoteAggregate<T::AccountId, T::BlockNumber>, _>(|_, votes| {
+		translated = translated.saturating_add(1);
+		Some(VoteAggregate {
+			votes: votes.votes.into_iter().map(|vote| MemberVote::new(vote.member, vote.vote)).collect(),
+			end: votes.end,
+		})
+	});
+
+	// the constituents were only tracked in `Members` before
+	let mut members: Weight = 0;
+	let constituents = Members::<T>::iter()
+		.inspect(|_| members = members.saturating_add(1))
+		.filter(|(_, member_type)| *member_type == MemberType::Constituent)
+		.count();
+	ConstituentCount::<T>::put(constituents as u32);
+	StorageVersion::new(2).put::<Pallet<T>>();
+
+	log::info!("Migrated the votes of {} committee proposals to weighted votes", translated);
+	T::DbWeight::get().reads_writes(translated.saturating_add(members).saturating_add(1), translated.saturating_add(2))
+}
@@ -4,16 +4,17 @@
 // Required as construct_runtime! produces code that violates this lint
 #![allow(clippy::from_over_into)]
 
-use crate::{self as pallet_committee, EnsureMember};
+use crate::{self as pallet_committee, Approval, EnsureMember, Quorum, VoteThreshold};
 #[cfg(feature = "std")]
 use frame_support::traits::GenesisBuild;
 use frame_support::{
-	ord_parameter_types, parameter_types, sp_io,
+	match_type, ord_parameter_types, parameter_types, sp_io,
 	sp_runtime::{
 		testing::Header,
 		traits::{BlakeTwo256, IdentityLookup},
+		Percent,
 	},
-	traits::{OnFinalize, OnInitialize},
+	traits::{Currency, OnFinalize, OnInitialize, StorageMapShim},
 };
 use frame_system::{self as system, EnsureSignedBy};
 
@@ -32,6 +33,7 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Committee: pallet_committee::{Pallet, Call, Storage, Origin<T>, Event<T>},
 	}
 );
@@ -42,6 +44,7 @@ parameter_types! {
 }
 
 pub(crate) type AccountId = u64;
+pub(crate) type Balance = u64;
 
 impl system::Config for Test {
 	type BaseCallFilter = Everything;
@@ -69,6 +72,29 @@ impl system::Config for Test {
 	type OnSetCode = ();
 }
 
+// param types for balances
+parameter_types! {
+	pub const MaxLocks: u32 = 1024;
+	pub const ExistentialDeposit: Balance = 0;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type Event = Event;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = StorageMapShim<
+		pallet_balances::Account<Test>,
+		system::Provider<Test>,
+		Balance,
+		pallet_balances::AccountData<Balance>,
+	>;
+	type MaxLocks = MaxLocks;
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+}
+
 pub(crate) const PROPOSAL_SUBMISSION_PERIOD: <Test as system::Config>::BlockNumber = 10;
 pub(crate) const VOTING_PERIOD: <Test as system::Config>::BlockNumber = 27;
 pub(crate) const WEEKS: <Test as system::Config>::BlockNumber = 7;
@@ -81,13 +107,57 @@ pub(crate) const PROPOSER_ACCOUNT_ID: AccountId = 88;
 pub(crate) const EXECUTER_ACCOUNT_ID: AccountId = PROPOSER_ACCOUNT_ID;
 pub(crate) const MIN_COUNCIL_MEMBERS: usize = 4;
 pub(crate) const MIN_COUNCIL_VOTES: usize = 4;
+pub(crate) const COUNCIL_SEATS: u32 = 5;
+pub(crate) const MAX_COUNCIL_CANDIDATES: u32 = 6;
+pub(crate) const COUNCIL_TERM_LENGTH: u32 = 2;
+pub(crate) const PROPOSAL_DEPOSIT: Balance = 10;
+pub(crate) const MAX_BATCH_SIZE: u32 = 3;
+pub(crate) const STRING_LIMIT: u32 = 32;
+pub(crate) const EMERGENCY_VOTING_PERIOD: <Test as system::Config>::BlockNumber = 5;
+pub(crate) const PROPOSAL_RETENTION_PERIOD: <Test as system::Config>::BlockNumber = 50;
+pub(crate) const MAX_PRUNED_PROPOSALS: u32 = 2;
+pub(crate) const MAX_DELEGATIONS: u32 = 4;
+pub(crate) const MAX_WEIGHT_REMOVALS: u32 = 1;
+pub(crate) const MAX_CONSTITUENTS: u32 = 10;
+pub(crate) const MAX_ENACTMENTS_PER_BLOCK: u32 = 2;
+pub(crate) const INITIAL_BALANCE: Balance = 1_000;
 
 ord_parameter_types! {
 	pub const AdminAccountId: AccountId = PROPOSER_ACCOUNT_ID;
 	pub const ExecuterAccountId: AccountId = EXECUTER_ACCOUNT_ID;
 	pub const MinCouncilMembers: usize = MIN_COUNCIL_MEMBERS;
 	pub const MinCouncilVotes: usize = MIN_COUNCIL_VOTES;
+	pub const CouncilSeats: u32 = COUNCIL_SEATS;
+	pub const MaxCouncilCandidates: u32 = MAX_COUNCIL_CANDIDATES;
+	pub const CouncilTermLength: u32 = COUNCIL_TERM_LENGTH;
+	pub const ProposalDeposit: Balance = PROPOSAL_DEPOSIT;
+	pub const MaxBatchSize: u32 = MAX_BATCH_SIZE;
+	pub const StringLimit: u32 = STRING_LIMIT;
+	pub const ProposalRetentionPeriod: <Test as system::Config>::BlockNumber = PROPOSAL_RETENTION_PERIOD;
+	pub const MaxPrunedProposals: u32 = MAX_PRUNED_PROPOSALS;
+	pub const MaxDelegations: u32 = MAX_DELEGATIONS;
+	pub const MaxWeightRemovals: u32 = MAX_WEIGHT_REMOVALS;
+	pub const MaxConstituents: u32 = MAX_CONSTITUENTS;
+	pub const MaxEnactmentsPerBlock: u32 = MAX_ENACTMENTS_PER_BLOCK;
+	// proposals are executed when they are closed unless a test sets a delay
+	pub static EnactmentDelay: <Test as system::Config>::BlockNumber = 0;
+	pub ExpeditedThreshold: VoteThreshold = VoteThreshold {
+		quorum: Quorum::Percent(Percent::from_percent(100)),
+		approval: Approval::Unanimous,
+		constituent_veto: true,
+	};
+	pub const EmergencyVotingPeriod: <Test as system::Config>::BlockNumber = EMERGENCY_VOTING_PERIOD;
+	pub EmergencyThreshold: VoteThreshold = VoteThreshold {
+		quorum: Quorum::Absolute(0),
+		approval: Approval::SuperMajority(Percent::from_percent(75)),
+		constituent_veto: false,
+	};
+}
 
+match_type! {
+	pub type EmergencyCalls: impl Contains<Call> = {
+		Call::Committee(pallet_committee::Call::remove_member { .. })
+	};
 }
 
 type EnsureApprovedByCommittee =
@@ -105,15 +175,48 @@ impl<T: frame_system::Config> crate::traits::VotingPeriodRange<T::BlockNumber> f
 	}
 }
 
+/// Uses the balances of accounts as their index token holdings, reserved
+/// balances count as locked index tokens
+pub struct IndexTokenHoldings;
+
+impl crate::traits::IndexTokenHoldings<AccountId> for IndexTokenHoldings {
+	fn balance(who: &AccountId) -> u128 {
+		Balances::total_balance(who).into()
+	}
+
+	fn locked(who: &AccountId) -> u128 {
+		Balances::reserved_balance(who).into()
+	}
+}
+
 impl pallet_committee::Config for Test {
 	type VotingPeriodRange = VotingPeriodRange<Self>;
 	type ProposalSubmissionPeriod = ProposalSubmissionPeriod;
 	type VotingPeriod = VotingPeriod;
 	type MinCouncilVotes = MinCouncilVotes;
+	type CouncilSeats = CouncilSeats;
+	type MaxCouncilCandidates = MaxCouncilCandidates;
+	type CouncilTermLength = CouncilTermLength;
+	type Currency = Balances;
+	type ProposalDeposit = ProposalDeposit;
+	type ProposalRetentionPeriod = ProposalRetentionPeriod;
+	type MaxPrunedProposals = MaxPrunedProposals;
+	type MaxDelegations = MaxDelegations;
+	type MaxWeightRemovals = MaxWeightRemovals;
+	type MaxConstituents = MaxConstituents;
+	type EnactmentDelay = EnactmentDelay;
+	type MaxEnactmentsPerBlock = MaxEnactmentsPerBlock;
+	type ExpeditedThreshold = ExpeditedThreshold;
+	type EmergencyCalls = EmergencyCalls;
+	type EmergencyVotingPeriod = EmergencyVotingPeriod;
+	type EmergencyThreshold = EmergencyThreshold;
+	type IndexTokenHoldings = IndexTokenHoldings;
 	type ProposalSubmissionOrigin = EnsureSignedBy<AdminAccountId, AccountId>;
 	type ProposalExecutionOrigin = EnsureMember<Self>;
 	type ApprovedByCommitteeOrigin = EnsureApprovedByCommittee;
 	type ProposalNonce = u32;
+	type MaxBatchSize = MaxBatchSize;
+	type StringLimit = StringLimit;
 	type Origin = Origin;
 	type Action = Call;
 	type Event = Event;
@@ -141,6 +244,9 @@ where
 
 	let mut council_members = vec![PROPOSER_ACCOUNT_ID];
 	council_members.append(&mut members.into_iter().collect());
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(PROPOSER_ACCOUNT_ID, INITIAL_BALANCE)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
 	pallet_committee::GenesisConfig::<Test> { council_members, constituent_members: Default::default() }
 		.assimilate_storage(&mut t)
 		.unwrap();
@@ -151,6 +257,9 @@ where
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext_without_members() -> sp_io::TestExternalities {
 	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(PROPOSER_ACCOUNT_ID, INITIAL_BALANCE)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
 	pallet_committee::GenesisConfig::<Test> {
 		council_members: vec![PROPOSER_ACCOUNT_ID],
 		constituent_members: Default::default(),
@@ -4,10 +4,17 @@
 use crate as pallet;
 
 use crate::{
-	mock::*, traits::VotingPeriodRange as _, CommitteeMember, MemberType, ProposalStatus, Proposals, VoteAggregate,
-	VoteKind, VotingEligibility,
+	mock::*, traits::VotingPeriodRange as _, Approval, CommitteeMember, MemberType, ProposalMetadata, ProposalStatus,
+	Proposals, Quorum, VoteAggregate, VoteCount, VoteKind, VoteThreshold, VoteWeighting, VotingEligibility,
+	VotingWindow,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	codec::Encode,
+	sp_runtime::{traits::BadOrigin, Percent},
+	storage::unhashed,
+	traits::{Currency, GetStorageVersion, Hooks, ReservableCurrency, StorageVersion},
 };
-use frame_support::{assert_noop, assert_ok, codec::Encode, sp_runtime::traits::BadOrigin};
 use frame_system as system;
 use std::convert::{TryFrom, TryInto};
 
@@ -34,7 +41,7 @@ fn make_action(value: u64) -> Call {
 fn submit_proposal(action_value: u64) -> pallet::Proposal<Test> {
 	let action = make_action(action_value);
 	let expected_nonce = pallet::ProposalCount::<Test>::get();
-	assert_ok!(Committee::propose(Origin::signed(PROPOSER_ACCOUNT_ID), Box::new(action.clone())));
+	assert_ok!(Committee::propose(Origin::signed(PROPOSER_ACCOUNT_ID), Box::new(action.clone()), Default::default()));
 	pallet::Proposal::<Test>::new(action, PROPOSER_ACCOUNT_ID, expected_nonce, ProposalStatus::Active)
 }
 
@@ -51,10 +58,44 @@ fn proposer_can_create_a_proposal() {
 	});
 }
 
+#[test]
+fn proposal_metadata_is_stored_with_proposal() {
+	new_test_ext(EMPTY_RANGE).execute_with(|| {
+		let metadata = ProposalMetadata { title: b"Add KSM".to_vec(), description: b"ipfs://Qm".to_vec() };
+		assert_ok!(Committee::propose(
+			Origin::signed(PROPOSER_ACCOUNT_ID),
+			Box::new(make_action(123)),
+			metadata.clone()
+		));
+		let proposal = pallet::Proposal::<Test>::new(make_action(123), PROPOSER_ACCOUNT_ID, 0, ProposalStatus::Active);
+
+		assert_eq!(
+			last_event(),
+			Event::Committee(crate::Event::Proposed(PROPOSER_ACCOUNT_ID, 0, proposal.hash(), metadata.clone()))
+		);
+		assert_eq!(Committee::proposal_metadata(&proposal.hash()), Some(metadata.clone()));
+		assert_eq!(Committee::proposal_info(proposal.hash()).map(|info| info.metadata), Some(metadata));
+	});
+}
+
+#[test]
+fn cannot_create_a_proposal_with_oversized_metadata() {
+	new_test_ext(EMPTY_RANGE).execute_with(|| {
+		let metadata = ProposalMetadata { title: vec![0; STRING_LIMIT as usize + 1], description: vec![] };
+		assert_noop!(
+			Committee::propose(Origin::signed(PROPOSER_ACCOUNT_ID), Box::new(make_action(123)), metadata),
+			pallet::Error::<Test>::BadMetadata
+		);
+	});
+}
+
 #[test]
 fn non_proposer_cannot_create_a_proposal() {
 	new_test_ext(EMPTY_RANGE).execute_with(|| {
-		assert_noop!(Committee::propose(Origin::signed(ASHLEY), Box::new(make_action(123))), BadOrigin);
+		assert_noop!(
+			Committee::propose(Origin::signed(ASHLEY), Box::new(make_action(123)), Default::default()),
+			BadOrigin
+		);
 		assert!(Committee::active_proposals().is_empty());
 	});
 }
@@ -91,7 +132,7 @@ fn cannot_exceed_max_nonce() {
 		submit_proposal(123);
 
 		assert_noop!(
-			Committee::propose(Origin::signed(PROPOSER_ACCOUNT_ID), Box::new(make_action(123))),
+			Committee::propose(Origin::signed(PROPOSER_ACCOUNT_ID), Box::new(make_action(123)), Default::default()),
 			pallet::Error::<Test>::ProposalNonceExhausted
 		);
 	});
@@ -254,6 +295,26 @@ fn member_cannot_vote_multiple_times() {
 	});
 }
 
+#[test]
+fn member_can_change_vote() {
+	new_test_ext(ASHLEY_RANGE).execute_with(|| {
+		let proposal = submit_proposal(123);
+		let expected_votes = VoteAggregate::<AccountId, u64>::new(vec![], vec![ASHLEY_COUNCIL], vec![], START_OF_V1);
+
+		run_to_block(START_OF_S1);
+		assert_ok!(Committee::vote(Origin::signed(ASHLEY), proposal.hash(), VoteKind::Aye));
+		assert_ok!(Committee::vote(Origin::signed(ASHLEY), proposal.hash(), VoteKind::Nay));
+		assert_eq!(Committee::get_votes_for(&proposal.hash()), Some(expected_votes));
+
+		// cannot change the vote once the voting period is over
+		run_to_block(START_OF_V1);
+		assert_noop!(
+			Committee::vote(Origin::signed(ASHLEY), proposal.hash(), VoteKind::Aye),
+			pallet::Error::<Test>::NotInVotingPeriod
+		);
+	});
+}
+
 //
 // Closing/executing a proposal
 //
@@ -359,6 +420,202 @@ fn cannot_close_if_constituents_veto() {
 	});
 }
 
+// give the accounts the given index token balances
+fn set_index_token_balances<I>(balances: I)
+where
+	I: IntoIterator<Item = (AccountId, Balance)>,
+{
+	for (account, balance) in balances.into_iter() {
+		Balances::make_free_balance_be(&account, balance);
+	}
+}
+
+#[test]
+fn weighted_constituents_can_veto_with_minority_of_votes() {
+	new_test_ext(0..4).execute_with(|| {
+		add_constituents(4..8);
+		set_index_token_balances(vec![(4, 1_000), (5, 10), (6, 10), (7, 10)]);
+		assert_ok!(Committee::set_vote_weighting(Origin::root(), VoteWeighting::IndexTokenBalance));
+		run_to_block(START_OF_V1);
+
+		let proposal = submit_proposal(123);
+		run_to_block(START_OF_V1 + START_OF_S1 - 1);
+
+		vote_with_each(0..4, proposal.hash(), VoteKind::Aye);
+		vote_with_each(4..5, proposal.hash(), VoteKind::Nay);
+		vote_with_each(5..8, proposal.hash(), VoteKind::Aye);
+
+		let tally = Committee::proposal_info(proposal.hash()).unwrap().tally;
+		assert_eq!(tally.constituents, VoteCount { ayes: 3, nays: 1, abstentions: 0 });
+		assert_eq!(tally.constituent_weights, VoteCount { ayes: 30, nays: 1_000, abstentions: 0 });
+
+		run_to_block(START_OF_V1 + START_OF_S1 + 1);
+		assert_noop!(
+			Committee::close(Origin::signed(EXECUTER_ACCOUNT_ID), proposal.hash()),
+			pallet::Error::<Test>::ProposalNotAcceptedConstituentVeto
+		);
+	});
+}
+
+#[test]
+fn weighted_constituents_cannot_veto_with_minority_of_tokens() {
+	new_test_ext(0..4).execute_with(|| {
+		add_constituents(4..8);
+		set_index_token_balances(vec![(4, 1_000), (5, 10), (6, 10), (7, 10)]);
+		assert_ok!(Committee::set_vote_weighting(Origin::root(), VoteWeighting::IndexTokenBalance));
+		run_to_block(START_OF_V1);
+
+		let proposal = submit_proposal(123);
+		run_to_block(START_OF_V1 + START_OF_S1 - 1);
+
+		vote_with_each(0..5, proposal.hash(), VoteKind::Aye);
+		vote_with_each(5..8, proposal.hash(), VoteKind::Nay);
+
+		run_to_block(START_OF_V1 + START_OF_S1 + 1);
+		assert_ok!(Committee::close(Origin::signed(EXECUTER_ACCOUNT_ID), proposal.hash()));
+	});
+}
+
+#[test]
+fn constituent_weights_are_snapshot_when_the_voting_period_starts() {
+	new_test_ext(0..4).execute_with(|| {
+		add_constituents(4..6);
+		set_index_token_balances(vec![(4, 100), (5, 10)]);
+		assert_ok!(Committee::set_vote_weighting(Origin::root(), VoteWeighting::LockedIndexTokens));
+		assert_ok!(Balances::reserve(&4, 50));
+		run_to_block(START_OF_V1);
+
+		let proposal = submit_proposal(123);
+		let end = START_OF_V1 + START_OF_S1;
+		run_to_block(end - VOTING_PERIOD - 1);
+		assert!(!pallet::ConstituentWeights::<Test>::contains_key(end, 4));
+		run_to_block(end - VOTING_PERIOD);
+		assert_eq!(pallet::ConstituentWeights::<Test>::get(end, 4), 50);
+		assert_eq!(pallet::ConstituentWeights::<Test>::get(end, 5), 0);
+
+		// changes after the snapshot do not affect the weights
+		Balances::unreserve(&4, 50);
+		assert_ok!(Balances::reserve(&5, 10));
+		assert_eq!(Committee::constituent_weight(&4, end), 50);
+		assert_eq!(Committee::constituent_weight(&5, end), 0);
+
+		run_to_block(end - 1);
+		vote_with_each(4..5, proposal.hash(), VoteKind::Nay);
+		vote_with_each(5..6, proposal.hash(), VoteKind::Aye);
+
+		let tally = Committee::proposal_info(proposal.hash()).unwrap().tally;
+		assert_eq!(tally.constituent_weights, VoteCount { ayes: 0, nays: 50, abstentions: 0 });
+	});
+}
+
+#[test]
+fn constituent_weights_cannot_be_counted_twice_by_transferring_tokens() {
+	new_test_ext(0..4).execute_with(|| {
+		add_constituents(4..6);
+		set_index_token_balances(vec![(4, 100), (5, 10)]);
+		assert_ok!(Committee::set_vote_weighting(Origin::root(), VoteWeighting::IndexTokenBalance));
+		run_to_block(START_OF_V1);
+
+		let proposal = submit_proposal(123);
+		run_to_block(START_OF_V1 + START_OF_S1 - 1);
+
+		// the tokens 4 voted with are moved to 5, which votes with them again
+		vote_with_each(4..5, proposal.hash(), VoteKind::Nay);
+		assert_ok!(Balances::transfer(Origin::signed(4), 5, 90));
+		vote_with_each(5..6, proposal.hash(), VoteKind::Nay);
+
+		let tally = Committee::proposal_info(proposal.hash()).unwrap().tally;
+		assert_eq!(tally.constituent_weights, VoteCount { ayes: 0, nays: 110, abstentions: 0 });
+	});
+}
+
+#[test]
+fn delegator_weights_are_snapshot_when_the_voting_period_starts() {
+	new_test_ext(0..4).execute_with(|| {
+		add_constituents(4..7);
+		set_index_token_balances(vec![(4, 100), (5, 10), (6, 20)]);
+		assert_ok!(Committee::set_vote_weighting(Origin::root(), VoteWeighting::LockedIndexTokens));
+		assert_ok!(Balances::reserve(&5, 10));
+		assert_ok!(Balances::reserve(&6, 20));
+		run_to_block(START_OF_V1);
+		assert_ok!(Committee::delegate(Origin::signed(5), ASHLEY));
+
+		let proposal = submit_proposal(123);
+		run_to_block(START_OF_V1 + START_OF_S1 - 1);
+		assert_ok!(Committee::delegate(Origin::signed(6), ASHLEY));
+
+		// changes after the snapshot do not affect the weights
+		Balances::unreserve(&5, 10);
+		Balances::unreserve(&6, 20);
+		assert_eq!(Committee::constituent_weight(&5, START_OF_V1 + START_OF_S1), 10);
+		assert_eq!(Committee::constituent_weight(&6, START_OF_V1 + START_OF_S1), 20);
+
+		vote_with_each(0..1, proposal.hash(), VoteKind::Aye);
+		vote_with_each(4..5, proposal.hash(), VoteKind::Aye);
+		run_to_block(START_OF_V1 + START_OF_S1);
+		let tally = Committee::proposal_info(proposal.hash()).unwrap().tally;
+		assert_eq!(tally.constituent_weights, VoteCount { ayes: 30, nays: 0, abstentions: 0 });
+	});
+}
+
+#[test]
+fn expired_constituent_weights_are_removed_over_several_blocks() {
+	new_test_ext(0..4).execute_with(|| {
+		add_constituents(4..6);
+		set_index_token_balances(vec![(4, 100), (5, 10)]);
+		assert_ok!(Committee::set_vote_weighting(Origin::root(), VoteWeighting::IndexTokenBalance));
+		run_to_block(START_OF_V1);
+
+		let proposal = submit_proposal(123);
+		let end = START_OF_V1 + START_OF_S1;
+		run_to_block(end - 1);
+		vote_with_each(4..6, proposal.hash(), VoteKind::Aye);
+		assert_eq!(pallet::ConstituentWeights::<Test>::iter_prefix(end).count(), 2);
+
+		// proposals of the voting period can be closed until the end of the next one
+		run_to_block(end + START_OF_S1);
+		assert!(pallet::VoteWeightingSnapshots::<Test>::get(end).is_none());
+		assert_eq!(pallet::ExpiredWeightSnapshots::<Test>::get(), vec![end]);
+
+		// at most `MaxWeightRemovals` weights are removed per block
+		run_to_block(end + START_OF_S1 + 1);
+		assert_eq!(pallet::ConstituentWeights::<Test>::iter_prefix(end).count(), 1);
+		run_to_block(end + START_OF_S1 + 2);
+		assert_eq!(pallet::ConstituentWeights::<Test>::iter_prefix(end).count(), 0);
+		assert!(pallet::ExpiredWeightSnapshots::<Test>::get().is_empty());
+	});
+}
+
+#[test]
+fn vote_weighting_applies_from_next_voting_period() {
+	new_test_ext(0..4).execute_with(|| {
+		add_constituents(4..5);
+		set_index_token_balances(vec![(4, 100)]);
+		run_to_block(START_OF_S1);
+		assert_ok!(Committee::set_vote_weighting(Origin::root(), VoteWeighting::IndexTokenBalance));
+		assert_eq!(
+			last_event(),
+			Event::Committee(crate::Event::VoteWeightingUpdated(VoteWeighting::IndexTokenBalance))
+		);
+
+		// the current voting period was snapshot with equal weights
+		assert_eq!(Committee::constituent_weight(&4, START_OF_V1), 1);
+
+		run_to_block(START_OF_V1 + START_OF_S1);
+		assert_eq!(Committee::constituent_weight(&4, START_OF_V1 + START_OF_S1), 100);
+	});
+}
+
+#[test]
+fn non_governance_origin_cannot_set_vote_weighting() {
+	new_test_ext(0..4).execute_with(|| {
+		assert_noop!(
+			Committee::set_vote_weighting(Origin::signed(ASHLEY), VoteWeighting::IndexTokenBalance),
+			BadOrigin
+		);
+	});
+}
+
 #[test]
 fn executer_can_close_if_voted_for_and_voting_period_elapsed() {
 	new_test_ext(0..4).execute_with(|| {
@@ -372,6 +629,109 @@ fn executer_can_close_if_voted_for_and_voting_period_elapsed() {
 	});
 }
 
+#[test]
+fn can_set_vote_threshold() {
+	new_test_ext(0..4).execute_with(|| {
+		let call_index = Committee::call_index(&make_action(123));
+		let threshold = VoteThreshold {
+			quorum: Quorum::Percent(Percent::from_percent(100)),
+			approval: Approval::SuperMajority(Percent::from_percent(67)),
+			constituent_veto: false,
+		};
+
+		assert_noop!(Committee::set_vote_threshold(Origin::signed(ASHLEY), call_index, None), BadOrigin);
+		assert_noop!(
+			Committee::set_vote_threshold(
+				Origin::root(),
+				call_index,
+				Some(VoteThreshold {
+					approval: Approval::SuperMajority(Percent::from_percent(50)),
+					..threshold.clone()
+				})
+			),
+			pallet::Error::<Test>::InvalidVoteThreshold
+		);
+
+		assert_ok!(Committee::set_vote_threshold(Origin::root(), call_index, Some(threshold.clone())));
+		assert_eq!(Committee::vote_threshold(call_index), threshold);
+
+		assert_ok!(Committee::set_vote_threshold(Origin::root(), call_index, None));
+		assert_eq!(Committee::vote_threshold(call_index), VoteThreshold::default());
+	});
+}
+
+#[test]
+fn cannot_close_without_unanimity_if_required() {
+	new_test_ext(0..4).execute_with(|| {
+		let threshold = VoteThreshold { approval: Approval::Unanimous, ..Default::default() };
+		assert_ok!(Committee::set_vote_threshold(
+			Origin::root(),
+			Committee::call_index(&make_action(123)),
+			Some(threshold)
+		));
+		let proposal = submit_proposal(123);
+
+		run_to_block(START_OF_S1);
+		vote_with_each(0..4, proposal.hash(), VoteKind::Aye);
+		vote_with_each(vec![PROPOSER_ACCOUNT_ID], proposal.hash(), VoteKind::Nay);
+
+		run_to_block(START_OF_V1 + 1);
+		assert_noop!(
+			Committee::close(Origin::signed(EXECUTER_ACCOUNT_ID), proposal.hash()),
+			pallet::Error::<Test>::ProposalNotAcceptedCouncilDeny
+		);
+	});
+}
+
+#[test]
+fn cannot_close_without_quorum_percentage() {
+	new_test_ext(0..4).execute_with(|| {
+		let threshold = VoteThreshold { quorum: Quorum::Percent(Percent::from_percent(100)), ..Default::default() };
+		assert_ok!(Committee::set_vote_threshold(
+			Origin::root(),
+			Committee::call_index(&make_action(123)),
+			Some(threshold)
+		));
+		let proposal = submit_proposal(123);
+		// thresholds only apply to the action they are set for
+		let other_proposal = submit_proposal(456);
+
+		run_to_block(START_OF_S1);
+		vote_with_each(0..4, proposal.hash(), VoteKind::Aye);
+		vote_with_each(0..4, other_proposal.hash(), VoteKind::Aye);
+
+		run_to_block(START_OF_V1 + 1);
+		assert_noop!(
+			Committee::close(Origin::signed(EXECUTER_ACCOUNT_ID), proposal.hash()),
+			pallet::Error::<Test>::ProposalNotAcceptedInsufficientVotes
+		);
+		assert_ok!(Committee::close(Origin::signed(EXECUTER_ACCOUNT_ID), other_proposal.hash()));
+	});
+}
+
+#[test]
+fn constituents_cannot_veto_if_disabled() {
+	new_test_ext(0..4).execute_with(|| {
+		let threshold = VoteThreshold { constituent_veto: false, ..Default::default() };
+		assert_ok!(Committee::set_vote_threshold(
+			Origin::root(),
+			Committee::call_index(&make_action(123)),
+			Some(threshold)
+		));
+		add_constituents(4..8);
+		run_to_block(START_OF_V1);
+
+		let proposal = submit_proposal(123);
+		run_to_block(START_OF_V1 + START_OF_S1 - 1);
+
+		vote_with_each(0..4, proposal.hash(), VoteKind::Aye);
+		vote_with_each(4..8, proposal.hash(), VoteKind::Nay);
+
+		run_to_block(START_OF_V1 + START_OF_S1 + 1);
+		assert_ok!(Committee::close(Origin::signed(EXECUTER_ACCOUNT_ID), proposal.hash()));
+	});
+}
+
 #[test]
 fn cannot_execute_proposal_twice() {
 	new_test_ext(0..4).execute_with(|| {
@@ -464,6 +824,24 @@ fn cannot_add_constituent_if_already_is_constituent() {
 	});
 }
 
+#[test]
+fn constituents_are_bounded() {
+	new_test_ext(0..4).execute_with(|| {
+		let constituents = 10..10 + MAX_CONSTITUENTS as AccountId;
+		add_constituents(constituents.clone());
+		assert_eq!(pallet::ConstituentCount::<Test>::get(), MAX_CONSTITUENTS);
+		assert_noop!(
+			Committee::add_constituent(Origin::root(), CONSTITUENT),
+			<pallet::Error<Test>>::TooManyConstituents
+		);
+
+		// removing a constituent makes room for another one
+		assert_ok!(Committee::remove_member(Origin::root(), 10));
+		assert_ok!(Committee::add_constituent(Origin::root(), CONSTITUENT));
+		assert_eq!(pallet::ConstituentCount::<Test>::get(), MAX_CONSTITUENTS);
+	});
+}
+
 #[test]
 fn can_remove_member() {
 	new_test_ext(0..4).execute_with(|| {
@@ -507,11 +885,12 @@ fn propose_constituent_works() {
 		// propose a new constituent
 		assert_ok!(Committee::propose(
 			Origin::signed(PROPOSER_ACCOUNT_ID),
-			Box::new(Call::Committee(crate::Call::add_constituent { constituent: CONSTITUENT }))
+			Box::new(Call::Committee(crate::Call::add_constituent { constituent: CONSTITUENT })),
+			Default::default()
 		));
 
 		// test if proposal submitted with event
-		if let Event::Committee(crate::Event::Proposed(_, _, hash)) = last_event() {
+		if let Event::Committee(crate::Event::Proposed(_, _, hash, _)) = last_event() {
 			assert_eq!(&[hash], Committee::active_proposals().as_slice());
 
 			// vote Aye on adding new constituent
@@ -553,3 +932,820 @@ fn can_set_voting_period() {
 		assert_eq!(pallet::VotingPeriod::<Test>::get(), WEEKS);
 	});
 }
+
+//
+// Queued execution
+//
+
+fn propose_new_constituent() -> pallet::Proposal<Test> {
+	let action = Call::Committee(crate::Call::add_constituent { constituent: CONSTITUENT });
+	let expected_nonce = pallet::ProposalCount::<Test>::get();
+	assert_ok!(Committee::propose(Origin::signed(PROPOSER_ACCOUNT_ID), Box::new(action.clone()), Default::default()));
+	pallet::Proposal::<Test>::new(action, PROPOSER_ACCOUNT_ID, expected_nonce, ProposalStatus::Active)
+}
+
+#[test]
+fn accepted_proposals_are_queued_for_enactment_delay() {
+	new_test_ext(0..4).execute_with(|| {
+		EnactmentDelay::set(10);
+		let proposal = propose_new_constituent();
+
+		run_to_block(START_OF_S1);
+		vote_with_each(0..4, proposal.hash(), VoteKind::Aye);
+
+		run_to_block(START_OF_V1 + 1);
+		assert_ok!(Committee::close(Origin::signed(EXECUTER_ACCOUNT_ID), proposal.hash()));
+		assert_eq!(last_event(), Event::Committee(crate::Event::ProposalQueued(proposal.hash(), START_OF_V1 + 11)));
+		assert_eq!(Committee::get_proposal(&proposal.hash()).map(|p| p.status), Some(ProposalStatus::Queued));
+		assert_eq!(Committee::queued_proposals().len(), 1);
+		assert_noop!(
+			Committee::close(Origin::signed(EXECUTER_ACCOUNT_ID), proposal.hash()),
+			pallet::Error::<Test>::ProposalAlreadyQueued
+		);
+
+		run_to_block(START_OF_V1 + 10);
+		assert!(!<pallet::Members<Test>>::contains_key(CONSTITUENT));
+
+		run_to_block(START_OF_V1 + 11);
+		assert!(<pallet::Members<Test>>::contains_key(CONSTITUENT));
+		assert_eq!(Committee::get_proposal(&proposal.hash()).map(|p| p.status), Some(ProposalStatus::Executed));
+		assert!(Committee::queued_proposals().is_empty());
+	});
+}
+
+#[test]
+fn queued_proposals_are_carried_over_beyond_the_enactment_limit() {
+	new_test_ext(0..4).execute_with(|| {
+		EnactmentDelay::set(10);
+		let proposals: Vec<_> = (0..MAX_ENACTMENTS_PER_BLOCK as u64 + 1).map(submit_proposal).collect();
+
+		run_to_block(START_OF_S1);
+		for proposal in &proposals {
+			vote_with_each(0..4, proposal.hash(), VoteKind::Aye);
+		}
+		run_to_block(START_OF_V1 + 1);
+		for proposal in &proposals {
+			assert_ok!(Committee::close(Origin::signed(EXECUTER_ACCOUNT_ID), proposal.hash()));
+		}
+
+		let status = |proposal: &pallet::Proposal<Test>| Committee::get_proposal(&proposal.hash()).map(|p| p.status);
+		run_to_block(START_OF_V1 + 11);
+		for proposal in &proposals[..MAX_ENACTMENTS_PER_BLOCK as usize] {
+			assert_eq!(status(proposal), Some(ProposalStatus::Executed));
+		}
+		let carried = proposals.last().unwrap();
+		assert_eq!(status(carried), Some(ProposalStatus::Queued));
+		assert_eq!(pallet::QueuedProposals::<Test>::get(carried.hash()).map(|e| e.at), Some(START_OF_V1 + 12));
+
+		run_to_block(START_OF_V1 + 12);
+		assert_eq!(status(carried), Some(ProposalStatus::Executed));
+		assert!(Committee::queued_proposals().is_empty());
+	});
+}
+
+#[test]
+fn expedited_proposals_skip_enactment_delay() {
+	new_test_ext(0..4).execute_with(|| {
+		EnactmentDelay::set(10);
+		let proposal = propose_new_constituent();
+
+		// the whole council supports the proposal
+		run_to_block(START_OF_S1);
+		vote_with_each(0..4, proposal.hash(), VoteKind::Aye);
+		vote_with_each(vec![PROPOSER_ACCOUNT_ID], proposal.hash(), VoteKind::Aye);
+
+		run_to_block(START_OF_V1 + 1);
+		assert_ok!(Committee::close(Origin::signed(EXECUTER_ACCOUNT_ID), proposal.hash()));
+		assert!(<pallet::Members<Test>>::contains_key(CONSTITUENT));
+		assert!(Committee::queued_proposals().is_empty());
+	});
+}
+
+#[test]
+fn committee_can_cancel_queued_proposals() {
+	new_test_ext(0..4).execute_with(|| {
+		EnactmentDelay::set(100);
+		let proposal = propose_new_constituent();
+
+		run_to_block(START_OF_S1);
+		vote_with_each(0..4, proposal.hash(), VoteKind::Aye);
+		run_to_block(START_OF_V1 + 1);
+		assert_ok!(Committee::close(Origin::signed(EXECUTER_ACCOUNT_ID), proposal.hash()));
+
+		// cancellations are executed without delay
+		let cancel = Call::Committee(crate::Call::cancel_queued_proposal { proposal_hash: proposal.hash() });
+		assert_ok!(Committee::propose(
+			Origin::signed(PROPOSER_ACCOUNT_ID),
+			Box::new(cancel.clone()),
+			Default::default()
+		));
+		let cancel = pallet::Proposal::<Test>::new(cancel, PROPOSER_ACCOUNT_ID, 1, ProposalStatus::Active);
+
+		run_to_block(START_OF_V1 + START_OF_S1 - 1);
+		vote_with_each(0..4, cancel.hash(), VoteKind::Aye);
+		run_to_block(START_OF_V1 + START_OF_S1 + 1);
+		assert_ok!(Committee::close(Origin::signed(EXECUTER_ACCOUNT_ID), cancel.hash()));
+
+		assert_eq!(Committee::get_proposal(&proposal.hash()).map(|p| p.status), Some(ProposalStatus::Cancelled));
+		assert!(Committee::queued_proposals().is_empty());
+		assert_noop!(
+			Committee::cancel_queued_proposal(Origin::root(), proposal.hash()),
+			pallet::Error::<Test>::ProposalNotQueued
+		);
+
+		run_to_block(START_OF_V1 + 101);
+		assert!(!<pallet::Members<Test>>::contains_key(CONSTITUENT));
+	});
+}
+
+//
+// Emergency proposals
+//
+
+#[test]
+fn only_emergency_calls_can_be_fast_tracked() {
+	new_test_ext(0..4).execute_with(|| {
+		assert_noop!(
+			Committee::propose_emergency(
+				Origin::signed(PROPOSER_ACCOUNT_ID),
+				Box::new(make_action(123)),
+				Default::default()
+			),
+			pallet::Error::<Test>::NotEmergencyCall
+		);
+	});
+}
+
+#[test]
+fn emergency_proposals_execute_once_accepted() {
+	new_test_ext(0..4).execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Committee::add_constituent(Origin::root(), CONSTITUENT));
+
+		let action = Call::Committee(crate::Call::remove_member { member: CONSTITUENT });
+		assert_ok!(Committee::propose_emergency(
+			Origin::signed(PROPOSER_ACCOUNT_ID),
+			Box::new(action.clone()),
+			Default::default()
+		));
+		let proposal = pallet::Proposal::<Test>::new(action, PROPOSER_ACCOUNT_ID, 0, ProposalStatus::Active);
+		assert_eq!(
+			last_event(),
+			Event::Committee(crate::Event::EmergencyProposed(proposal.hash(), 1 + EMERGENCY_VOTING_PERIOD))
+		);
+
+		// voting opens immediately, a simple majority is not enough to close early
+		vote_with_each(0..3, proposal.hash(), VoteKind::Aye);
+		vote_with_each(vec![3, PROPOSER_ACCOUNT_ID], proposal.hash(), VoteKind::Nay);
+		assert_noop!(
+			Committee::close(Origin::signed(EXECUTER_ACCOUNT_ID), proposal.hash()),
+			pallet::Error::<Test>::ProposalNotAcceptedInsufficientVotes
+		);
+
+		// two thirds of the whole council vote aye and the proposal can be closed right away
+		vote_with_each(vec![PROPOSER_ACCOUNT_ID], proposal.hash(), VoteKind::Aye);
+		assert_ok!(Committee::close(Origin::signed(EXECUTER_ACCOUNT_ID), proposal.hash()));
+		assert!(!<pallet::Members<Test>>::contains_key(CONSTITUENT));
+		assert_noop!(
+			Committee::vote(Origin::signed(ASHLEY), proposal.hash(), VoteKind::Nay),
+			pallet::Error::<Test>::ProposalAlreadyExecuted
+		);
+	});
+}
+
+#[test]
+fn emergency_proposals_need_two_thirds_of_the_council_to_close_early() {
+	new_test_ext(0..4).execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Committee::add_constituent(Origin::root(), CONSTITUENT));
+
+		let action = Call::Committee(crate::Call::remove_member { member: CONSTITUENT });
+		assert_ok!(Committee::propose_emergency(
+			Origin::signed(PROPOSER_ACCOUNT_ID),
+			Box::new(action.clone()),
+			Default::default()
+		));
+		let proposal = pallet::Proposal::<Test>::new(action, PROPOSER_ACCOUNT_ID, 0, ProposalStatus::Active);
+
+		// every vote cast is an aye, but only three of the five council members voted
+		vote_with_each(0..3, proposal.hash(), VoteKind::Aye);
+		assert_noop!(
+			Committee::close(Origin::signed(EXECUTER_ACCOUNT_ID), proposal.hash()),
+			pallet::Error::<Test>::ProposalNotAcceptedInsufficientVotes
+		);
+
+		// after the voting period the `EmergencyThreshold` applies to the votes cast
+		run_to_block(2 + EMERGENCY_VOTING_PERIOD);
+		assert_ok!(Committee::close(Origin::signed(EXECUTER_ACCOUNT_ID), proposal.hash()));
+		assert!(!<pallet::Members<Test>>::contains_key(CONSTITUENT));
+	});
+}
+
+#[test]
+fn emergency_proposals_snapshot_the_vote_weighting() {
+	new_test_ext(0..4).execute_with(|| {
+		add_constituents(4..5);
+		set_index_token_balances(vec![(4, 100)]);
+		run_to_block(START_OF_V1);
+		assert_ok!(Committee::set_vote_weighting(Origin::root(), VoteWeighting::IndexTokenBalance));
+
+		let action = Call::Committee(crate::Call::remove_member { member: CONSTITUENT });
+		assert_ok!(Committee::propose_emergency(
+			Origin::signed(PROPOSER_ACCOUNT_ID),
+			Box::new(action.clone()),
+			Default::default()
+		));
+		let proposal = pallet::Proposal::<Test>::new(action, PROPOSER_ACCOUNT_ID, 0, ProposalStatus::Active);
+
+		// the weighting applies to the emergency proposal right away
+		assert_ok!(Committee::set_vote_weighting(Origin::root(), VoteWeighting::Equal));
+		vote_with_each(4..5, proposal.hash(), VoteKind::Aye);
+		let tally = Committee::proposal_info(proposal.hash()).unwrap().tally;
+		assert_eq!(tally.constituent_weights, VoteCount { ayes: 100, nays: 0, abstentions: 0 });
+	});
+}
+
+#[test]
+fn emergency_proposals_cannot_be_voted_after_their_voting_period() {
+	new_test_ext(0..4).execute_with(|| {
+		System::set_block_number(1);
+		let action = Call::Committee(crate::Call::remove_member { member: ASHLEY });
+		assert_ok!(Committee::propose_emergency(
+			Origin::signed(PROPOSER_ACCOUNT_ID),
+			Box::new(action.clone()),
+			Default::default()
+		));
+		let proposal = pallet::Proposal::<Test>::new(action, PROPOSER_ACCOUNT_ID, 0, ProposalStatus::Active);
+
+		run_to_block(1 + EMERGENCY_VOTING_PERIOD);
+		assert_noop!(
+			Committee::vote(Origin::signed(ASHLEY), proposal.hash(), VoteKind::Aye),
+			pallet::Error::<Test>::NotInVotingPeriod
+		);
+	});
+}
+
+//
+// Batch proposals
+//
+
+fn submit_batch(actions: Vec<Call>) -> pallet::Proposal<Test> {
+	let expected_nonce = pallet::ProposalCount::<Test>::get();
+	assert_ok!(Committee::propose_batch(Origin::signed(PROPOSER_ACCOUNT_ID), actions.clone(), Default::default()));
+	pallet::Proposal::<Test>::batch(actions, PROPOSER_ACCOUNT_ID, expected_nonce, ProposalStatus::Active)
+}
+
+fn add_constituent(constituent: AccountId) -> Call {
+	Call::Committee(crate::Call::add_constituent { constituent })
+}
+
+#[test]
+fn cannot_propose_empty_or_oversized_batches() {
+	new_test_ext(0..4).execute_with(|| {
+		assert_noop!(
+			Committee::propose_batch(Origin::signed(PROPOSER_ACCOUNT_ID), vec![], Default::default()),
+			pallet::Error::<Test>::EmptyBatch
+		);
+		assert_noop!(
+			Committee::propose_batch(
+				Origin::signed(PROPOSER_ACCOUNT_ID),
+				(0..=MAX_BATCH_SIZE as u64).map(|i| add_constituent(CONSTITUENT + i)).collect(),
+				Default::default()
+			),
+			pallet::Error::<Test>::BatchTooLarge
+		);
+	});
+}
+
+#[test]
+fn accepted_batch_dispatches_all_actions() {
+	new_test_ext(0..4).execute_with(|| {
+		let proposal = submit_batch(vec![add_constituent(CONSTITUENT), add_constituent(CONSTITUENT + 1)]);
+
+		run_to_block(START_OF_S1);
+		vote_with_each(0..4, proposal.hash(), VoteKind::Aye);
+
+		run_to_block(START_OF_V1 + 1);
+		assert_ok!(Committee::close(Origin::signed(EXECUTER_ACCOUNT_ID), proposal.hash()));
+		assert_eq!(
+			last_event(),
+			Event::Committee(crate::Event::ClosedAndExecutedProposal(proposal.hash(), vec![Ok(()), Ok(())]))
+		);
+		assert!(<pallet::Members<Test>>::contains_key(CONSTITUENT));
+		assert!(<pallet::Members<Test>>::contains_key(CONSTITUENT + 1));
+	});
+}
+
+#[test]
+fn failing_batch_reverts_all_actions() {
+	new_test_ext(0..4).execute_with(|| {
+		let proposal = submit_batch(vec![add_constituent(CONSTITUENT), add_constituent(CONSTITUENT)]);
+
+		run_to_block(START_OF_S1);
+		vote_with_each(0..4, proposal.hash(), VoteKind::Aye);
+
+		run_to_block(START_OF_V1 + 1);
+		assert_ok!(Committee::close(Origin::signed(EXECUTER_ACCOUNT_ID), proposal.hash()));
+		assert_eq!(
+			last_event(),
+			Event::Committee(crate::Event::ClosedAndExecutedProposal(
+				proposal.hash(),
+				vec![Ok(()), Err(pallet::Error::<Test>::AlreadyConstituentMember.into())]
+			))
+		);
+		assert!(!<pallet::Members<Test>>::contains_key(CONSTITUENT));
+		assert_eq!(Committee::get_proposal(&proposal.hash()).map(|p| p.status), Some(ProposalStatus::Executed));
+	});
+}
+
+//
+// Delegating votes
+//
+
+#[test]
+fn constituents_can_only_delegate_to_council_members() {
+	new_test_ext(ASHLEY_RANGE).execute_with(|| {
+		add_constituents(4..7);
+
+		assert_noop!(Committee::delegate(Origin::signed(4), ASHLEY), pallet::Error::<Test>::NotEligibileToVoteYet);
+		run_to_block(VotingEligibility::<Test>::get(4).unwrap());
+
+		assert_noop!(
+			Committee::delegate(Origin::signed(ASHLEY), PROPOSER_ACCOUNT_ID),
+			pallet::Error::<Test>::NotConstituentMember
+		);
+		assert_noop!(Committee::delegate(Origin::signed(4), CONSTITUENT), pallet::Error::<Test>::NotCouncilMember);
+		assert_noop!(Committee::delegate(Origin::signed(4), 4), pallet::Error::<Test>::NotCouncilMember);
+		assert_noop!(Committee::delegate(Origin::signed(4), 5), pallet::Error::<Test>::NotCouncilMember);
+
+		assert_ok!(Committee::delegate(Origin::signed(5), ASHLEY));
+		assert_ok!(Committee::delegate(Origin::signed(6), ASHLEY));
+		assert_eq!(last_event(), Event::Committee(crate::Event::Delegated(6, ASHLEY)));
+
+		assert_ok!(Committee::undelegate(Origin::signed(5)));
+		assert_eq!(pallet::Delegations::<Test>::get(5), None);
+		assert_noop!(Committee::undelegate(Origin::signed(5)), pallet::Error::<Test>::NotDelegating);
+	});
+}
+
+#[test]
+fn delegated_votes_follow_the_delegate() {
+	new_test_ext(0..4).execute_with(|| {
+		add_constituents(4..8);
+		run_to_block(START_OF_V1);
+
+		let proposal = submit_proposal(123);
+		run_to_block(START_OF_V1 + START_OF_S1 - 1);
+
+		assert_ok!(Committee::delegate(Origin::signed(6), 0));
+		assert_ok!(Committee::delegate(Origin::signed(7), 1));
+
+		vote_with_each(0..4, proposal.hash(), VoteKind::Aye);
+		// without the delegated votes the constituents would veto the proposal
+		vote_with_each(4..6, proposal.hash(), VoteKind::Nay);
+
+		// delegations are snapshot at the end of the voting period
+		run_to_block(START_OF_V1 + START_OF_S1 + 1);
+		assert_ok!(Committee::undelegate(Origin::signed(6)));
+		assert_ok!(Committee::undelegate(Origin::signed(7)));
+
+		assert_ok!(Committee::close(Origin::signed(EXECUTER_ACCOUNT_ID), proposal.hash()));
+	});
+}
+
+#[test]
+fn delegations_are_bounded() {
+	new_test_ext(ASHLEY_RANGE).execute_with(|| {
+		add_constituents(4..10);
+		run_to_block(VotingEligibility::<Test>::get(9).unwrap());
+
+		for delegator in 4..4 + MAX_DELEGATIONS as AccountId {
+			assert_ok!(Committee::delegate(Origin::signed(delegator), ASHLEY));
+		}
+		assert_eq!(pallet::DelegationCount::<Test>::get(), MAX_DELEGATIONS);
+
+		assert_noop!(Committee::delegate(Origin::signed(9), ASHLEY), pallet::Error::<Test>::TooManyDelegations);
+		// changing an existing delegation does not count towards the limit
+		assert_ok!(Committee::delegate(Origin::signed(8), ASHLEY));
+
+		assert_ok!(Committee::undelegate(Origin::signed(8)));
+		assert_ok!(Committee::delegate(Origin::signed(9), ASHLEY));
+		assert_eq!(pallet::DelegationCount::<Test>::get(), MAX_DELEGATIONS);
+	});
+}
+
+#[test]
+fn delegations_are_snapshot_per_voting_period() {
+	new_test_ext(0..4).execute_with(|| {
+		add_constituents(4..6);
+		run_to_block(START_OF_V1);
+		assert_ok!(Committee::delegate(Origin::signed(4), 0));
+
+		let first_end = START_OF_V1 + START_OF_S1;
+		run_to_block(first_end + 1);
+		assert_ok!(Committee::delegate(Origin::signed(5), 0));
+
+		let second_end = first_end + START_OF_S1;
+		run_to_block(second_end + 1);
+
+		// later delegations do not change the snapshot of an earlier period
+		assert_eq!(Committee::delegations_at(first_end), vec![(4, 0)]);
+		assert_eq!(Committee::delegations_at(second_end).len(), 2);
+
+		// only the snapshots of the last two voting periods are kept
+		run_to_block(second_end + START_OF_S1 + 1);
+		assert_eq!(Committee::delegations_at(first_end).len(), 2);
+		assert!(pallet::DelegationSnapshots::<Test>::get(first_end).is_none());
+	});
+}
+
+//
+// Withdrawing and pruning proposals
+//
+
+#[test]
+fn proposer_can_withdraw_proposal_before_voting() {
+	new_test_ext(ASHLEY_RANGE).execute_with(|| {
+		let proposal = submit_proposal(123);
+		assert_eq!(Balances::reserved_balance(PROPOSER_ACCOUNT_ID), PROPOSAL_DEPOSIT);
+
+		assert_noop!(
+			Committee::withdraw_proposal(Origin::signed(ASHLEY), proposal.hash()),
+			pallet::Error::<Test>::NotProposalIssuer
+		);
+		assert_ok!(Committee::withdraw_proposal(Origin::signed(PROPOSER_ACCOUNT_ID), proposal.hash()));
+		assert_eq!(last_event(), Event::Committee(crate::Event::ProposalWithdrawn(proposal.hash())));
+		assert_eq!(Committee::get_proposal(&proposal.hash()).map(|p| p.status), Some(ProposalStatus::Cancelled));
+		assert!(!Committee::active_proposals().contains(&proposal.hash()));
+		assert_noop!(
+			Committee::withdraw_proposal(Origin::signed(PROPOSER_ACCOUNT_ID), proposal.hash()),
+			pallet::Error::<Test>::ProposalCancelled
+		);
+
+		// withdrawn proposals cannot be voted on
+		run_to_block(START_OF_S1);
+		assert_noop!(
+			Committee::vote(Origin::signed(ASHLEY), proposal.hash(), VoteKind::Aye),
+			pallet::Error::<Test>::ProposalCancelled
+		);
+	});
+}
+
+#[test]
+fn cannot_withdraw_proposal_after_voting_started() {
+	new_test_ext(EMPTY_RANGE).execute_with(|| {
+		let proposal = submit_proposal(123);
+
+		run_to_block(START_OF_S1);
+		assert_noop!(
+			Committee::withdraw_proposal(Origin::signed(PROPOSER_ACCOUNT_ID), proposal.hash()),
+			pallet::Error::<Test>::ProposalVotingStarted
+		);
+	});
+}
+
+#[test]
+fn finished_proposals_are_pruned_after_retention_period() {
+	new_test_ext(0..4).execute_with(|| {
+		let proposal = submit_proposal(123);
+		let withdrawn = submit_proposal(456);
+		assert_ok!(Committee::withdraw_proposal(Origin::signed(PROPOSER_ACCOUNT_ID), withdrawn.hash()));
+		assert_eq!(Balances::reserved_balance(PROPOSER_ACCOUNT_ID), 2 * PROPOSAL_DEPOSIT);
+
+		run_to_block(START_OF_S1);
+		vote_with_each(0..4, proposal.hash(), VoteKind::Aye);
+		run_to_block(START_OF_V1 + 1);
+		assert_ok!(Committee::close(Origin::signed(EXECUTER_ACCOUNT_ID), proposal.hash()));
+
+		// both voting periods ended at `START_OF_V1`, pruning happens with the
+		// first upkeep after the retention period
+		let pruned_at = START_OF_V1 + 2 * (VOTING_PERIOD + PROPOSAL_SUBMISSION_PERIOD);
+
+		run_to_block(pruned_at - 1);
+		assert!(Committee::get_proposal(&proposal.hash()).is_some());
+		assert!(Committee::get_proposal(&withdrawn.hash()).is_some());
+		assert_eq!(Balances::reserved_balance(PROPOSER_ACCOUNT_ID), 2 * PROPOSAL_DEPOSIT);
+
+		run_to_block(pruned_at);
+		for hash in [proposal.hash(), withdrawn.hash()].iter() {
+			assert_eq!(Committee::get_proposal(hash), None);
+			assert_eq!(Committee::get_votes_for(hash), None);
+		}
+		assert_eq!(pallet::InactiveProposals::<Test>::iter().count(), 0);
+		assert_eq!(Balances::reserved_balance(PROPOSER_ACCOUNT_ID), 0);
+		assert_eq!(Balances::free_balance(PROPOSER_ACCOUNT_ID), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn pruning_is_bounded_per_voting_cycle() {
+	new_test_ext(0..4).execute_with(|| {
+		let proposals: Vec<_> = (0..MAX_PRUNED_PROPOSALS as u64 + 1).map(submit_proposal).collect();
+		for proposal in proposals.iter() {
+			assert_ok!(Committee::withdraw_proposal(Origin::signed(PROPOSER_ACCOUNT_ID), proposal.hash()));
+		}
+		let remaining = || proposals.iter().filter(|p| Committee::get_proposal(&p.hash()).is_some()).count();
+
+		// only `MaxPrunedProposals` are pruned with the first upkeep after the retention period
+		let pruned_at = START_OF_V1 + 2 * (VOTING_PERIOD + PROPOSAL_SUBMISSION_PERIOD);
+		run_to_block(pruned_at);
+		assert_eq!(remaining(), 1);
+
+		run_to_block(pruned_at + VOTING_PERIOD + PROPOSAL_SUBMISSION_PERIOD);
+		assert_eq!(remaining(), 0);
+		assert_eq!(pallet::InactiveProposals::<Test>::iter().count(), 0);
+	});
+}
+
+//
+// Council elections
+//
+
+// Upkeep runs at the end of every voting period, the council term concludes
+// with the last upkeep of the term. New constituents are not eligible to vote
+// before the end of the first term, so the tests use the second one.
+const END_OF_SECOND_TERM: <Test as system::Config>::BlockNumber =
+	VOTING_PERIOD + (2 * COUNCIL_TERM_LENGTH as u64 - 1) * (VOTING_PERIOD + PROPOSAL_SUBMISSION_PERIOD);
+
+/// Adds the given constituents and runs to the block they are eligible to vote
+fn add_eligible_constituents<I>(accounts: I)
+where
+	I: IntoIterator<Item = AccountId>,
+{
+	let accounts: Vec<_> = accounts.into_iter().collect();
+	add_constituents(accounts.clone());
+	let eligible_from =
+		accounts.iter().filter_map(|account| VotingEligibility::<Test>::get(account)).max().unwrap_or_default();
+	run_to_block(eligible_from);
+}
+
+#[test]
+fn members_can_nominate_themselves() {
+	new_test_ext(ASHLEY_RANGE).execute_with(|| {
+		assert_noop!(Committee::nominate(Origin::signed(CONSTITUENT)), pallet::Error::<Test>::NotMember);
+
+		assert_ok!(Committee::add_constituent(Origin::root(), CONSTITUENT));
+		assert_ok!(Committee::nominate(Origin::signed(CONSTITUENT)));
+		assert_ok!(Committee::nominate(Origin::signed(ASHLEY)));
+		assert_eq!(last_event(), Event::Committee(crate::Event::CouncilCandidateNominated(ASHLEY)));
+
+		assert_noop!(Committee::nominate(Origin::signed(ASHLEY)), pallet::Error::<Test>::AlreadyCouncilCandidate);
+		assert_eq!(pallet::CouncilCandidates::<Test>::get(CONSTITUENT), Some(0));
+		assert_eq!(pallet::CouncilCandidates::<Test>::get(ASHLEY), Some(0));
+	});
+}
+
+#[test]
+fn only_eligible_constituents_can_vote_for_candidates() {
+	new_test_ext(ASHLEY_RANGE).execute_with(|| {
+		assert_ok!(Committee::nominate(Origin::signed(ASHLEY)));
+		assert_ok!(Committee::add_constituent(Origin::root(), CONSTITUENT));
+
+		assert_noop!(
+			Committee::vote_candidate(Origin::signed(CONSTITUENT), ASHLEY),
+			pallet::Error::<Test>::NotEligibileToVoteYet
+		);
+		run_to_block(VotingEligibility::<Test>::get(CONSTITUENT).unwrap());
+
+		assert_noop!(
+			Committee::vote_candidate(Origin::signed(PROPOSER_ACCOUNT_ID), ASHLEY),
+			pallet::Error::<Test>::NotConstituentMember
+		);
+		assert_noop!(
+			Committee::vote_candidate(Origin::signed(CONSTITUENT), PROPOSER_ACCOUNT_ID),
+			pallet::Error::<Test>::NotCouncilCandidate
+		);
+
+		assert_ok!(Committee::vote_candidate(Origin::signed(CONSTITUENT), ASHLEY));
+		assert_eq!(last_event(), Event::Committee(crate::Event::CouncilVoteCast(CONSTITUENT, ASHLEY)));
+		assert_eq!(pallet::CouncilCandidates::<Test>::get(ASHLEY), Some(1));
+	});
+}
+
+#[test]
+fn constituents_can_move_their_vote() {
+	new_test_ext(ASHLEY_RANGE).execute_with(|| {
+		add_eligible_constituents(vec![CONSTITUENT]);
+		assert_ok!(Committee::nominate(Origin::signed(ASHLEY)));
+		assert_ok!(Committee::nominate(Origin::signed(PROPOSER_ACCOUNT_ID)));
+
+		assert_ok!(Committee::vote_candidate(Origin::signed(CONSTITUENT), ASHLEY));
+		assert_ok!(Committee::vote_candidate(Origin::signed(CONSTITUENT), PROPOSER_ACCOUNT_ID));
+		assert_eq!(pallet::CouncilCandidates::<Test>::get(ASHLEY), Some(0));
+		assert_eq!(pallet::CouncilCandidates::<Test>::get(PROPOSER_ACCOUNT_ID), Some(1));
+
+		// removing the voter retracts the vote
+		assert_ok!(Committee::remove_member(Origin::root(), CONSTITUENT));
+		assert_eq!(pallet::CouncilCandidates::<Test>::get(PROPOSER_ACCOUNT_ID), Some(0));
+		assert_eq!(pallet::CouncilVotes::<Test>::get(CONSTITUENT), None);
+	});
+}
+
+#[test]
+fn council_is_rotated_at_the_end_of_the_term() {
+	new_test_ext(0..4).execute_with(|| {
+		add_eligible_constituents(10..18);
+
+		// constituents 10..14 and the proposer stand for election
+		for candidate in (10..14).chain(std::iter::once(PROPOSER_ACCOUNT_ID)) {
+			assert_ok!(Committee::nominate(Origin::signed(candidate)));
+		}
+		// candidate 13 receives no votes and candidate 14 does not stand
+		for (voter, candidate) in vec![(14, 10), (15, 11), (16, 12), (17, 10), (10, PROPOSER_ACCOUNT_ID), (11, 12)] {
+			assert_ok!(Committee::vote_candidate(Origin::signed(voter), candidate));
+		}
+
+		run_to_block(END_OF_SECOND_TERM - 1);
+		assert_eq!(pallet::Members::<Test>::get(10), Some(MemberType::Constituent));
+
+		run_to_block(END_OF_SECOND_TERM);
+		assert_eq!(last_event(), Event::Committee(crate::Event::NewCouncil(vec![10, 12, 11, PROPOSER_ACCOUNT_ID])));
+
+		let mut council = Committee::council_members();
+		council.sort();
+		assert_eq!(council, vec![10, 11, 12, PROPOSER_ACCOUNT_ID]);
+		for outgoing in 0..4 {
+			assert_eq!(pallet::Members::<Test>::get(outgoing), Some(MemberType::Constituent));
+		}
+		assert_eq!(pallet::Members::<Test>::get(13), Some(MemberType::Constituent));
+
+		// newly seated members have to wait before they can vote, re-elected members do not
+		let eligible_from = END_OF_SECOND_TERM + Committee::get_next_voting_period_end(&END_OF_SECOND_TERM).unwrap();
+		assert_eq!(VotingEligibility::<Test>::get(10), Some(eligible_from));
+		assert_eq!(VotingEligibility::<Test>::get(PROPOSER_ACCOUNT_ID), Some(0));
+
+		// the election resets candidates and votes
+		assert_eq!(pallet::CouncilCandidates::<Test>::iter().count(), 0);
+		assert_eq!(pallet::CouncilCandidateCount::<Test>::get(), 0);
+		assert_eq!(pallet::CouncilElection::<Test>::get(), 1);
+		assert_eq!(pallet::CouncilTermEpochs::<Test>::get(), 0);
+
+		// votes of the previous election are not retracted from new candidates
+		assert_ok!(Committee::nominate(Origin::signed(12)));
+		assert_ok!(Committee::nominate(Origin::signed(13)));
+		assert_ok!(Committee::vote_candidate(Origin::signed(15), 12));
+		assert_ok!(Committee::vote_candidate(Origin::signed(16), 13));
+		assert_eq!(pallet::CouncilCandidates::<Test>::get(12), Some(1));
+		assert_eq!(pallet::CouncilCandidates::<Test>::get(13), Some(1));
+	});
+}
+
+#[test]
+fn council_candidates_are_bounded() {
+	new_test_ext(0..4).execute_with(|| {
+		let candidates = 10..10 + MAX_COUNCIL_CANDIDATES as AccountId;
+		add_constituents(candidates.clone());
+		for candidate in candidates {
+			assert_ok!(Committee::nominate(Origin::signed(candidate)));
+		}
+		assert_noop!(Committee::nominate(Origin::signed(0)), pallet::Error::<Test>::TooManyCouncilCandidates);
+
+		// removing a candidate frees a slot
+		assert_ok!(Committee::remove_member(Origin::root(), 10));
+		assert_ok!(Committee::nominate(Origin::signed(0)));
+	});
+}
+
+#[test]
+fn council_stays_in_office_if_too_few_candidates_are_elected() {
+	new_test_ext(0..4).execute_with(|| {
+		add_eligible_constituents(10..12);
+		assert_ok!(Committee::nominate(Origin::signed(10)));
+		assert_ok!(Committee::vote_candidate(Origin::signed(11), 10));
+
+		run_to_block(END_OF_SECOND_TERM);
+		assert_eq!(last_event(), Event::Committee(crate::Event::CouncilElectionFailed(1)));
+
+		let mut council = Committee::council_members();
+		council.sort();
+		assert_eq!(council, vec![0, 1, 2, 3, PROPOSER_ACCOUNT_ID]);
+		assert_eq!(pallet::Members::<Test>::get(10), Some(MemberType::Constituent));
+	});
+}
+
+//
+// Runtime API
+//
+
+#[test]
+fn voting_window_follows_epochs() {
+	new_test_ext(0..4).execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(
+			Committee::voting_window(),
+			VotingWindow {
+				voting_start: 0,
+				voting_end: VOTING_PERIOD,
+				submission_start: VOTING_PERIOD,
+				submission_end: START_OF_S1
+			}
+		);
+
+		System::set_block_number(START_OF_V1);
+		assert_ok!(
+			Committee::voting_window(),
+			VotingWindow {
+				voting_start: START_OF_S1,
+				voting_end: START_OF_V1,
+				submission_start: START_OF_V1,
+				submission_end: START_OF_S1 + START_OF_S1
+			}
+		);
+	});
+}
+
+#[test]
+fn proposal_info_contains_live_tally() {
+	new_test_ext(0..4).execute_with(|| {
+		let proposal = submit_proposal(123);
+
+		run_to_block(START_OF_S1);
+		vote_with_each(0..3, proposal.hash(), VoteKind::Aye);
+		vote_with_each(3..4, proposal.hash(), VoteKind::Nay);
+
+		let info = Committee::proposal_info(proposal.hash()).expect("proposal exists");
+		assert_eq!(info.issuer, PROPOSER_ACCOUNT_ID);
+		assert_eq!(info.status, ProposalStatus::Active);
+		assert_eq!(info.end, START_OF_V1);
+		assert_eq!(info.tally.council, VoteCount { ayes: 3, nays: 1, abstentions: 0 });
+		assert_eq!(info.tally.constituents, VoteCount::default());
+		assert!(info.accepted);
+		assert_eq!(info.actions.len(), 1);
+		assert_eq!(info.actions[0].pallet_name, b"System".to_vec());
+		assert_eq!(info.actions[0].function_name, b"remark".to_vec());
+		assert_eq!(info.actions[0].encoded, make_action(123).encode());
+
+		assert_eq!(Committee::active_proposal_infos(), vec![info]);
+	});
+}
+
+//
+// Storage migrations
+//
+
+#[test]
+fn single_action_proposals_are_migrated_to_batches() {
+	new_test_ext(0..4).execute_with(|| {
+		StorageVersion::new(0).put::<Committee>();
+
+		// a queued proposal stored before proposals held a batch of actions
+		let action = make_action(123);
+		let hash = <Test as system::Config>::Hash::repeat_byte(1);
+		let legacy = (action.clone(), PROPOSER_ACCOUNT_ID, 0u32, ProposalStatus::Queued);
+		unhashed::put(&Proposals::<Test>::hashed_key_for(hash), &legacy);
+		assert_eq!(Committee::get_proposal(&hash), None);
+
+		Committee::on_runtime_upgrade();
+
+		let migrated = pallet::Proposal::<Test>::new(action, PROPOSER_ACCOUNT_ID, 0, ProposalStatus::Queued);
+		assert_eq!(Committee::get_proposal(&hash), Some(migrated));
+		assert_eq!(Committee::on_chain_storage_version(), 2);
+	});
+}
+
+#[test]
+fn council_is_seeded_from_the_members_by_the_migration() {
+	new_test_ext(0..4).execute_with(|| {
+		assert_ok!(Committee::add_constituent(Origin::root(), CONSTITUENT));
+
+		// the council was only tracked in `Members` before
+		StorageVersion::new(0).put::<Committee>();
+		pallet::Council::<Test>::kill();
+		assert!(Committee::council_members().is_empty());
+
+		Committee::on_runtime_upgrade();
+
+		let mut council = Committee::council_members();
+		council.sort_unstable();
+		assert_eq!(council, vec![0, 1, 2, 3, PROPOSER_ACCOUNT_ID]);
+		assert_eq!(Committee::on_chain_storage_version(), 2);
+
+		// council members can be removed until the minimum is reached
+		assert_ok!(Committee::remove_member(Origin::root(), 3));
+		assert_eq!(Committee::council_members().len(), 4);
+	});
+}
+
+#[test]
+fn votes_are_migrated_to_weighted_votes() {
+	new_test_ext(0..4).execute_with(|| {
+		add_constituents(4..6);
+		pallet::ConstituentCount::<Test>::kill();
+		StorageVersion::new(1).put::<Committee>();
+
+		// votes stored before constituent votes were weighted
+		let hash = <Test as system::Config>::Hash::repeat_byte(1);
+		let constituent = CommitteeMember::new(CONSTITUENT, MemberType::Constituent);
+		let legacy = (vec![(ASHLEY_COUNCIL, VoteKind::Aye), (constituent.clone(), VoteKind::Nay)], START_OF_V1);
+		unhashed::put(&pallet::Votes::<Test>::hashed_key_for(hash), &legacy);
+		assert_eq!(Committee::get_votes_for(&hash), None);
+
+		Committee::on_runtime_upgrade();
+
+		let votes = Committee::get_votes_for(&hash).unwrap();
+		assert_eq!(votes, VoteAggregate::new(vec![ASHLEY_COUNCIL], vec![constituent], vec![], START_OF_V1));
+		assert_eq!(votes.weighted_tally(&MemberType::Constituent), (0, 1, 0));
+		assert_eq!(pallet::ConstituentCount::<Test>::get(), 2);
+		assert_eq!(Committee::on_chain_storage_version(), 2);
+	});
+}
@@ -9,3 +9,23 @@ pub trait VotingPeriodRange<BlockNumber> {
 	/// The maximum value of the voting period range
 	fn max() -> BlockNumber;
 }
+
+/// Provides the index token holdings of accounts, used to weigh the votes of
+/// constituents
+pub trait IndexTokenHoldings<AccountId> {
+	/// The index token balance of the account
+	fn balance(who: &AccountId) -> u128;
+
+	/// The amount of index tokens of the account that are currently locked
+	fn locked(who: &AccountId) -> u128;
+}
+
+impl<AccountId> IndexTokenHoldings<AccountId> for () {
+	fn balance(_: &AccountId) -> u128 {
+		0
+	}
+
+	fn locked(_: &AccountId) -> u128 {
+		0
+	}
+}
@@ -1,21 +1,18 @@
 // Copyright 2021 ChainSafe Systems
 // SPDX-License-Identifier: LGPL-3.0-only
 
-use crate::{Config, Members, Origin};
+use crate::{Config, Members, Origin, Pallet};
 use frame_support::{
 	pallet_prelude::*,
-	sp_runtime::traits::Hash,
-	sp_std::{self, prelude::Vec},
+	sp_runtime::{traits::Hash, Percent},
+	sp_std::{self, collections::btree_map::BTreeMap, prelude::Vec},
 	traits::EnsureOrigin,
 };
 use frame_system::RawOrigin;
 
-#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
-pub enum ProposalStatus {
-	Active,
-	Executed,
-	Timeout,
-}
+pub use primitives::types::{
+	CallIndex, ProposalInfo, ProposalMetadata, ProposalStatus, ProposedAction, VoteCount, VoteTally, VotingWindow,
+};
 
 /// This represents an instance of a proposal that can be voted on.
 /// It has been proposed and has an assigned nonce.
@@ -25,7 +22,8 @@ pub enum ProposalStatus {
 #[codec(mel_bound(T: Config))]
 #[scale_info(skip_type_params(T))]
 pub struct Proposal<T: Config> {
-	pub action: T::Action,
+	/// The actions of this proposal, dispatched all-or-nothing in order
+	pub actions: Vec<T::Action>,
 	pub issuer: T::AccountId,
 	pub nonce: T::ProposalNonce,
 	pub status: ProposalStatus,
@@ -33,7 +31,16 @@ pub struct Proposal<T: Config> {
 
 impl<T: Config> Proposal<T> {
 	pub fn new(action: T::Action, issuer: T::AccountId, nonce: T::ProposalNonce, status: ProposalStatus) -> Self {
-		Self { action, nonce, status, issuer }
+		Self::batch(sp_std::vec![action], issuer, nonce, status)
+	}
+
+	pub fn batch(
+		actions: Vec<T::Action>,
+		issuer: T::AccountId,
+		nonce: T::ProposalNonce,
+		status: ProposalStatus,
+	) -> Self {
+		Self { actions, nonce, status, issuer }
 	}
 
 	pub fn hash(&self) -> <T as frame_system::Config>::Hash {
@@ -64,7 +71,7 @@ impl<AccountId> CommitteeMember<AccountId> {
 	}
 
 	pub fn into_vote(self, vote: VoteKind) -> MemberVote<AccountId> {
-		MemberVote { member: self, vote }
+		MemberVote::new(self, vote)
 	}
 }
 
@@ -73,20 +80,27 @@ impl<AccountId> CommitteeMember<AccountId> {
 pub struct MemberVote<AccountId> {
 	pub member: CommitteeMember<AccountId>,
 	pub vote: VoteKind,
+	/// The weight of the vote in the constituent veto, ignored for council
+	/// votes
+	pub weight: u128,
 }
 
 impl<AccountId> MemberVote<AccountId> {
 	pub fn new(member: CommitteeMember<AccountId>, vote: VoteKind) -> Self {
-		Self { member, vote }
+		Self { member, vote, weight: 1 }
+	}
+
+	pub fn with_weight(self, weight: u128) -> Self {
+		Self { weight, ..self }
 	}
 }
 
 /// Origin for the committee pallet.
 #[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, scale_info::TypeInfo)]
 pub enum CommitteeOrigin<AccountId, BlockNumber> {
-	/// Action is executed by the committee. Contains the closer account and the
-	/// members that voted Aye
-	ApprovedByCommittee(AccountId, VoteAggregate<AccountId, BlockNumber>),
+	/// Action is executed by the committee. Contains the closer account, the
+	/// votes on the proposal and the call index of the proposed action
+	ApprovedByCommittee(AccountId, VoteAggregate<AccountId, BlockNumber>, CallIndex),
 	/// It has been condoned by a single member of the committee.
 	CommitteeMember(AccountId),
 }
@@ -140,6 +154,34 @@ impl<AccountId: Default + PartialEq, BlockNumber: Default> VoteAggregate<Account
 		self.votes.iter().any(|x| &x.member.account_id == voter)
 	}
 
+	/// Returns the vote cast by the given voter, if any
+	pub fn vote_of(&self, voter: &AccountId) -> Option<&VoteKind> {
+		self.votes.iter().find(|x| &x.member.account_id == voter).map(|x| &x.vote)
+	}
+
+	/// Adds a vote for every delegator that did not vote themselves.
+	///
+	/// A delegator's vote follows the council vote of their delegate and is
+	/// counted as a constituent vote with the delegator's weight, delegates
+	/// that are no longer council members do not pass on their vote.
+	/// Delegations are given as `(delegator, delegate)` pairs.
+	pub fn apply_delegations(&mut self, delegations: &[(AccountId, AccountId)], weight_of: impl Fn(&AccountId) -> u128)
+	where
+		AccountId: Clone + Ord,
+	{
+		let votes: BTreeMap<_, _> = self.votes.iter().map(|x| (&x.member.account_id, x)).collect();
+		let delegated: Vec<_> = delegations
+			.iter()
+			.filter(|(delegator, _)| !votes.contains_key(delegator))
+			.filter_map(|(delegator, delegate)| {
+				let vote = votes.get(delegate).filter(|x| x.member.member_type == MemberType::Council)?;
+				let member = CommitteeMember::new(delegator.clone(), MemberType::Constituent);
+				Some(member.into_vote(vote.vote.clone()).with_weight(weight_of(delegator)))
+			})
+			.collect();
+		self.votes.extend(delegated);
+	}
+
 	/// produce a tuple of the vote totals: (ayes, nays, abstentions)
 	/// Can optionally filter by membership type to only tally council or
 	/// constituent votes
@@ -154,25 +196,145 @@ impl<AccountId: Default + PartialEq, BlockNumber: Default> VoteAggregate<Account
 		)
 	}
 
+	/// Produces a tuple of the summed vote weights: (ayes, nays, abstentions)
+	/// of the given membership type
+	pub fn weighted_tally(&self, member_type: &MemberType) -> (u128, u128, u128) {
+		self.votes.iter().filter(|x| &x.member.member_type == member_type).fold((0, 0, 0), |(ayes, nays, abs), x| {
+			match x.vote {
+				VoteKind::Aye => (ayes.saturating_add(x.weight), nays, abs),
+				VoteKind::Nay => (ayes, nays.saturating_add(x.weight), abs),
+				VoteKind::Abstain => (ayes, nays, abs.saturating_add(x.weight)),
+			}
+		})
+	}
+
 	/// For a vote to be accepted all of the following must be true:
 	///  - At least min_council_votes must be cast by the council
 	///  - A simple majority of council Ayes vs Nays (e.g. count(ayes) > count(nays))
-	///  - There is NOT a majority of Nay votes by the constituent members
+	///  - There is NOT a majority of Nay votes by the constituent members, weighted by the weight
+	///    of their votes
 	pub fn is_accepted(&self, min_council_votes: usize) -> Result<(), VoteRejectionReason> {
+		self.is_accepted_with(&VoteThreshold::default(), min_council_votes, || 0)
+	}
+
+	/// Checks the votes against the given threshold.
+	///
+	/// The required council participation is never lower than
+	/// `min_council_votes`. The size of the council is only requested for
+	/// percentage quorums.
+	pub fn is_accepted_with(
+		&self,
+		threshold: &VoteThreshold,
+		min_council_votes: usize,
+		council_size: impl FnOnce() -> usize,
+	) -> Result<(), VoteRejectionReason> {
 		// council votes
 		let (ayes, nays, abs) = self.tally(Some(&MemberType::Council));
 		let participants = ayes + nays + abs;
-		// constituent votes
-		let (cons_ayes, cons_nays, _) = self.tally(Some(&MemberType::Constituent));
+		// weighted constituent votes
+		let (cons_ayes, cons_nays, _) = self.weighted_tally(&MemberType::Constituent);
 
-		ensure!(participants >= min_council_votes, VoteRejectionReason::InsuffientVotes);
-		ensure!(ayes > nays, VoteRejectionReason::CouncilDeny);
-		ensure!(cons_nays <= cons_ayes, VoteRejectionReason::ConstituentVeto);
+		let quorum = match threshold.quorum {
+			Quorum::Absolute(members) => members as usize,
+			Quorum::Percent(share) => share.mul_ceil(council_size()),
+		};
+		ensure!(participants >= quorum.max(min_council_votes), VoteRejectionReason::InsuffientVotes);
+
+		let approved = match threshold.approval {
+			Approval::SimpleMajority => ayes > nays,
+			Approval::SuperMajority(share) => ayes > 0 && ayes >= share.mul_ceil(ayes + nays),
+			Approval::Unanimous => ayes > 0 && nays == 0 && abs == 0,
+		};
+		ensure!(approved, VoteRejectionReason::CouncilDeny);
+
+		if threshold.constituent_veto {
+			ensure!(cons_nays <= cons_ayes, VoteRejectionReason::ConstituentVeto);
+		}
 
 		Ok(())
 	}
 }
 
+/// Minimum participation of the council in a vote
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+pub enum Quorum {
+	/// At least this many council members must vote
+	Absolute(u32),
+	/// At least this share of the council must vote
+	Percent(Percent),
+}
+
+/// Council support that is required for a proposal to pass
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+pub enum Approval {
+	/// More ayes than nays
+	SimpleMajority,
+	/// Ayes make up at least this share of all ayes and nays, must be more
+	/// than 50%
+	SuperMajority(Percent),
+	/// Every participating council member votes aye
+	Unanimous,
+}
+
+/// The rules a proposal has to satisfy in order to be accepted
+///
+/// The default is a simple council majority with a constituent veto, which is
+/// used for every action without a dedicated threshold.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+pub struct VoteThreshold {
+	/// Minimum council participation
+	pub quorum: Quorum,
+	/// Required council support
+	pub approval: Approval,
+	/// Whether a majority of constituent nays vetoes the proposal
+	pub constituent_veto: bool,
+}
+
+impl VoteThreshold {
+	/// Whether this threshold can be satisfied
+	pub fn is_valid(&self) -> bool {
+		match self.approval {
+			Approval::SuperMajority(share) => share > Percent::from_percent(50),
+			_ => true,
+		}
+	}
+}
+
+impl Default for VoteThreshold {
+	fn default() -> Self {
+		Self { quorum: Quorum::Absolute(0), approval: Approval::SimpleMajority, constituent_veto: true }
+	}
+}
+
+/// An accepted proposal that waits for its enactment delay to pass
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+pub struct Enactment<AccountId, BlockNumber> {
+	/// The block at which the proposal is executed
+	pub at: BlockNumber,
+	/// The votes the proposal was accepted with
+	pub votes: VoteAggregate<AccountId, BlockNumber>,
+}
+
+/// Determines the weight of constituent votes
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum VoteWeighting {
+	/// Every constituent vote has the same weight
+	Equal,
+	/// Constituent votes are weighted by the index token balance of the
+	/// constituent
+	IndexTokenBalance,
+	/// Constituent votes are weighted by the locked index tokens of the
+	/// constituent
+	LockedIndexTokens,
+}
+
+impl Default for VoteWeighting {
+	fn default() -> Self {
+		VoteWeighting::Equal
+	}
+}
+
 /// Possible votes a member can cast
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
 pub enum VoteKind {
@@ -193,8 +355,8 @@ impl<O: Into<Result<Origin<T>, O>> + From<Origin<T>> + Clone, T: Config> EnsureO
 	fn try_origin(o: O) -> Result<Self::Success, O> {
 		let origin = o.clone().into()?;
 		match origin {
-			CommitteeOrigin::ApprovedByCommittee(i, votes) => {
-				votes.is_accepted(T::MinCouncilVotes::get()).map_err(|_| o)?;
+			CommitteeOrigin::ApprovedByCommittee(i, votes, call_index) => {
+				<Pallet<T>>::ensure_accepted(&votes, call_index).map_err(|_| o)?;
 				Ok(i)
 			}
 			_ => Err(o),
@@ -210,12 +372,14 @@ impl<O: Into<Result<Origin<T>, O>> + From<Origin<T>> + Clone, T: Config> EnsureO
 				votes: vec![
 					MemberVote {
 						member: CommitteeMember { account_id: Default::default(), member_type: MemberType::Council },
-						vote: VoteKind::Aye
+						vote: VoteKind::Aye,
+						weight: 1,
 					};
 					T::MinCouncilVotes::get() + 1
 				],
 				end: <frame_system::Pallet<T>>::block_number() + 1_u32.into(),
 			},
+			Default::default(),
 		))
 	}
 }
@@ -46,7 +46,10 @@ pub mod pallet {
 	#[cfg(feature = "std")]
 	use frame_support::traits::GenesisBuild;
 
-	pub use crate::{traits::PriceFeed, types::TimestampedValue};
+	pub use crate::{
+		traits::{PriceFeed, PriceFeedAge},
+		types::TimestampedValue,
+	};
 	use frame_support::{
 		pallet_prelude::*,
 		sp_runtime::{traits::CheckedDiv, FixedPointNumber, FixedPointOperand},
@@ -267,6 +270,15 @@ pub mod pallet {
 		}
 	}
 
+	impl<T: Config> PriceFeedAge<T::AssetId, T::BlockNumber> for Pallet<T> {
+		fn price_updated_at(base: T::AssetId) -> Result<T::BlockNumber, DispatchError> {
+			let feed_id = Self::asset_feed_id(&base).ok_or(Error::<T>::AssetPriceFeedNotFound)?;
+			let feed = pallet_chainlink_feed::Pallet::<T>::feed(feed_id).ok_or(Error::<T>::AssetPriceFeedNotFound)?;
+			ensure!(feed.first_valid_round().is_some(), Error::<T>::InvalidFeedValue);
+			Ok(feed.latest_data().updated_at)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl<T: Config> PriceFeedBenchmarks<T::AccountId, T::AssetId> for Pallet<T> {
 		fn create_feed(
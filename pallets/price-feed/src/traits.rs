@@ -19,6 +19,12 @@ pub trait PriceFeed<AssetId> {
 	fn get_relative_price_pair(base: AssetId, quote: AssetId) -> Result<AssetPricePair<AssetId>, DispatchError>;
 }
 
+/// Provides the freshness of the price data
+pub trait PriceFeedAge<AssetId, BlockNumber> {
+	/// Returns the block at which the price for the given asset was last updated
+	fn price_updated_at(base: AssetId) -> Result<BlockNumber, DispatchError>;
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 pub trait PriceFeedBenchmarks<AccountId, AssetId> {
 	fn create_feed(caller: AccountId, asset_id: AssetId) -> DispatchResultWithPostInfo;
@@ -5,10 +5,14 @@
 //!
 //! The Remote Asset Manager pallet provides capabilities to bond/unbond
 //! and transfer assets on other chains.
+//!
+//! Governance decides which validators PINT's stash nominates on an asset's chain, these
+//! nominations are sent via XCM as soon as the stash is bonded and resent whenever they change.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
+pub mod migrations;
 pub mod traits;
 pub mod types;
 
@@ -40,7 +44,7 @@ pub mod pallet {
 
 	use crate::{
 		traits::{BalanceMeter, StakingCap},
-		types::{AssetLedger, StatemintConfig, XcmStakingMessageCount},
+		types::{AssetLedger, NominationState, StatemintConfig, XcmStakingMessageCount},
 	};
 	use xcm_calls::staking::UnlockChunk;
 
@@ -63,6 +67,9 @@ pub mod pallet {
 		<T as frame_system::Config>::BlockNumber,
 	>;
 
+	/// The validators to nominate on the asset's chain and whether they were already sent
+	type NominationStateFor<T> = NominationState<LookupSourceFor<T>, <T as frame_system::Config>::BlockNumber>;
+
 	// A `pallet_staking` dispatchable on another chain
 	type PalletStakingCall<T> = StakingCall<LookupSourceFor<T>, BalanceFor<T>, AccountIdFor<T>>;
 
@@ -129,6 +136,16 @@ pub mod pallet {
 		#[pallet::constant]
 		type AssetUnbondingSlashingSpans: Get<u32>;
 
+		/// The maximum number of validators that can be nominated on an asset's chain.
+		///
+		/// This should not exceed the `MAX_NOMINATIONS` of the remote `pallet_staking`.
+		#[pallet::constant]
+		type MaxNominations: Get<u32>;
+
+		/// The number of blocks to wait before resending nominations that failed to send.
+		#[pallet::constant]
+		type NominationRetryDelay: Get<Self::BlockNumber>;
+
 		/// Determines the threshold amounts when operating with staked assets.
 		type AssetStakingCap: StakingCap<Self::AssetId, Self::Balance>;
 
@@ -158,8 +175,12 @@ pub mod pallet {
 		type WeightInfo: WeightInfo;
 	}
 
+	/// The current storage version
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub (super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// The config of `pallet_staking` in the runtime of the parachain.
@@ -195,6 +216,23 @@ pub mod pallet {
 	pub(super) type XcmStakingCount<T: Config> =
 		StorageMap<_, Twox64Concat, T::AssetId, XcmStakingMessageCount, ValueQuery>;
 
+	/// The validators PINT's stash nominates on the asset's chain, as set by governance.
+	///
+	/// Pending nominations are sent once the stash is bonded with PINT's account as controller.
+	#[pallet::storage]
+	#[pallet::getter(fn nominations)]
+	pub type Nominations<T: Config> = StorageMap<_, Twox64Concat, T::AssetId, NominationStateFor<T>, OptionQuery>;
+
+	/// The raw storage key of the last asset in `PalletStakingConfig` whose staking upkeep was
+	/// performed in `on_idle`, the next block continues with the asset after it.
+	#[pallet::storage]
+	pub type StakingUpkeepCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
+	/// The raw storage key of the last asset in `Nominations` that was checked for pending
+	/// nominations in `on_idle`, the next block continues with the asset after it.
+	#[pallet::storage]
+	pub type NominationsCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
 	/// The config of `pallet_proxy` in the runtime of the parachain.
 	#[pallet::storage]
 	#[pallet::getter(fn proxy_config)]
@@ -282,6 +320,15 @@ pub mod pallet {
 		/// Successfully sent a cross chain message to withdraw unbonded funds.
 		/// \[asset \]
 		SentWithdrawUnbonded(T::AssetId),
+		/// The validators to nominate were updated by governance. \[asset,
+		/// targets\]
+		UpdatedNominations(T::AssetId, Vec<LookupSourceFor<T>>),
+		/// Successfully sent a cross chain message to nominate validators.
+		/// \[asset, targets\]
+		SentNominate(T::AssetId, Vec<LookupSourceFor<T>>),
+		/// Failed to send a nominate call, it will be retried after `NominationRetryDelay`
+		/// blocks. \[error, asset\]
+		ErrorSendingNominate(XcmError, T::AssetId),
 		/// Successfully sent a cross chain message to add a proxy. \[asset,
 		/// delegate, proxy type\]
 		SentAddProxy(T::AssetId, AccountIdFor<T>, ProxyType),
@@ -339,6 +386,10 @@ pub mod pallet {
 		FailedToSendWithdrawUnbondedXcm,
 		/// Thrown when sending an Xcm `pallet_proxy::add_proxy` failed
 		FailedToSendAddProxyXcm,
+		/// Thrown when no validators were provided to nominate
+		NoNominations,
+		/// Thrown when more than `MaxNominations` validators were provided to nominate
+		TooManyNominations,
 		/// Thrown when sending an Xcm `pallet_proxy::remove_proxy` failed
 		FailedToSendRemoveProxyXcm,
 		/// PINT's stash is already bonded.
@@ -395,12 +446,44 @@ pub mod pallet {
 		///
 		/// The maximum number of separate xcm calls we send here is limited to the number of liquid
 		/// assets with staking support.
+		///
+		/// Afterwards pending nominations are resent, once `NominationRetryDelay` blocks passed
+		/// since their last failed attempt. Assets are only processed as long as the
+		/// `remaining_weight` suffices, the next block continues after the asset that was
+		/// processed last.
 		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			// reading and updating both cursors
+			let cursor_weight = T::DbWeight::get().reads_writes(1, 1);
+			let mut consumed: Weight = cursor_weight.saturating_mul(2);
+			if consumed > remaining_weight {
+				return 0;
+			}
+
 			// check all assets with enabled cross chain staking support and a valid destination
-			// TODO handle weight
-			for (asset, config, dest) in PalletStakingConfig::<T>::iter()
-				.filter_map(|(asset, config)| Self::asset_destination(asset).ok().map(|dest| (asset, config, dest)))
-			{
+			let upkeep_weight = T::WeightInfo::staking_upkeep();
+			let mut cursor = StakingUpkeepCursor::<T>::get();
+			// only the ledger and balances are mutated, which is safe while iterating the configs
+			let mut configs = match cursor.clone() {
+				Some(last) => PalletStakingConfig::<T>::iter_from(last),
+				None => PalletStakingConfig::<T>::iter(),
+			};
+			while consumed.saturating_add(upkeep_weight) <= remaining_weight {
+				let (asset, config) = match configs.next() {
+					Some(next) => next,
+					None => {
+						// all assets were visited, start over in the next block
+						cursor = None;
+						break;
+					}
+				};
+				consumed = consumed.saturating_add(upkeep_weight);
+				cursor = Some(PalletStakingConfig::<T>::hashed_key_for(&asset));
+
+				let dest = match Self::asset_destination(asset) {
+					Ok(dest) => dest,
+					Err(_) => continue,
+				};
+
 				// consider only location which are already bonded
 				if let Some(mut ledger) = PalletStakingLedger::<T>::get(&asset) {
 					// derive the appropriate action based on the current balances
@@ -464,8 +547,40 @@ pub mod pallet {
 					AssetBalance::<T>::insert(asset, balances);
 				}
 			}
+			StakingUpkeepCursor::<T>::set(cursor);
+
+			// send the nominations that are still pending, e.g. because the asset was bonded since
+			// or sending failed before
+			let read_weight = T::DbWeight::get().reads(1);
+			let nominate_weight = T::WeightInfo::send_nominate();
+			let retry_delay = T::NominationRetryDelay::get();
+			let mut pending = Vec::new();
+			let mut cursor = NominationsCursor::<T>::get();
+			let mut nominations = match cursor.clone() {
+				Some(last) => Nominations::<T>::iter_from(last),
+				None => Nominations::<T>::iter(),
+			};
+			while consumed.saturating_add(read_weight).saturating_add(nominate_weight) <= remaining_weight {
+				consumed = consumed.saturating_add(read_weight);
+				let (asset, state) = match nominations.next() {
+					Some(next) => next,
+					None => {
+						// all assets were visited, start over in the next block
+						cursor = None;
+						break;
+					}
+				};
+				cursor = Some(Nominations::<T>::hashed_key_for(&asset));
+				if state.is_due(now, retry_delay) {
+					consumed = consumed.saturating_add(nominate_weight);
+					pending.push(asset);
+				}
+			}
+			NominationsCursor::<T>::set(cursor);
+			// the nominations are updated after iterating
+			pending.into_iter().for_each(Self::send_pending_nominations);
 
-			remaining_weight
+			consumed
 		}
 	}
 
@@ -521,9 +636,50 @@ pub mod pallet {
 			PalletStakingLedger::<T>::insert(&asset, state);
 
 			Self::deposit_event(Event::SentBond(asset, controller, value));
+
+			// nominate the validators governance already selected for this asset
+			Self::send_pending_nominations(asset);
 			Ok(().into())
 		}
 
+		/// Sets the validators PINT's stash should nominate on the asset's chain.
+		///
+		/// The nominations are sent via a `pallet_staking`
+		/// [`nominate`](https://crates.parity.io/pallet_staking/enum.Call.html#variant.nominate) call
+		/// right away if the stash is already bonded, otherwise they're sent once it is bonded.
+		///
+		/// This is a noop if the targets didn't change.
+		/// Callable by the admin origin
+		#[pallet::weight(T::WeightInfo::send_nominate().saturating_add(T::DbWeight::get().reads_writes(3, 1)))]
+		pub fn set_nominations(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			targets: Vec<LookupSourceFor<T>>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(!targets.is_empty(), Error::<T>::NoNominations);
+			ensure!(targets.len() as u32 <= T::MaxNominations::get(), Error::<T>::TooManyNominations);
+
+			// ensures that the call is encodable for the destination
+			ensure!(T::PalletStakingCallEncoder::can_encode(&asset), Error::<T>::NotEncodableForLocation);
+			ensure!(PalletStakingConfig::<T>::contains_key(&asset), Error::<T>::NoPalletConfigFound);
+
+			// nominating requires controller origin
+			if let Some(ledger) = PalletStakingLedger::<T>::get(&asset) {
+				Self::ensure_staking_controller(ledger.controller)?;
+			}
+
+			if Nominations::<T>::get(&asset).map(|current| current.targets == targets).unwrap_or_default() {
+				return Ok(());
+			}
+
+			Nominations::<T>::insert(&asset, NominationState::new(targets.clone()));
+			Self::deposit_event(Event::UpdatedNominations(asset, targets));
+
+			Self::send_pending_nominations(asset);
+			Ok(())
+		}
+
 		/// Transacts a `pallet_proxy::Call::add_proxy` call to add a proxy on
 		/// behalf of the PINT parachain's account on the target chain.
 		///
@@ -925,6 +1081,69 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Sends the pending nominations of the asset via an XCM
+		/// [`nominate`](https://crates.parity.io/pallet_staking/enum.Call.html#variant.nominate) call
+		///
+		/// This does nothing if the asset isn't bonded with PINT's account as controller or is
+		/// frozen. Nominations that failed to send remain pending and are retried in `on_idle` once
+		/// `NominationRetryDelay` blocks passed.
+		fn send_pending_nominations(asset: T::AssetId) {
+			let mut nominations = match Nominations::<T>::get(&asset) {
+				Some(nominations) if nominations.is_pending() => nominations,
+				_ => return,
+			};
+			let config = match PalletStakingConfig::<T>::get(&asset) {
+				Some(config) if !config.is_frozen => config,
+				_ => return,
+			};
+			// only the controller is allowed to nominate
+			let bonded = PalletStakingLedger::<T>::get(&asset)
+				.map(|ledger| Self::ensure_staking_controller(ledger.controller).is_ok())
+				.unwrap_or_default();
+			if !bonded {
+				return;
+			}
+			let dest = match Self::asset_destination(asset) {
+				Ok(dest) => dest,
+				Err(_) => return,
+			};
+
+			match Self::do_transact_nominate(&config, asset, nominations.targets.clone(), dest) {
+				Ok(()) => {
+					XcmStakingCount::<T>::mutate(asset, |count| count.nominate = count.nominate.saturating_add(1));
+					nominations.sent_at = Some(frame_system::Pallet::<T>::block_number());
+					Self::deposit_event(Event::SentNominate(asset, nominations.targets.clone()));
+					Nominations::<T>::insert(&asset, nominations);
+				}
+				Err(err) => {
+					nominations.last_attempt = Some(frame_system::Pallet::<T>::block_number());
+					Nominations::<T>::insert(&asset, nominations);
+					Self::deposit_event(Event::ErrorSendingNominate(err, asset));
+				}
+			}
+		}
+
+		/// Encodes the correct `Xcm::Transact` message and sends it to the given destination
+		fn do_transact_nominate(
+			config: &StakingConfigFor<T>,
+			asset: T::AssetId,
+			targets: Vec<LookupSourceFor<T>>,
+			dest: MultiLocation,
+		) -> XcmResult {
+			let call = PalletStakingCall::<T>::Nominate(targets);
+			let encoder = call.encoder::<T::PalletStakingCallEncoder>(&asset);
+
+			let xcm = Self::wrap_call_into_xcm(
+				encoder.encode_runtime_call(config.pallet_index).encode(),
+				config.weights.nominate,
+				Self::xcm_dest_weight().into(),
+			);
+
+			let result = T::XcmSender::send_xcm(dest, xcm);
+			log::info!(target: "pint_xcm", "sent pallet_staking::nominate xcm: {:?} ",result);
+			result.map_err(|e| e.into())
+		}
+
 		/// Ensures that the controller account of
 		fn ensure_staking_controller(controller: LookupSourceFor<T>) -> DispatchResult {
 			ensure!(
@@ -1011,6 +1230,8 @@ pub mod pallet {
 		fn freeze() -> Weight;
 		fn thaw() -> Weight;
 		fn set_xcm_dest_weight() -> Weight;
+		fn staking_upkeep() -> Weight;
+		fn send_nominate() -> Weight;
 	}
 
 	/// For backwards compatibility and tests
@@ -1027,5 +1248,11 @@ pub mod pallet {
 		fn set_xcm_dest_weight() -> Weight {
 			Default::default()
 		}
+		fn staking_upkeep() -> Weight {
+			Default::default()
+		}
+		fn send_nominate() -> Weight {
+			Default::default()
+		}
 	}
 }
@@ -0,0 +1,89 @@
+// Copyright 2021 ChainSafe Systems
+// SPDX-License-Identifier: LGPL-3.0-only
+
+//! Storage migrations of the remote asset manager pallet
+
+use crate::{types::XcmStakingMessageCount, Config, Pallet, PalletStakingConfig, XcmStakingCount};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+use xcm_calls::staking::{RewardDestination, StakingConfig, StakingWeights};
+
+/// The encoding of the `pallet_staking` weights before `nominate` was sent
+#[derive(Decode)]
+struct OldStakingWeights {
+	bond: Weight,
+	bond_extra: Weight,
+	unbond: Weight,
+	withdraw_unbonded: Weight,
+}
+
+/// The encoding of a staking config before `nominate` was sent
+#[derive(Decode)]
+struct OldStakingConfig<T: Config> {
+	pallet_index: u8,
+	reward_destination: RewardDestination<T::AccountId>,
+	minimum_balance: T::Balance,
+	weights: OldStakingWeights,
+	bonding_duration: T::BlockNumber,
+	is_frozen: bool,
+}
+
+/// The encoding of the XCM staking counters before `nominate` was sent
+#[derive(Decode)]
+struct OldXcmStakingMessageCount {
+	bond_extra: u32,
+	unbond: u32,
+	withdraw_unbonded: u32,
+}
+
+/// Migrates the stored staking configs to include the weight of `nominate`, which is set to
+/// the given weight, and the stored XCM staking counters to include the `nominate` counter.
+pub fn migrate_to_v1<T: Config>(nominate: Weight) -> Weight {
+	if Pallet::<T>::on_chain_storage_version() >= 1 {
+		return T::DbWeight::get().reads(1);
+	}
+
+	let mut translated: Weight = 0;
+	PalletStakingConfig::<T>::translate::<OldStakingConfig<T>, _>(|_, config| {
+		translated = translated.saturating_add(1);
+		Some(StakingConfig {
+			pallet_index: config.pallet_index,
+			reward_destination: config.reward_destination,
+			minimum_balance: config.minimum_balance,
+			weights: StakingWeights {
+				bond: config.weights.bond,
+				bond_extra: config.weights.bond_extra,
+				unbond: config.weights.unbond,
+				withdraw_unbonded: config.weights.withdraw_unbonded,
+				nominate,
+			},
+			bonding_duration: config.bonding_duration,
+			is_frozen: config.is_frozen,
+		})
+	});
+	XcmStakingCount::<T>::translate::<OldXcmStakingMessageCount, _>(|_, count| {
+		translated = translated.saturating_add(1);
+		Some(XcmStakingMessageCount {
+			bond_extra: count.bond_extra,
+			unbond: count.unbond,
+			withdraw_unbonded: count.withdraw_unbonded,
+			nominate: 0,
+		})
+	});
+	StorageVersion::new(1).put::<Pallet<T>>();
+
+	log::info!("Migrated {} remote asset manager staking configs and counters", translated);
+	T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+}
+
+/// Runs [`migrate_to_v1`] as part of a runtime upgrade, with the weight of `nominate` on the
+/// assets' chains given by `NominateWeight`.
+pub struct MigrateToV1<T, NominateWeight>(PhantomData<(T, NominateWeight)>);
+
+impl<T: Config, NominateWeight: Get<Weight>> OnRuntimeUpgrade for MigrateToV1<T, NominateWeight> {
+	fn on_runtime_upgrade() -> Weight {
+		migrate_to_v1::<T>(NominateWeight::get())
+	}
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: LGPL-3.0-only
 
 use codec::{Decode, Encode};
-use frame_support::{sp_runtime::traits::AtLeast32BitUnsigned, RuntimeDebug};
+use frame_support::{sp_runtime::traits::AtLeast32BitUnsigned, sp_std::vec::Vec, RuntimeDebug};
 use xcm::v1::{AssetId, Fungibility, Junction, Junctions, MultiAsset, MultiLocation};
 
 /// Represents all XCM calls of the `pallet_staking` pallet transacted on a parachain
@@ -14,6 +14,39 @@ pub struct XcmStakingMessageCount {
 	pub unbond: u32,
 	/// Total number of all `pallet_staking::Pallet::withdraw_unbonded` calls transacted
 	pub withdraw_unbonded: u32,
+	/// Total number of all `pallet_staking::Pallet::nominate` calls transacted
+	pub nominate: u32,
+}
+
+/// The validators PINT's stash nominates on an asset's native chain
+#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug, scale_info::TypeInfo)]
+pub struct NominationState<Source, BlockNumber> {
+	/// The validators to nominate
+	pub targets: Vec<Source>,
+	/// The block at which the `targets` were sent via `pallet_staking::Pallet::nominate`,
+	/// `None` if they are still pending
+	pub sent_at: Option<BlockNumber>,
+	/// The block of the last failed attempt to send the `targets`, if any
+	pub last_attempt: Option<BlockNumber>,
+}
+
+impl<Source, BlockNumber: AtLeast32BitUnsigned + Copy> NominationState<Source, BlockNumber> {
+	/// Creates pending nominations of the given validators
+	pub fn new(targets: Vec<Source>) -> Self {
+		Self { targets, sent_at: None, last_attempt: None }
+	}
+
+	/// Whether the current `targets` still need to be nominated on the remote chain
+	pub fn is_pending(&self) -> bool {
+		self.sent_at.is_none()
+	}
+
+	/// Whether the pending `targets` should be sent at the given block, which is not the case
+	/// until `retry_delay` blocks passed since the last failed attempt
+	pub fn is_due(&self, now: BlockNumber, retry_delay: BlockNumber) -> bool {
+		self.is_pending() &&
+			self.last_attempt.map(|last_attempt| now >= last_attempt.saturating_add(retry_delay)).unwrap_or(true)
+	}
 }
 
 /// Represents the different balances of an asset
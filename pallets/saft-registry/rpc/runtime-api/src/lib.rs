@@ -0,0 +1,26 @@
+// Copyright 2021 ChainSafe Systems
+// SPDX-License-Identifier: LGPL-3.0-only
+
+//! Runtime API definition for the SAFT registry pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use sp_std::prelude::*;
+
+use primitives::AssetSAFTs;
+
+sp_api::decl_runtime_apis! {
+	pub trait SaftRegistryApi<AssetId, Balance, BlockNumber> where
+		AssetId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// The active SAFTs of the asset with their totals and the asset's share of the SAFT NAV
+		fn get_asset_safts(asset: AssetId) -> AssetSAFTs<AssetId, Balance, BlockNumber>;
+
+		/// The active SAFTs of all assets that have active SAFTs
+		fn get_all_asset_safts() -> Vec<AssetSAFTs<AssetId, Balance, BlockNumber>>;
+	}
+}
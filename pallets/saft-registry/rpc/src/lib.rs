@@ -0,0 +1,109 @@
+// Copyright 2021 ChainSafe Systems
+// SPDX-License-Identifier: LGPL-3.0-only
+
+//! RPC interface for the SAFT registry pallet.
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use primitives::AssetSAFTs;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use std::sync::Arc;
+
+pub use self::gen_client::Client as SaftRegistryClient;
+pub use pallet_saft_registry_rpc_runtime_api::SaftRegistryApi as SaftRegistryRuntimeApi;
+
+/// SAFT registry state API
+#[rpc]
+pub trait SaftRegistryApi<BlockHash, AssetId, Balance, BlockNumber> {
+	#[rpc(name = "saftRegistry_getAssetSafts")]
+	fn get_asset_safts(
+		&self,
+		asset: AssetId,
+		at: Option<BlockHash>,
+	) -> Result<AssetSAFTs<AssetId, Balance, BlockNumber>>;
+
+	#[rpc(name = "saftRegistry_getAllAssetSafts")]
+	fn get_all_asset_safts(&self, at: Option<BlockHash>) -> Result<Vec<AssetSAFTs<AssetId, Balance, BlockNumber>>>;
+}
+
+/// A struct that implements the [`SaftRegistryApi`].
+pub struct SaftRegistryBackend<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> SaftRegistryBackend<C, B> {
+	/// Create new `SaftRegistry` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		SaftRegistryBackend { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block> SaftRegistryBackend<C, Block>
+where
+	Block: BlockT,
+	C: HeaderBackend<Block>,
+{
+	/// Returns the block for the given hash or the best block, if no hash was supplied.
+	fn block_id(&self, at: Option<<Block as BlockT>::Hash>) -> BlockId<Block> {
+		BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		))
+	}
+}
+
+pub enum Error {
+	RuntimeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+/// Converts a runtime api error into an rpc error
+fn runtime_error(message: &str, e: impl std::fmt::Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(Error::RuntimeError.into()),
+		message: message.into(),
+		data: Some(format!("{:?}", e).into()),
+	}
+}
+
+impl<C, Block, AssetId, Balance, BlockNumber> SaftRegistryApi<<Block as BlockT>::Hash, AssetId, Balance, BlockNumber>
+	for SaftRegistryBackend<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: SaftRegistryRuntimeApi<Block, AssetId, Balance, BlockNumber>,
+	AssetId: Codec,
+	Balance: Codec,
+	BlockNumber: Codec,
+{
+	fn get_asset_safts(
+		&self,
+		asset: AssetId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<AssetSAFTs<AssetId, Balance, BlockNumber>> {
+		let at = self.block_id(at);
+		self.client
+			.runtime_api()
+			.get_asset_safts(&at, asset)
+			.map_err(|e| runtime_error("Unable to get SAFTs of the asset.", e))
+	}
+
+	fn get_all_asset_safts(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<AssetSAFTs<AssetId, Balance, BlockNumber>>> {
+		let at = self.block_id(at);
+		self.client.runtime_api().get_all_asset_safts(&at).map_err(|e| runtime_error("Unable to get SAFTs.", e))
+	}
+}
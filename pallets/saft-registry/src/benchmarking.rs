@@ -3,8 +3,9 @@
 
 #![cfg(feature = "runtime-benchmarks")]
 
-use frame_benchmarking::benchmarks;
+use frame_benchmarking::{benchmarks, whitelisted_caller};
 use frame_support::{assert_ok, dispatch::UnfilteredDispatchable, sp_runtime::traits::Zero, traits::EnsureOrigin};
+use frame_system::RawOrigin;
 use primitives::traits::AssetRecorderBenchmarks;
 use xcm::v1::{Junction, MultiLocation};
 
@@ -40,6 +41,38 @@ benchmarks! {
 		);
 	}
 
+	add_vesting_saft {
+		let asset: T::AssetId = T::try_convert(2u8).unwrap();
+		let origin = T::AdminOrigin::successful_origin();
+		let vesting = VestingSchedule {
+			start: Zero::zero(),
+			period: 10u32.into(),
+			period_count: 4,
+			per_period: 5u32.into(),
+		};
+
+		assert_ok!(T::AssetRecorderBenchmarks::add_asset(
+			T::try_convert(3u8).unwrap(),
+			100u32.into(),
+			MultiLocation::default(),
+			1000u32.into()
+		));
+
+		let call = Call::<T>::add_vesting_saft {
+				asset_id: asset,
+				nav: 100u32.into(),
+				units: 20u32.into(),
+				vesting: vesting.clone(),
+		};
+	}: { call.dispatch_bypass_filter(origin)? }
+	 verify {
+		let id = SaftRegistry::<T>::saft_counter(asset) - 1;
+		assert_eq!(
+			SaftRegistry::<T>::active_safts(asset, id),
+			Some(SAFTRecord::new(100_u32.into(), 20_u32.into()).with_vesting(vesting))
+		);
+	}
+
 	remove_saft {
 		let asset: T::AssetId = T::try_convert(2u8).unwrap();
 		let origin = T::AdminOrigin::successful_origin();
@@ -98,6 +131,66 @@ benchmarks! {
 		);
 	}
 
+	attest_nav {
+		let asset: T::AssetId = T::try_convert(2u8).unwrap();
+		let origin = T::AdminOrigin::successful_origin();
+		let attestor: T::AccountId = whitelisted_caller();
+
+		assert_ok!(T::AssetRecorderBenchmarks::add_asset(
+			T::try_convert(3u8).unwrap(),
+			100u32.into(),
+			MultiLocation::default(),
+			1000u32.into()
+		));
+
+		assert_ok!(SaftRegistry::<T>::add_saft(
+			origin.clone(),
+			asset,
+			100_u32.into(),
+			20_u32.into(),
+		));
+		assert_ok!(SaftRegistry::<T>::add_attestor(origin, attestor.clone()));
+
+		// advance the block number so that the SAFT can be attested again
+		<frame_system::Pallet<T>>::set_block_number(
+			<frame_system::Pallet<T>>::block_number() + T::MinAttestationInterval::get(),
+		);
+		let timestamp = T::Time::now().as_millis() as u64;
+	}: _(
+		RawOrigin::Signed(attestor.clone()),
+		asset,
+		0,
+		101_u32.into(),
+		timestamp,
+		Default::default()
+	) verify {
+		assert_eq!(
+			SaftRegistry::<T>::active_safts(asset, 0u32),
+			Some(SAFTRecord::new(101_u32.into(), 20_u32.into()))
+		);
+		assert_eq!(
+			SaftRegistry::<T>::last_reported(asset, 0u32).map(|report| report.reporter),
+			Some(attestor)
+		);
+	}
+
+	add_attestor {
+		let origin = T::AdminOrigin::successful_origin();
+		let attestor: T::AccountId = whitelisted_caller();
+		let call = Call::<T>::add_attestor { attestor: attestor.clone() };
+	}: { call.dispatch_bypass_filter(origin)? } verify {
+		assert!(SaftRegistry::<T>::attestors(attestor).is_some());
+	}
+
+	remove_attestor {
+		let origin = T::AdminOrigin::successful_origin();
+		let attestor: T::AccountId = whitelisted_caller();
+		assert_ok!(SaftRegistry::<T>::add_attestor(origin.clone(), attestor.clone()));
+		let call = Call::<T>::remove_attestor { attestor: attestor.clone() };
+	}: { call.dispatch_bypass_filter(origin)? } verify {
+		assert!(SaftRegistry::<T>::attestors(attestor).is_none());
+	}
+
 	convert_to_liquid {
 		let o in 1 .. MAX_SAFT_RECORDS;
 
@@ -130,14 +223,49 @@ benchmarks! {
 			location: (Junction::Parachain(100)).into()
 		};
 	}: { call.dispatch_bypass_filter(origin)? } verify {
-		assert_eq!(
-			SaftRegistry::<T>::saft_counter(asset),
-			0
+		assert!(
+			SaftRegistry::<T>::active_safts(asset, 0).is_none()
 		);
 		assert!(
 			SaftRegistry::<T>::saft_nav(asset).is_zero()
 		);
 	}
+
+	convert_safts_to_liquid {
+		let s in 1 .. T::MaxSaftConversions::get();
+
+		let asset: T::AssetId = T::try_convert(5u8).unwrap();
+		let origin = T::AdminOrigin::successful_origin();
+		let location: MultiLocation = (Junction::Parachain(100)).into();
+
+		assert_ok!(T::AssetRecorderBenchmarks::add_asset(
+			T::try_convert(3u8).unwrap(),
+			100u32.into(),
+			MultiLocation::default(),
+			1000u32.into()
+		));
+
+		// the first SAFT makes the asset liquid, the others are converted afterwards
+		for _ in 0 .. s + 1 {
+			assert_ok!(SaftRegistry::<T>::add_saft(
+				origin.clone(),
+				asset,
+				100u32.into(),
+				20u32.into(),
+			));
+		}
+		assert_ok!(SaftRegistry::<T>::convert_safts_to_liquid(origin.clone(), asset, location.clone(), vec![0]));
+
+		let call = Call::<T>::convert_safts_to_liquid {
+			asset_id: asset,
+			location,
+			saft_ids: (1 .. s + 1).collect(),
+		};
+	}: { call.dispatch_bypass_filter(origin)? } verify {
+		assert!(
+			SaftRegistry::<T>::saft_nav(asset).is_zero()
+		);
+	}
 }
 
 #[cfg(test)]
@@ -155,6 +283,13 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn add_vesting_saft() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_add_vesting_saft());
+		});
+	}
+
 	#[test]
 	fn remove_saft() {
 		new_test_ext().execute_with(|| {
@@ -169,10 +304,38 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn attest_nav() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_attest_nav());
+		});
+	}
+
+	#[test]
+	fn add_attestor() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_add_attestor());
+		});
+	}
+
+	#[test]
+	fn remove_attestor() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_remove_attestor());
+		});
+	}
+
 	#[test]
 	fn convert_to_liquid() {
 		new_test_ext().execute_with(|| {
 			assert_ok!(Pallet::<Test>::test_benchmark_convert_to_liquid());
 		});
 	}
+
+	#[test]
+	fn convert_safts_to_liquid() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_convert_safts_to_liquid());
+		});
+	}
 }
@@ -11,10 +11,21 @@
 //! which is implemented for the `AssetIndex`. Adding a SAFT record will call into the
 //! `AssetRecorder::add_saft` function, in mints new PINT according to the value of the SAFT record.
 //! SAFTs can be converted to liquid tokens once they're available in the network with a location.
+//!
+//! SAFT records can carry a `VestingSchedule`. When the asset is converted to liquid, only the
+//! vested units of such records become liquid, the remaining units stay locked in the index and
+//! are valued by the record's NAV. The locked units are released as they vest.
+//!
+//! Besides the governance committee, registered attestors can report the NAV of SAFTs. Their
+//! reports carry the time of the valuation and the hash of the documentation backing it, and
+//! may only change the NAV of a SAFT by `MaxAttestedNavChange` per report. The last report of
+//! every SAFT is kept in `LastReported`, SAFTs whose NAV was not reported within
+//! `MaxSaftNavAge` blocks are considered stale and flagged as such by the runtime API.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
+pub mod migrations;
 
 #[cfg(test)]
 mod mock;
@@ -35,20 +46,24 @@ pub mod pallet {
 	use frame_support::{
 		pallet_prelude::*,
 		sp_runtime::{
+			helpers_128bit::multiply_by_rational,
 			traits::{AtLeast32BitUnsigned, CheckedAdd, One, Saturating, Zero},
-			ArithmeticError,
+			ArithmeticError, FixedPointNumber, Permill, SaturatedConversion,
 		},
 		sp_std::{self, prelude::*, result::Result},
+		traits::UnixTime,
 		transactional,
 	};
 	use frame_system::pallet_prelude::*;
 	use primitives::{
 		traits::{AssetRecorder, MaybeAssetIdConvert, SaftRegistry},
 		types::AssetAvailability,
-		SAFTId,
+		Ratio, SAFTId,
 	};
 	use xcm::v1::MultiLocation;
 
+	pub use primitives::types::{AssetSAFTs, SAFTInfo, VestingSchedule};
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config + MaybeAssetIdConvert<u8, Self::AssetId> {
 		// Origin that is allowed to manage the SAFTs
@@ -58,6 +73,24 @@ pub mod pallet {
 		type AssetRecorderBenchmarks: AssetRecorderBenchmarks<Self::AssetId, Self::Balance>;
 		type Balance: Parameter + Member + AtLeast32BitUnsigned + Default + Copy;
 		type AssetId: Parameter + Member + Copy;
+		/// The maximum relative change of a SAFT's NAV an attestor can report
+		/// with a single report.
+		#[pallet::constant]
+		type MaxAttestedNavChange: Get<Permill>;
+		/// The number of blocks after which the reported NAV of a SAFT is
+		/// considered stale.
+		#[pallet::constant]
+		type MaxSaftNavAge: Get<Self::BlockNumber>;
+		/// The maximum number of SAFTs that can be converted with a single
+		/// `convert_safts_to_liquid` call.
+		#[pallet::constant]
+		type MaxSaftConversions: Get<u32>;
+		/// The minimum number of blocks between the last report of a SAFT's
+		/// NAV and a report by an attestor.
+		#[pallet::constant]
+		type MinAttestationInterval: Get<Self::BlockNumber>;
+		/// The current time, attested valuations must not be newer than it.
+		type Time: UnixTime;
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 		/// The weight for this pallet's extrinsics.
 		type WeightInfo: WeightInfo;
@@ -65,21 +98,80 @@ pub mod pallet {
 
 	/// Represents a single off-chain SAFT Record of a non liquid asset
 	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
-	pub struct SAFTRecord<Balance, NAV> {
+	pub struct SAFTRecord<Balance, NAV, BlockNumber> {
 		/// Net asset value of the SAFT, or the total value of `units`
 		nav: NAV,
 		/// How many units of the asset are included in the SAFT
 		units: Balance,
+		/// When the units of the SAFT unlock, `None` if they unlock as soon as
+		/// the asset is liquid
+		vesting: Option<VestingSchedule<BlockNumber, Balance>>,
 	}
 
-	impl<Balance, NAV> SAFTRecord<Balance, NAV> {
+	impl<Balance, NAV, BlockNumber> SAFTRecord<Balance, NAV, BlockNumber> {
 		pub fn new(nav: NAV, units: Balance) -> Self {
-			Self { nav, units }
+			Self { nav, units, vesting: None }
+		}
+
+		pub fn with_vesting(self, vesting: VestingSchedule<BlockNumber, Balance>) -> Self {
+			Self { vesting: Some(vesting), ..self }
 		}
 	}
 
+	/// The archived record of a SAFT that was converted into liquid units
+	#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct ConvertedSAFT<Balance, BlockNumber> {
+		/// The NAV of the SAFT's units at the time they were converted
+		pub nav: Balance,
+		/// The converted units of the SAFT
+		pub units: Balance,
+		/// The block of the last conversion
+		pub converted_at: BlockNumber,
+	}
+
+	/// The last report of a SAFT's NAV
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct NavReport<AccountId, BlockNumber, Hash> {
+		/// The account that reported the NAV
+		pub reporter: AccountId,
+		/// The block the NAV was reported at
+		pub reported_at: BlockNumber,
+		/// The time of the valuation in milliseconds since the unix epoch,
+		/// `None` if the NAV was not reported by an attestor
+		pub timestamp: Option<u64>,
+		/// The hash of the documentation backing the valuation, `None` if the
+		/// NAV was not reported by an attestor
+		pub documentation: Option<Hash>,
+	}
+
+	/// An upcoming unlock of SAFT units
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct SAFTUnlock<BlockNumber, Balance> {
+		/// The SAFT whose units unlock
+		pub saft_id: SAFTId,
+		/// The block the units unlock at
+		pub block: BlockNumber,
+		/// The number of units that unlock
+		pub units: Balance,
+	}
+
+	/// The SAFT record type stored by the registry
+	pub type SAFTRecordFor<T> =
+		SAFTRecord<<T as Config>::Balance, <T as Config>::Balance, <T as frame_system::Config>::BlockNumber>;
+
+	/// The NAV report type stored by the registry
+	pub type NavReportFor<T> = NavReport<
+		<T as frame_system::Config>::AccountId,
+		<T as frame_system::Config>::BlockNumber,
+		<T as frame_system::Config>::Hash,
+	>;
+
+	/// The current storage version
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Store a mapping (AssetId, SAFTId) -> SAFTRecord
@@ -87,18 +179,12 @@ pub mod pallet {
 	/// Since `SAFTCounter(AssetId)` stores a running counter of `SAFTRecord`,
 	/// this map is guaranteed to store less `SAFTRecord`s than the asset's
 	/// `SAFTCounter`. If this maps stores a `None` value for a `SAFTId` lower
-	/// than the counter, then this means the record was removed entirely.
+	/// than the counter, then this means the record was removed entirely or
+	/// converted into liquid units, see `ConvertedSAFTs`.
 	#[pallet::storage]
 	#[pallet::getter(fn active_safts)]
-	pub type ActiveSAFTs<T: Config> = StorageDoubleMap<
-		_,
-		Blake2_128Concat,
-		T::AssetId,
-		Twox64Concat,
-		SAFTId,
-		SAFTRecord<T::Balance, T::Balance>,
-		OptionQuery,
-	>;
+	pub type ActiveSAFTs<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AssetId, Twox64Concat, SAFTId, SAFTRecordFor<T>, OptionQuery>;
 
 	/// A running counter used to determine the next SAFT id for a specific asset.
 	#[pallet::storage]
@@ -113,21 +199,69 @@ pub mod pallet {
 	#[pallet::getter(fn saft_nav)]
 	pub type SAFTNetAssetValue<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, T::Balance, ValueQuery>;
 
+	/// Store a mapping (AssetId, SAFTId) -> ConvertedSAFT of all SAFTs whose
+	/// units were converted into liquid units
+	///
+	/// Serves as an audit trail of the conversions, the entries of SAFTs with
+	/// a vesting schedule accumulate the units converted with every unlock.
+	#[pallet::storage]
+	#[pallet::getter(fn converted_safts)]
+	pub type ConvertedSAFTs<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Twox64Concat,
+		SAFTId,
+		ConvertedSAFT<T::Balance, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	/// Store a mapping (AssetId, SAFTId) -> NavReport of the last NAV report
+	/// of every active SAFT
+	#[pallet::storage]
+	#[pallet::getter(fn last_reported)]
+	pub type LastReported<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AssetId, Twox64Concat, SAFTId, NavReportFor<T>, OptionQuery>;
+
+	/// The accounts that are allowed to report the NAV of SAFTs with
+	/// `attest_nav`
+	#[pallet::storage]
+	#[pallet::getter(fn attestors)]
+	pub type Attestors<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// Store a mapping (BlockNumber) -> [(AssetId, SAFTId)] of the SAFTs with
+	/// units that unlock at that block
+	#[pallet::storage]
+	pub type UnlockAgenda<T: Config> =
+		StorageMap<_, Twox64Concat, T::BlockNumber, Vec<(T::AssetId, SAFTId)>, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// A new SAFT was added
 		/// \[AssetId, AssetIndex, SAFT Record\]
-		SAFTAdded(T::AssetId, SAFTId, SAFTRecord<T::Balance, T::Balance>),
+		SAFTAdded(T::AssetId, SAFTId, SAFTRecordFor<T>),
 		/// A SAFT was removed
 		/// \[AssetId, AssetIndex, SAFT Record\]
-		SAFTRemoved(T::AssetId, SAFTId, SAFTRecord<T::Balance, T::Balance>),
+		SAFTRemoved(T::AssetId, SAFTId, SAFTRecordFor<T>),
 		/// The NAV for a SAFT was updated
 		/// \[AssetId, AssetIndex, OldNav, NewNav\]
 		NavUpdated(T::AssetId, SAFTId, T::Balance, T::Balance),
 		/// A SAFT was converted into a liquid asset
 		/// \[AssetId, MultiLocation\]
 		ConvertedToLiquid(T::AssetId, MultiLocation),
+		/// Units of a SAFT were converted into liquid units of the index
+		/// \[AssetId, SAFTId, Units, NAV\]
+		SAFTConverted(T::AssetId, SAFTId, T::Balance, T::Balance),
+		/// An account was registered as attestor
+		/// \[Attestor\]
+		AttestorAdded(T::AccountId),
+		/// An attestor was removed
+		/// \[Attestor\]
+		AttestorRemoved(T::AccountId),
+		/// An attestor reported the NAV of a SAFT
+		/// \[AssetId, SAFTId, Attestor, NAV, Timestamp, Documentation\]
+		NavAttested(T::AssetId, SAFTId, T::AccountId, T::Balance, u64, T::Hash),
 	}
 
 	#[pallet::error]
@@ -136,10 +270,41 @@ pub mod pallet {
 		SAFTNotFound,
 		/// Thrown if the given asset was not a known SAFT.
 		ExpectedSAFT,
+		/// Thrown if a vesting schedule has no unlocks or its total does not
+		/// match the units of the SAFT.
+		InvalidVestingSchedule,
+		/// Thrown if the given location does not match the location of the
+		/// liquid asset.
+		LocationMismatch,
+		/// Thrown if the account is already an attestor.
+		AlreadyAttestor,
+		/// Thrown if the account is not an attestor.
+		NotAttestor,
+		/// Thrown if the reported NAV differs from the SAFT's current NAV by
+		/// more than `MaxAttestedNavChange`.
+		NavChangeTooLarge,
+		/// Thrown if the timestamp of a report is not newer than the timestamp
+		/// of the SAFT's last report.
+		OutdatedNavReport,
+		/// Thrown if more than `MaxSaftConversions` SAFTs are converted at once.
+		TooManySAFTConversions,
+		/// Thrown if the same SAFT is converted more than once in a single call.
+		DuplicateSAFTConversion,
+		/// Thrown if an attestor reports the NAV of a SAFT less than
+		/// `MinAttestationInterval` blocks after its last report.
+		NavReportTooFrequent,
+		/// Thrown if the timestamp of a report is newer than the current time.
+		FutureNavReport,
 	}
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Moves the SAFT units that vest at this block into the liquid
+		/// holdings of the index
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			Self::process_unlocks(now)
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -176,7 +341,31 @@ pub mod pallet {
 			nav: T::Balance,
 			units: T::Balance,
 		) -> DispatchResult {
-			Self::do_add_saft(T::AdminOrigin::ensure_origin(origin)?, asset_id, nav, units)
+			Self::do_add_saft(T::AdminOrigin::ensure_origin(origin)?, asset_id, nav, units, None)
+		}
+
+		/// Adds a new SAFT whose units vest according to the given schedule.
+		///
+		/// Works like `add_saft`, but once the asset is converted to liquid, only the vested
+		/// units of the SAFT become liquid. The remaining units stay locked and are moved into
+		/// the liquid holdings of the index as they vest.
+		///
+		/// The total of the `vesting` schedule must equal the `units` of the SAFT.
+		///
+		/// Callable by the governance committee.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::add_vesting_saft())]
+		pub fn add_vesting_saft(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			nav: T::Balance,
+			units: T::Balance,
+			vesting: VestingSchedule<T::BlockNumber, T::Balance>,
+		) -> DispatchResult {
+			let who = T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(vesting.period_count > 0 && vesting.total() == Some(units), Error::<T>::InvalidVestingSchedule);
+			Self::do_add_saft(who, asset_id, nav, units, Some(vesting))
 		}
 
 		/// Adds saft with root origin
@@ -189,7 +378,7 @@ pub mod pallet {
 			units: T::Balance,
 		) -> DispatchResult {
 			ensure_root(origin)?;
-			Self::do_add_saft(recipient, asset_id, nav, units)
+			Self::do_add_saft(recipient, asset_id, nav, units, None)
 		}
 
 		/// Removes the SAFT from the registry by purging it from the
@@ -230,9 +419,8 @@ pub mod pallet {
 		/// NAV of the record will also be reflected in the `asset-index`, because this does not
 		/// burn any index tokens but rather represents a "changed price" of the future token.
 		///
-		///
-		/// This is a noop if the given `latest_nav` is equal to the current nav of the SAFT.
-		///
+		/// The report is recorded in `LastReported`, even if the given `latest_nav` is equal to
+		/// the current nav of the SAFT.
 		///
 		/// Parameters:
 		///   - `asset_id`: The identifier of the SAFT
@@ -250,31 +438,105 @@ pub mod pallet {
 			saft_id: SAFTId,
 			latest_nav: T::Balance,
 		) -> DispatchResult {
-			T::AdminOrigin::ensure_origin(origin)?;
-
-			let old_nav = ActiveSAFTs::<T>::try_mutate(asset_id, saft_id, |maybe_saft| -> Result<_, DispatchError> {
-				let saft = maybe_saft.as_mut().ok_or(Error::<T>::SAFTNotFound)?;
-				Ok(sp_std::mem::replace(&mut saft.nav, latest_nav))
-			})?;
+			let who = T::AdminOrigin::ensure_origin(origin)?;
+			let report = NavReport {
+				reporter: who,
+				reported_at: frame_system::Pallet::<T>::block_number(),
+				timestamp: None,
+				documentation: None,
+			};
+			Self::do_report_nav(asset_id, saft_id, latest_nav, report, None)
+		}
 
-			if old_nav == latest_nav {
-				// nothing to update
-				return Ok(());
+		/// Reports the NAV of a SAFT as attestor.
+		///
+		/// Works like `report_nav`, but the reported NAV may differ from the current NAV of the
+		/// SAFT by at most `MaxAttestedNavChange`, and only `MinAttestationInterval` blocks after
+		/// the last report of the SAFT. Larger changes require a `report_nav` by the governance
+		/// committee.
+		///
+		/// Parameters:
+		///   - `asset_id`: The identifier of the SAFT's asset
+		///   - `saft_id`: The identifier of the SAFT whose NAV is reported
+		///   - `latest_nav`: The NAV of the SAFT
+		///   - `timestamp`: The time of the valuation in milliseconds since the unix epoch, must be
+		///     newer than the timestamp of the SAFT's last attested report and not in the future
+		///   - `documentation`: The hash of the documentation backing the valuation
+		///
+		/// Callable by attestors.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::attest_nav())]
+		#[transactional]
+		pub fn attest_nav(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			saft_id: SAFTId,
+			latest_nav: T::Balance,
+			timestamp: u64,
+			documentation: T::Hash,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Attestors::<T>::contains_key(&who), Error::<T>::NotAttestor);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			if let Some(last) = LastReported::<T>::get(asset_id, saft_id) {
+				ensure!(
+					now.saturating_sub(last.reported_at) >= T::MinAttestationInterval::get(),
+					Error::<T>::NavReportTooFrequent
+				);
+				ensure!(last.timestamp.map(|last| timestamp > last).unwrap_or(true), Error::<T>::OutdatedNavReport);
 			}
+			ensure!(u128::from(timestamp) <= T::Time::now().as_millis(), Error::<T>::FutureNavReport);
 
-			SAFTNetAssetValue::<T>::try_mutate(asset_id, |nav| -> Result<_, DispatchError> {
-				*nav = nav.saturating_sub(old_nav).checked_add(&latest_nav).ok_or(ArithmeticError::Overflow)?;
-				Ok(())
-			})?;
+			let report = NavReport {
+				reporter: who.clone(),
+				reported_at: now,
+				timestamp: Some(timestamp),
+				documentation: Some(documentation),
+			};
+			Self::do_report_nav(asset_id, saft_id, latest_nav, report, Some(T::MaxAttestedNavChange::get()))?;
 
-			Self::deposit_event(Event::<T>::NavUpdated(asset_id, saft_id, old_nav, latest_nav));
+			Self::deposit_event(Event::<T>::NavAttested(asset_id, saft_id, who, latest_nav, timestamp, documentation));
+			Ok(())
+		}
+
+		/// Registers the account as attestor, allowed to report the NAV of SAFTs
+		/// with `attest_nav`.
+		///
+		/// Callable by the governance committee.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::add_attestor())]
+		pub fn add_attestor(origin: OriginFor<T>, attestor: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(!Attestors::<T>::contains_key(&attestor), Error::<T>::AlreadyAttestor);
+			Attestors::<T>::insert(&attestor, ());
+			Self::deposit_event(Event::<T>::AttestorAdded(attestor));
+			Ok(())
+		}
 
+		/// Removes the account from the attestors.
+		///
+		/// Callable by the governance committee.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::remove_attestor())]
+		pub fn remove_attestor(origin: OriginFor<T>, attestor: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Attestors::<T>::take(&attestor).ok_or(Error::<T>::NotAttestor)?;
+			Self::deposit_event(Event::<T>::AttestorRemoved(attestor));
 			Ok(())
 		}
 
 		/// Converts the asset secured by the SAFT into a liquid asset with the given
 		/// location
 		///
+		/// SAFTs without a vesting schedule and the vested units of SAFTs with a
+		/// vesting schedule become liquid. The units that did not vest yet stay
+		/// locked in the index, their SAFTs remain in the registry until they vest.
+		/// Converted SAFTs are archived in `ConvertedSAFTs`.
+		///
 		/// Callable by the governance committee.
 		///
 		/// Weight: `O(C)` where C is the number of SAFTs for the asset as tracked by the
@@ -290,20 +552,74 @@ pub mod pallet {
 		) -> DispatchResult {
 			T::AdminOrigin::ensure_origin(origin)?;
 
-			// update the asset location and ensure it was a SAFT
-			let maybe_availability = T::AssetRecorder::insert_asset_availability(asset_id, location.clone().into());
-			ensure!(maybe_availability == Some(AssetAvailability::Saft), Error::<T>::ExpectedSAFT);
+			Self::ensure_liquid(asset_id, location.clone())?;
 
-			// remove all SAFT records
-			SAFTNetAssetValue::<T>::take(asset_id);
-			let counter = SAFTCounter::<T>::take(asset_id);
-			for saft_id in SAFTId::zero()..counter {
-				ActiveSAFTs::<T>::take(asset_id, saft_id);
+			// convert all SAFT records
+			let now = frame_system::Pallet::<T>::block_number();
+			let saft_ids: Vec<_> = ActiveSAFTs::<T>::iter_key_prefix(asset_id).collect();
+			for saft_id in saft_ids {
+				Self::convert_saft(asset_id, saft_id, now)?;
 			}
 
 			Self::deposit_event(Event::<T>::ConvertedToLiquid(asset_id, location));
 			Ok(())
 		}
+
+		/// Converts the given SAFTs of the asset into liquid units of the asset
+		/// with the given location
+		///
+		/// If the asset is still a SAFT, it becomes liquid, but the units of all
+		/// SAFTs that are not converted stay locked in the index and keep being
+		/// valued by their NAV. The asset remains partially secured by SAFTs until
+		/// the last SAFT is converted, SAFTs that are not converted can still be
+		/// removed with `remove_saft`. Of SAFTs with a vesting schedule only the
+		/// vested units are converted. Converted SAFTs are archived in
+		/// `ConvertedSAFTs`.
+		///
+		/// Parameters:
+		///   - `asset_id`: The identifier of the asset of the SAFTs
+		///   - `location`: The location of the liquid asset, must match the location of an asset
+		///     that is already liquid
+		///   - `saft_ids`: The ids of the SAFTs to convert
+		///
+		/// Callable by the governance committee.
+		///
+		/// Weight: `O(S)` where S is the number of given `saft_ids`, at most
+		/// `MaxSaftConversions`. Making the asset liquid additionally costs `O(C)` where C is
+		/// the number of SAFTs for the asset as tracked by the `SAFTCounter`, which is refunded
+		/// if the asset is liquid already.
+		#[pallet::weight(
+			T::WeightInfo::convert_safts_to_liquid(saft_ids.len() as u32)
+				.saturating_add(T::WeightInfo::convert_to_liquid(SAFTCounter::<T>::get(asset_id).saturating_sub(1)))
+		)]
+		#[transactional]
+		pub fn convert_safts_to_liquid(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			location: MultiLocation,
+			saft_ids: Vec<SAFTId>,
+		) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(saft_ids.len() <= T::MaxSaftConversions::get() as usize, Error::<T>::TooManySAFTConversions);
+			let mut unique_ids = saft_ids.clone();
+			unique_ids.sort_unstable();
+			unique_ids.dedup();
+			ensure!(unique_ids.len() == saft_ids.len(), Error::<T>::DuplicateSAFTConversion);
+
+			let was_saft = Self::ensure_liquid(asset_id, location)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			for saft_id in saft_ids.iter() {
+				ensure!(ActiveSAFTs::<T>::contains_key(asset_id, saft_id), Error::<T>::SAFTNotFound);
+				Self::convert_saft(asset_id, *saft_id, now)?;
+			}
+
+			if was_saft {
+				Ok(().into())
+			} else {
+				Ok(Some(T::WeightInfo::convert_safts_to_liquid(saft_ids.len() as u32)).into())
+			}
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -317,6 +633,7 @@ pub mod pallet {
 			asset_id: T::AssetId,
 			nav: T::Balance,
 			units: T::Balance,
+			vesting: Option<VestingSchedule<T::BlockNumber, T::Balance>>,
 		) -> DispatchResult {
 			if units.is_zero() {
 				return Ok(());
@@ -337,9 +654,19 @@ pub mod pallet {
 				Ok(id)
 			})?;
 
-			// insert the new record
-			let record = SAFTRecord::new(nav, units);
+			// insert the new record, its NAV counts as reported by the recipient
+			let record = SAFTRecord { nav, units, vesting };
 			ActiveSAFTs::<T>::insert(asset_id, saft_id, record.clone());
+			LastReported::<T>::insert(
+				asset_id,
+				saft_id,
+				NavReport {
+					reporter: recipient,
+					reported_at: frame_system::Pallet::<T>::block_number(),
+					timestamp: None,
+					documentation: None,
+				},
+			);
 			Self::deposit_event(Event::<T>::SAFTAdded(asset_id, saft_id, record));
 			Ok(())
 		}
@@ -349,6 +676,7 @@ pub mod pallet {
 		fn do_remove_saft(who: T::AccountId, asset_id: T::AssetId, saft_id: SAFTId) -> DispatchResult {
 			// remove the SAFT record
 			let saft = ActiveSAFTs::<T>::take(asset_id, saft_id).ok_or(Error::<T>::SAFTNotFound)?;
+			LastReported::<T>::remove(asset_id, saft_id);
 
 			// reflect the change in NAV
 			T::AssetRecorder::remove_saft(&who, asset_id, saft.units, saft.nav)?;
@@ -358,6 +686,248 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Updates the NAV of the SAFT and records the report
+		///
+		/// If `max_change` is set, the new NAV may differ from the SAFT's current
+		/// NAV by at most that fraction of the current NAV.
+		fn do_report_nav(
+			asset_id: T::AssetId,
+			saft_id: SAFTId,
+			latest_nav: T::Balance,
+			report: NavReportFor<T>,
+			max_change: Option<Permill>,
+		) -> DispatchResult {
+			let old_nav = ActiveSAFTs::<T>::try_mutate(asset_id, saft_id, |maybe_saft| -> Result<_, DispatchError> {
+				let saft = maybe_saft.as_mut().ok_or(Error::<T>::SAFTNotFound)?;
+				if let Some(max_change) = max_change {
+					let change = if latest_nav > saft.nav {
+						latest_nav.saturating_sub(saft.nav)
+					} else {
+						saft.nav.saturating_sub(latest_nav)
+					};
+					ensure!(change <= max_change * saft.nav, Error::<T>::NavChangeTooLarge);
+				}
+				Ok(sp_std::mem::replace(&mut saft.nav, latest_nav))
+			})?;
+			LastReported::<T>::insert(asset_id, saft_id, report);
+
+			if old_nav == latest_nav {
+				// nothing to update
+				return Ok(());
+			}
+
+			SAFTNetAssetValue::<T>::try_mutate(asset_id, |nav| -> Result<_, DispatchError> {
+				*nav = nav.saturating_sub(old_nav).checked_add(&latest_nav).ok_or(ArithmeticError::Overflow)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::NavUpdated(asset_id, saft_id, old_nav, latest_nav));
+
+			Ok(())
+		}
+
+		/// Returns the ids of the asset's SAFTs whose NAV was not reported within
+		/// the last `MaxSaftNavAge` blocks
+		pub fn stale_safts(asset_id: T::AssetId) -> Vec<SAFTId> {
+			let now = frame_system::Pallet::<T>::block_number();
+			ActiveSAFTs::<T>::iter_key_prefix(asset_id)
+				.filter(|saft_id| {
+					Self::is_stale(LastReported::<T>::get(asset_id, saft_id).map(|report| report.reported_at), now)
+				})
+				.collect()
+		}
+
+		/// Whether a NAV last reported at the given block is older than
+		/// `MaxSaftNavAge` blocks
+		fn is_stale(reported_at: Option<T::BlockNumber>, now: T::BlockNumber) -> bool {
+			reported_at.map(|reported_at| now.saturating_sub(reported_at) > T::MaxSaftNavAge::get()).unwrap_or(true)
+		}
+
+		/// The share of the SAFT's NAV that is attributed to the given units
+		fn nav_share(saft: &SAFTRecordFor<T>, units: T::Balance) -> Result<T::Balance, DispatchError> {
+			if saft.units.is_zero() {
+				return Ok(Zero::zero());
+			}
+			let share = multiply_by_rational(
+				saft.nav.saturated_into::<u128>(),
+				units.saturated_into::<u128>(),
+				saft.units.saturated_into::<u128>(),
+			)
+			.map_err(|_| ArithmeticError::Overflow)?;
+			Ok(share.saturated_into())
+		}
+
+		/// Makes the asset liquid with the given location
+		///
+		/// If the asset was a SAFT, the units of all its SAFTs are locked in the
+		/// index, so that they keep being valued by their NAV until they are
+		/// converted, and the next unlocks of SAFTs with a vesting schedule are
+		/// scheduled.
+		///
+		/// Returns whether the asset was a SAFT.
+		fn ensure_liquid(asset_id: T::AssetId, location: MultiLocation) -> Result<bool, DispatchError> {
+			match T::AssetRecorder::insert_asset_availability(asset_id, location.clone().into()) {
+				Some(AssetAvailability::Saft) => {
+					let now = frame_system::Pallet::<T>::block_number();
+					let mut units = T::Balance::zero();
+					for (saft_id, saft) in ActiveSAFTs::<T>::iter_prefix(asset_id) {
+						units = units.saturating_add(saft.units);
+						if let Some(next_unlock) = saft.vesting.and_then(|vesting| vesting.next_unlock(now)) {
+							UnlockAgenda::<T>::append(next_unlock, (asset_id, saft_id));
+						}
+					}
+					T::AssetRecorder::lock_saft_units(asset_id, units)?;
+					Ok(true)
+				}
+				Some(AssetAvailability::Liquid(current)) => {
+					ensure!(current == location, Error::<T>::LocationMismatch);
+					Ok(false)
+				}
+				None => Err(Error::<T>::ExpectedSAFT.into()),
+			}
+		}
+
+		/// Converts the units of the SAFT that are not locked by its vesting
+		/// schedule into liquid units of the index and archives them
+		///
+		/// Expects the asset to be liquid already.
+		#[transactional]
+		fn convert_saft(asset_id: T::AssetId, saft_id: SAFTId, now: BlockNumberFor<T>) -> DispatchResult {
+			let mut saft = match ActiveSAFTs::<T>::get(asset_id, saft_id) {
+				Some(saft) => saft,
+				// the SAFT was already converted or removed
+				None => return Ok(()),
+			};
+			let locked = saft.vesting.as_ref().map(|vesting| vesting.locked_at(now)).unwrap_or_else(Zero::zero);
+			let units = saft.units.saturating_sub(locked);
+			if units.is_zero() {
+				return Ok(());
+			}
+			let nav = Self::nav_share(&saft, units)?;
+
+			// move the units into the liquid holdings of the index
+			T::AssetRecorder::unlock_saft_units(asset_id, units)?;
+			SAFTNetAssetValue::<T>::mutate_exists(asset_id, |maybe_nav| {
+				*maybe_nav = maybe_nav.map(|total| total.saturating_sub(nav)).filter(|total| !total.is_zero());
+			});
+
+			// keep track of the converted units
+			ConvertedSAFTs::<T>::mutate(asset_id, saft_id, |maybe_converted| {
+				let converted = maybe_converted.get_or_insert_with(Default::default);
+				converted.nav = converted.nav.saturating_add(nav);
+				converted.units = converted.units.saturating_add(units);
+				converted.converted_at = now;
+			});
+
+			saft.units = saft.units.saturating_sub(units);
+			saft.nav = saft.nav.saturating_sub(nav);
+			if saft.units.is_zero() {
+				ActiveSAFTs::<T>::remove(asset_id, saft_id);
+				LastReported::<T>::remove(asset_id, saft_id);
+			} else {
+				ActiveSAFTs::<T>::insert(asset_id, saft_id, saft);
+			}
+
+			Self::deposit_event(Event::<T>::SAFTConverted(asset_id, saft_id, units, nav));
+			Ok(())
+		}
+
+		/// Converts the units of SAFTs that vest at the given block and schedules
+		/// their next unlock.
+		///
+		/// Only SAFTs of liquid assets are on the agenda, the vested units of all
+		/// other SAFTs are converted together with their asset.
+		///
+		/// Returns the consumed weight:
+		///
+		/// `Storage: UnlockAgenda (r:1 w:1) + (ActiveSAFTs (r1 w1) + SAFTNetAssetValue (r1 w1) +
+		/// ConvertedSAFTs (r1 w1) + AssetIndex Assets (r1) + Tokens Accounts (r1 w1) + UnlockAgenda
+		/// (r1 w1)) * len(agenda)`
+		fn process_unlocks(now: BlockNumberFor<T>) -> Weight {
+			let agenda = UnlockAgenda::<T>::take(now);
+			let weight = T::DbWeight::get()
+				.reads_writes(1, 1)
+				.saturating_add(T::DbWeight::get().reads_writes(6, 5).saturating_mul(agenda.len() as Weight));
+
+			for (asset_id, saft_id) in agenda {
+				if let Err(err) = Self::convert_saft(asset_id, saft_id, now) {
+					log::error!("Failed to convert vested SAFT {:?} of asset {:?}: {:?}", saft_id, asset_id, err);
+				}
+				let next_unlock = ActiveSAFTs::<T>::get(asset_id, saft_id)
+					.and_then(|saft| saft.vesting)
+					.and_then(|vesting| vesting.next_unlock(now));
+				if let Some(next_unlock) = next_unlock {
+					UnlockAgenda::<T>::append(next_unlock, (asset_id, saft_id));
+				}
+			}
+			weight
+		}
+
+		/// Returns the active SAFTs of the asset together with their totals and
+		/// the share of the asset in the NAV of all SAFTs
+		pub fn asset_safts(asset_id: T::AssetId) -> AssetSAFTs<T::AssetId, T::Balance, T::BlockNumber> {
+			Self::asset_safts_with_total(asset_id, Self::total_saft_nav())
+		}
+
+		/// Returns the active SAFTs of all assets that have active SAFTs
+		pub fn all_asset_safts() -> Vec<AssetSAFTs<T::AssetId, T::Balance, T::BlockNumber>> {
+			let total_saft_nav = Self::total_saft_nav();
+			SAFTCounter::<T>::iter_keys()
+				.map(|asset_id| Self::asset_safts_with_total(asset_id, total_saft_nav))
+				.filter(|asset_safts| !asset_safts.safts.is_empty())
+				.collect()
+		}
+
+		/// The sum of the NAV of the SAFTs of all assets
+		fn total_saft_nav() -> u128 {
+			SAFTNetAssetValue::<T>::iter_values()
+				.fold(T::Balance::zero(), |total, nav| total.saturating_add(nav))
+				.saturated_into()
+		}
+
+		fn asset_safts_with_total(
+			asset_id: T::AssetId,
+			total_saft_nav: u128,
+		) -> AssetSAFTs<T::AssetId, T::Balance, T::BlockNumber> {
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut safts: Vec<_> = ActiveSAFTs::<T>::iter_prefix(asset_id)
+				.map(|(saft_id, saft)| {
+					let reported_at = LastReported::<T>::get(asset_id, saft_id).map(|report| report.reported_at);
+					SAFTInfo {
+						saft_id,
+						nav: saft.nav,
+						units: saft.units,
+						vesting: saft.vesting,
+						reported_at,
+						stale: Self::is_stale(reported_at, now),
+					}
+				})
+				.collect();
+			safts.sort_by_key(|saft| saft.saft_id);
+
+			let total_nav = safts.iter().fold(T::Balance::zero(), |total, saft| total.saturating_add(saft.nav));
+			let total_units = safts.iter().fold(T::Balance::zero(), |total, saft| total.saturating_add(saft.units));
+			let saft_nav_share =
+				Ratio::checked_from_rational(total_nav.saturated_into::<u128>(), total_saft_nav).unwrap_or_default();
+
+			AssetSAFTs { asset: asset_id, safts, total_nav, total_units, saft_nav_share }
+		}
+
+		/// Returns the upcoming unlocks of all SAFTs of the given asset
+		pub fn upcoming_unlocks(asset_id: T::AssetId) -> Vec<SAFTUnlock<T::BlockNumber, T::Balance>> {
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut unlocks: Vec<_> = ActiveSAFTs::<T>::iter_prefix(asset_id)
+				.filter_map(|(saft_id, saft)| {
+					let vesting = saft.vesting?;
+					let block = vesting.next_unlock(now)?;
+					let units = vesting.locked_at(now).saturating_sub(vesting.locked_at(block));
+					Some(SAFTUnlock { saft_id, block, units })
+				})
+				.collect();
+			unlocks.sort_by_key(|unlock| (unlock.block, unlock.saft_id));
+			unlocks
+		}
 	}
 
 	// implementation of NAV reporting for SAFT records
@@ -373,6 +943,11 @@ pub mod pallet {
 		fn remove_saft() -> Weight;
 		fn report_nav() -> Weight;
 		fn convert_to_liquid(_: u32) -> Weight;
+		fn add_vesting_saft() -> Weight;
+		fn attest_nav() -> Weight;
+		fn add_attestor() -> Weight;
+		fn remove_attestor() -> Weight;
+		fn convert_safts_to_liquid(_: u32) -> Weight;
 	}
 
 	/// For backwards compatibility and tests
@@ -392,5 +967,25 @@ pub mod pallet {
 		fn convert_to_liquid(_: u32) -> Weight {
 			Default::default()
 		}
+
+		fn add_vesting_saft() -> Weight {
+			Default::default()
+		}
+
+		fn attest_nav() -> Weight {
+			Default::default()
+		}
+
+		fn add_attestor() -> Weight {
+			Default::default()
+		}
+
+		fn remove_attestor() -> Weight {
+			Default::default()
+		}
+
+		fn convert_safts_to_liquid(_: u32) -> Weight {
+			Default::default()
+		}
 	}
 }
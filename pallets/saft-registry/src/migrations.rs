@@ -0,0 +1,44 @@
+// Copyright 2021 ChainSafe Systems
+// SPDX-License-Identifier: LGPL-3.0-only
+
+//! Storage migrations of the SAFT registry pallet
+
+use crate::{ActiveSAFTs, Config, Pallet, SAFTRecord};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+
+/// The encoding of a SAFT record before SAFTs could vest
+#[derive(Decode)]
+struct UnvestedSAFTRecord<Balance> {
+	nav: Balance,
+	units: Balance,
+}
+
+/// Migrates the stored SAFT records to records without a vesting schedule,
+/// whose units unlock as soon as their asset is liquid like before.
+pub fn migrate_to_v1<T: Config>() -> Weight {
+	if Pallet::<T>::on_chain_storage_version() >= 1 {
+		return T::DbWeight::get().reads(1);
+	}
+
+	let mut translated: Weight = 0;
+	ActiveSAFTs::<T>::translate::<UnvestedSAFTRecord<T::Balance>, _>(|_, _, record| {
+		translated = translated.saturating_add(1);
+		Some(SAFTRecord::new(record.nav, record.units))
+	});
+	StorageVersion::new(1).put::<Pallet<T>>();
+
+	log::info!("Migrated {} SAFT records", translated);
+	T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+}
+
+/// Runs [`migrate_to_v1`] as part of a runtime upgrade.
+pub struct MigrateToV1<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+	fn on_runtime_upgrade() -> Weight {
+		migrate_to_v1::<T>()
+	}
+}
@@ -19,7 +19,7 @@ use frame_support::{
 };
 use frame_system as system;
 use orml_traits::{parameter_type_with_key, MultiCurrency};
-use pallet_price_feed::{AssetPricePair, Price, PriceFeed};
+use pallet_price_feed::{AssetPricePair, Price, PriceFeed, PriceFeedAge};
 use xcm::v1::MultiLocation;
 
 use primitives::{
@@ -49,6 +49,7 @@ frame_support::construct_runtime!(
 		AssetIndex: pallet_asset_index::{Pallet, Call, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Currency: orml_tokens::{Pallet, Event<T>},
+		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
 	}
 );
 
@@ -128,6 +129,22 @@ parameter_types! {
 	};
 	// No fees for now
 	pub const BaseWithdrawalFee: primitives::fee::FeeRate = primitives::fee::FeeRate{ numerator: 0, denominator: 1_000,};
+	pub const SingleAssetWithdrawalFee: primitives::fee::FeeRate = primitives::fee::FeeRate{ numerator: 0, denominator: 1_000,};
+	pub const SingleAssetRedemptionCap: sp_runtime::Permill = sp_runtime::Permill::from_percent(50);
+	pub const MaxAssetsPerCall: u32 = 2;
+	pub const MaxTargets: u32 = 2;
+	pub const BlocksPerYear: <Test as system::Config>::BlockNumber = 1_000;
+	pub const NavSnapshotInterval: <Test as system::Config>::BlockNumber = 10;
+	pub const MaxNavSnapshots: u32 = 3;
+	pub const MaxNavChange: sp_runtime::Permill = sp_runtime::Permill::from_percent(20);
+	pub const NavChangeWindow: <Test as system::Config>::BlockNumber = 10;
+	pub const MaxPriceAge: <Test as system::Config>::BlockNumber = 100;
+	pub const MaxPriceFeedChecks: u32 = 1;
+	pub const ResumeGracePeriod: <Test as system::Config>::BlockNumber = 10;
+	pub const MaxAttestedNavChange: sp_runtime::Permill = sp_runtime::Permill::from_percent(10);
+	pub const MaxSaftNavAge: <Test as system::Config>::BlockNumber = 100;
+	pub const MaxSaftConversions: u32 = 2;
+	pub const MinAttestationInterval: <Test as system::Config>::BlockNumber = 10;
 }
 
 /// Range of voting period
@@ -164,6 +181,18 @@ impl pallet_asset_index::Config for Test {
 	type PriceFeedBenchmarks = MockPriceFeed;
 	type SaftRegistry = SaftRegistry;
 	type BaseWithdrawalFee = BaseWithdrawalFee;
+	type SingleAssetWithdrawalFee = SingleAssetWithdrawalFee;
+	type SingleAssetRedemptionCap = SingleAssetRedemptionCap;
+	type MaxAssetsPerCall = MaxAssetsPerCall;
+	type MaxTargets = MaxTargets;
+	type BlocksPerYear = BlocksPerYear;
+	type NavSnapshotInterval = NavSnapshotInterval;
+	type MaxNavSnapshots = MaxNavSnapshots;
+	type MaxNavChange = MaxNavChange;
+	type NavChangeWindow = NavChangeWindow;
+	type MaxPriceAge = MaxPriceAge;
+	type MaxPriceFeedChecks = MaxPriceFeedChecks;
+	type ResumeGracePeriod = ResumeGracePeriod;
 	type TreasuryPalletId = TreasuryPalletId;
 	type Event = Event;
 	type StringLimit = StringLimit;
@@ -198,6 +227,13 @@ impl PriceFeed<AssetId> for MockPriceFeed {
 	}
 }
 
+impl PriceFeedAge<AssetId, BlockNumber> for MockPriceFeed {
+	fn price_updated_at(asset: AssetId) -> Result<BlockNumber, DispatchError> {
+		Self::get_price(asset)?;
+		Ok(frame_system::Pallet::<Test>::block_number())
+	}
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 impl PriceFeedBenchmarks<AccountId, AssetId> for MockPriceFeed {
 	fn create_feed(_caller: AccountId, _asset_id: AssetId) -> DispatchResultWithPostInfo {
@@ -236,6 +272,22 @@ impl pallet_saft_registry::Config for Test {
 	type Balance = Balance;
 	type AssetRecorder = AssetIndex;
 	type AssetId = AssetId;
+	type MaxAttestedNavChange = MaxAttestedNavChange;
+	type MaxSaftNavAge = MaxSaftNavAge;
+	type MaxSaftConversions = MaxSaftConversions;
+	type MinAttestationInterval = MinAttestationInterval;
+	type Time = Timestamp;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const MinimumPeriod: u64 = 1000;
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
 	type WeightInfo = ();
 }
 
@@ -273,3 +325,8 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 
 	t
 }
+
+// Get last event
+pub fn last_event() -> Event {
+	frame_system::Pallet::<Test>::events().pop().expect("Event expected").event
+}
@@ -2,20 +2,39 @@
 // SPDX-License-Identifier: LGPL-3.0-only
 
 use crate as pallet;
-use crate::{mock::*, SAFTRecord};
-use frame_support::{assert_noop, assert_ok};
+use crate::{mock::*, AssetSAFTs, ConvertedSAFT, NavReport, SAFTInfo, SAFTRecord, SAFTUnlock, VestingSchedule};
+use frame_support::{
+	assert_noop, assert_ok,
+	storage::unhashed,
+	traits::{GetStorageVersion, OnInitialize, OnRuntimeUpgrade, StorageVersion},
+};
 use primitives::traits::{MultiAssetRegistry, NavProvider};
-use sp_runtime::{traits::BadOrigin, FixedPointNumber};
+use sp_core::H256;
+use sp_runtime::{traits::BadOrigin, FixedPointNumber, FixedU128};
 use xcm::v1::{Junction, Junctions, MultiLocation};
 
 const ASHLEY: AccountId = 0;
 
+fn run_to_block(n: BlockNumber) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		SaftRegistry::on_initialize(System::block_number());
+	}
+}
+
+// 5 units unlock every 10 blocks
+fn vesting_schedule() -> VestingSchedule<BlockNumber, Balance> {
+	VestingSchedule { start: 0, period: 10, period_count: 4, per_period: 5 }
+}
+
 #[test]
 fn non_admin_cannot_call_any_extrinsics() {
 	new_test_ext().execute_with(|| {
 		assert_noop!(SaftRegistry::add_saft(Origin::signed(ASHLEY), SAFT_ASSET_ID, 0, 0), BadOrigin);
 		assert_noop!(SaftRegistry::remove_saft(Origin::signed(ASHLEY), SAFT_ASSET_ID, 0), BadOrigin);
 		assert_noop!(SaftRegistry::report_nav(Origin::signed(ASHLEY), SAFT_ASSET_ID, 0, 0), BadOrigin);
+		assert_noop!(SaftRegistry::add_attestor(Origin::signed(ASHLEY), ASHLEY), BadOrigin);
+		assert_noop!(SaftRegistry::remove_attestor(Origin::signed(ASHLEY), ASHLEY), BadOrigin);
 	});
 }
 
@@ -150,6 +169,161 @@ fn admin_cannot_update_or_remove_invalid_index() {
 	});
 }
 
+#[test]
+fn attestors_can_report_nav_within_bounds() {
+	new_test_ext().execute_with(|| {
+		let documentation = H256::repeat_byte(1);
+		assert_ok!(SaftRegistry::add_saft(Origin::signed(ADMIN_ACCOUNT_ID), SAFT_ASSET_ID, 100, 20));
+		assert_noop!(
+			SaftRegistry::attest_nav(Origin::signed(ASHLEY), SAFT_ASSET_ID, 0, 105, 1_000, documentation),
+			pallet::Error::<Test>::NotAttestor
+		);
+
+		assert_ok!(SaftRegistry::add_attestor(Origin::signed(ADMIN_ACCOUNT_ID), ASHLEY));
+		assert_noop!(
+			SaftRegistry::add_attestor(Origin::signed(ADMIN_ACCOUNT_ID), ASHLEY),
+			pallet::Error::<Test>::AlreadyAttestor
+		);
+
+		// at most once every `MinAttestationInterval` blocks
+		assert_noop!(
+			SaftRegistry::attest_nav(Origin::signed(ASHLEY), SAFT_ASSET_ID, 0, 105, 1_000, documentation),
+			pallet::Error::<Test>::NavReportTooFrequent
+		);
+		System::set_block_number(MinAttestationInterval::get());
+		Timestamp::set_timestamp(3_000);
+
+		// at most 10% per report
+		assert_noop!(
+			SaftRegistry::attest_nav(Origin::signed(ASHLEY), SAFT_ASSET_ID, 0, 111, 1_000, documentation),
+			pallet::Error::<Test>::NavChangeTooLarge
+		);
+		assert_noop!(
+			SaftRegistry::attest_nav(Origin::signed(ASHLEY), SAFT_ASSET_ID, 0, 89, 1_000, documentation),
+			pallet::Error::<Test>::NavChangeTooLarge
+		);
+		assert_ok!(SaftRegistry::attest_nav(Origin::signed(ASHLEY), SAFT_ASSET_ID, 0, 110, 1_000, documentation));
+		assert_eq!(SaftRegistry::active_safts(SAFT_ASSET_ID, 0), Some(SAFTRecord::new(110, 20)));
+		assert_eq!(SaftRegistry::saft_nav(SAFT_ASSET_ID), 110);
+
+		// reports must be newer than the last one, but not in the future
+		System::set_block_number(2 * MinAttestationInterval::get());
+		assert_noop!(
+			SaftRegistry::attest_nav(Origin::signed(ASHLEY), SAFT_ASSET_ID, 0, 100, 4_000, documentation),
+			pallet::Error::<Test>::FutureNavReport
+		);
+		assert_noop!(
+			SaftRegistry::attest_nav(Origin::signed(ASHLEY), SAFT_ASSET_ID, 0, 100, 1_000, documentation),
+			pallet::Error::<Test>::OutdatedNavReport
+		);
+		assert_ok!(SaftRegistry::attest_nav(Origin::signed(ASHLEY), SAFT_ASSET_ID, 0, 100, 2_000, documentation));
+		assert_eq!(SaftRegistry::saft_nav(SAFT_ASSET_ID), 100);
+
+		assert_ok!(SaftRegistry::remove_attestor(Origin::signed(ADMIN_ACCOUNT_ID), ASHLEY));
+		assert_noop!(
+			SaftRegistry::attest_nav(Origin::signed(ASHLEY), SAFT_ASSET_ID, 0, 100, 3_000, documentation),
+			pallet::Error::<Test>::NotAttestor
+		);
+		assert_noop!(
+			SaftRegistry::remove_attestor(Origin::signed(ADMIN_ACCOUNT_ID), ASHLEY),
+			pallet::Error::<Test>::NotAttestor
+		);
+	});
+}
+
+#[test]
+fn tracks_last_reported_nav() {
+	new_test_ext().execute_with(|| {
+		let documentation = H256::repeat_byte(1);
+		System::set_block_number(1);
+		assert_ok!(SaftRegistry::add_saft(Origin::signed(ADMIN_ACCOUNT_ID), SAFT_ASSET_ID, 100, 20));
+		assert_ok!(SaftRegistry::add_saft(Origin::signed(ADMIN_ACCOUNT_ID), SAFT_ASSET_ID, 100, 20));
+		assert_eq!(
+			SaftRegistry::last_reported(SAFT_ASSET_ID, 0),
+			Some(NavReport { reporter: ADMIN_ACCOUNT_ID, reported_at: 1, timestamp: None, documentation: None })
+		);
+
+		assert_ok!(SaftRegistry::add_attestor(Origin::signed(ADMIN_ACCOUNT_ID), ASHLEY));
+		System::set_block_number(50);
+		Timestamp::set_timestamp(1_000);
+		assert_ok!(SaftRegistry::attest_nav(Origin::signed(ASHLEY), SAFT_ASSET_ID, 0, 100, 1_000, documentation));
+		assert_eq!(
+			SaftRegistry::last_reported(SAFT_ASSET_ID, 0),
+			Some(NavReport {
+				reporter: ASHLEY,
+				reported_at: 50,
+				timestamp: Some(1_000),
+				documentation: Some(documentation)
+			})
+		);
+
+		// the second SAFT was not reported for more than `MaxSaftNavAge` blocks
+		System::set_block_number(1 + MaxSaftNavAge::get());
+		assert!(SaftRegistry::stale_safts(SAFT_ASSET_ID).is_empty());
+		System::set_block_number(2 + MaxSaftNavAge::get());
+		assert_eq!(SaftRegistry::stale_safts(SAFT_ASSET_ID), vec![1]);
+		let staleness: Vec<_> =
+			SaftRegistry::asset_safts(SAFT_ASSET_ID).safts.into_iter().map(|saft| (saft.saft_id, saft.stale)).collect();
+		assert_eq!(staleness, vec![(0, false), (1, true)]);
+
+		assert_ok!(SaftRegistry::report_nav(Origin::signed(ADMIN_ACCOUNT_ID), SAFT_ASSET_ID, 1, 100));
+		assert!(SaftRegistry::stale_safts(SAFT_ASSET_ID).is_empty());
+
+		// removed SAFTs are no longer tracked
+		assert_ok!(SaftRegistry::remove_saft(Origin::signed(ADMIN_ACCOUNT_ID), SAFT_ASSET_ID, 1));
+		assert_eq!(SaftRegistry::last_reported(SAFT_ASSET_ID, 1), None);
+	});
+}
+
+#[test]
+fn can_query_asset_safts() {
+	const OTHER_SAFT_ASSET_ID: AssetId = 44;
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(SaftRegistry::all_asset_safts(), vec![]);
+
+		assert_ok!(SaftRegistry::add_saft(Origin::signed(ADMIN_ACCOUNT_ID), SAFT_ASSET_ID, 100, 20));
+		assert_ok!(SaftRegistry::add_vesting_saft(
+			Origin::signed(ADMIN_ACCOUNT_ID),
+			SAFT_ASSET_ID,
+			200,
+			20,
+			vesting_schedule()
+		));
+		assert_ok!(SaftRegistry::add_saft(Origin::signed(ADMIN_ACCOUNT_ID), OTHER_SAFT_ASSET_ID, 100, 10));
+
+		let asset_safts = AssetSAFTs {
+			asset: SAFT_ASSET_ID,
+			safts: vec![
+				SAFTInfo { saft_id: 0, nav: 100, units: 20, vesting: None, reported_at: Some(1), stale: false },
+				SAFTInfo {
+					saft_id: 1,
+					nav: 200,
+					units: 20,
+					vesting: Some(vesting_schedule()),
+					reported_at: Some(1),
+					stale: false,
+				},
+			],
+			total_nav: 300,
+			total_units: 40,
+			saft_nav_share: FixedU128::saturating_from_rational(3, 4),
+		};
+		assert_eq!(SaftRegistry::asset_safts(SAFT_ASSET_ID), asset_safts);
+
+		let mut all_asset_safts = SaftRegistry::all_asset_safts();
+		all_asset_safts.sort_by_key(|asset_safts| asset_safts.asset);
+		assert_eq!(all_asset_safts.len(), 2);
+		assert_eq!(all_asset_safts[0], asset_safts);
+		assert_eq!(all_asset_safts[1].saft_nav_share, FixedU128::saturating_from_rational(1, 4));
+
+		// assets without SAFTs are omitted
+		assert_ok!(SaftRegistry::remove_saft(Origin::signed(ADMIN_ACCOUNT_ID), OTHER_SAFT_ASSET_ID, 0));
+		assert_eq!(SaftRegistry::all_asset_safts(), vec![SaftRegistry::asset_safts(SAFT_ASSET_ID)]);
+		assert_eq!(SaftRegistry::asset_safts(SAFT_ASSET_ID).saft_nav_share, FixedU128::saturating_from_integer(1));
+	});
+}
+
 #[test]
 fn can_convert_to_liquid() {
 	new_test_ext().execute_with(|| {
@@ -162,9 +336,272 @@ fn can_convert_to_liquid() {
 		assert_ok!(SaftRegistry::convert_to_liquid(Origin::signed(ADMIN_ACCOUNT_ID), SAFT_ASSET_ID, location.clone()));
 		assert_eq!(AssetIndex::native_asset_location(&SAFT_ASSET_ID), Some(location));
 
-		// everything is reset and purged
-		assert_eq!(SaftRegistry::saft_counter(SAFT_ASSET_ID), 0);
+		// the record is archived
+		assert_eq!(SaftRegistry::saft_counter(SAFT_ASSET_ID), 1);
+		assert_eq!(SaftRegistry::saft_nav(SAFT_ASSET_ID), 0);
+		assert_eq!(SaftRegistry::active_safts(SAFT_ASSET_ID, 0), None);
+		assert_eq!(
+			SaftRegistry::converted_safts(SAFT_ASSET_ID, 0),
+			Some(ConvertedSAFT { nav: 100, units: 20, converted_at: 0 })
+		);
+	});
+}
+
+#[test]
+fn vesting_saft_requires_matching_schedule() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			SaftRegistry::add_vesting_saft(
+				Origin::signed(ADMIN_ACCOUNT_ID),
+				SAFT_ASSET_ID,
+				100,
+				21,
+				vesting_schedule()
+			),
+			pallet::Error::<Test>::InvalidVestingSchedule
+		);
+		assert_noop!(
+			SaftRegistry::add_vesting_saft(
+				Origin::signed(ADMIN_ACCOUNT_ID),
+				SAFT_ASSET_ID,
+				100,
+				0,
+				VestingSchedule { period_count: 0, ..vesting_schedule() }
+			),
+			pallet::Error::<Test>::InvalidVestingSchedule
+		);
+	});
+}
+
+#[test]
+fn can_query_upcoming_unlocks() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(SaftRegistry::add_saft(Origin::signed(ADMIN_ACCOUNT_ID), SAFT_ASSET_ID, 100, 20));
+		assert_ok!(SaftRegistry::add_vesting_saft(
+			Origin::signed(ADMIN_ACCOUNT_ID),
+			SAFT_ASSET_ID,
+			100,
+			20,
+			vesting_schedule()
+		));
+		assert_eq!(SaftRegistry::upcoming_unlocks(SAFT_ASSET_ID), vec![SAFTUnlock { saft_id: 1, block: 10, units: 5 }]);
+
+		run_to_block(35);
+		assert_eq!(SaftRegistry::upcoming_unlocks(SAFT_ASSET_ID), vec![SAFTUnlock { saft_id: 1, block: 40, units: 5 }]);
+
+		run_to_block(40);
+		assert!(SaftRegistry::upcoming_unlocks(SAFT_ASSET_ID).is_empty());
+	});
+}
+
+#[test]
+fn converting_to_liquid_keeps_unvested_units_locked() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(SaftRegistry::add_saft(Origin::signed(ADMIN_ACCOUNT_ID), SAFT_ASSET_ID, 100, 20));
+		assert_ok!(SaftRegistry::add_vesting_saft(
+			Origin::signed(ADMIN_ACCOUNT_ID),
+			SAFT_ASSET_ID,
+			100,
+			20,
+			vesting_schedule()
+		));
+		run_to_block(15);
+
+		let location: MultiLocation = MultiLocation { parents: 1, interior: Junctions::X1(Junction::Parachain(100)) };
+		assert_ok!(SaftRegistry::convert_to_liquid(Origin::signed(ADMIN_ACCOUNT_ID), SAFT_ASSET_ID, location));
+		assert!(AssetIndex::is_liquid_asset(&SAFT_ASSET_ID));
+
+		// the SAFT without schedule and the first unlock of the vesting SAFT are liquid
+		assert_eq!(SaftRegistry::active_safts(SAFT_ASSET_ID, 0), None);
+		assert_eq!(
+			SaftRegistry::active_safts(SAFT_ASSET_ID, 1),
+			Some(SAFTRecord::new(75, 15).with_vesting(vesting_schedule()))
+		);
+		assert_eq!(SaftRegistry::saft_counter(SAFT_ASSET_ID), 2);
+		assert_eq!(SaftRegistry::saft_nav(SAFT_ASSET_ID), 75);
+		assert_eq!(AssetIndex::asset_balance(SAFT_ASSET_ID), 25);
+		assert_eq!(pallet_asset_index::LockedSaftUnits::<Test>::get(SAFT_ASSET_ID), 15);
+
+		// the next unlock moves more units into the liquid holdings
+		run_to_block(20);
+		assert_eq!(
+			SaftRegistry::active_safts(SAFT_ASSET_ID, 1),
+			Some(SAFTRecord::new(50, 10).with_vesting(vesting_schedule()))
+		);
+		assert_eq!(SaftRegistry::saft_nav(SAFT_ASSET_ID), 50);
+		assert_eq!(AssetIndex::asset_balance(SAFT_ASSET_ID), 30);
+		assert_eq!(pallet_asset_index::LockedSaftUnits::<Test>::get(SAFT_ASSET_ID), 10);
+		assert_eq!(last_event(), Event::SaftRegistry(pallet::Event::SAFTConverted(SAFT_ASSET_ID, 1, 5, 25)));
+
+		// the last unlock removes the record
+		run_to_block(40);
+		assert_eq!(SaftRegistry::active_safts(SAFT_ASSET_ID, 1), None);
+		assert_eq!(SaftRegistry::saft_nav(SAFT_ASSET_ID), 0);
+		assert_eq!(AssetIndex::asset_balance(SAFT_ASSET_ID), 40);
+		assert_eq!(pallet_asset_index::LockedSaftUnits::<Test>::get(SAFT_ASSET_ID), 0);
+	});
+}
+
+#[test]
+fn can_convert_single_safts_to_liquid() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(SaftRegistry::add_saft(Origin::signed(ADMIN_ACCOUNT_ID), SAFT_ASSET_ID, 100, 20));
+		assert_ok!(SaftRegistry::add_saft(Origin::signed(ADMIN_ACCOUNT_ID), SAFT_ASSET_ID, 300, 30));
+		run_to_block(5);
+
+		let location: MultiLocation = MultiLocation { parents: 1, interior: Junctions::X1(Junction::Parachain(100)) };
+		assert_noop!(
+			SaftRegistry::convert_safts_to_liquid(
+				Origin::signed(ADMIN_ACCOUNT_ID),
+				SAFT_ASSET_ID,
+				location.clone(),
+				vec![2]
+			),
+			pallet::Error::<Test>::SAFTNotFound
+		);
+		assert_ok!(SaftRegistry::convert_safts_to_liquid(
+			Origin::signed(ADMIN_ACCOUNT_ID),
+			SAFT_ASSET_ID,
+			location.clone(),
+			vec![0]
+		));
+
+		// the asset is liquid but still partially secured by the remaining SAFT
+		assert!(AssetIndex::is_liquid_asset(&SAFT_ASSET_ID));
+		assert_eq!(SaftRegistry::active_safts(SAFT_ASSET_ID, 0), None);
+		assert_eq!(SaftRegistry::active_safts(SAFT_ASSET_ID, 1), Some(SAFTRecord::new(300, 30)));
+		assert_eq!(SaftRegistry::saft_nav(SAFT_ASSET_ID), 300);
+		assert_eq!(AssetIndex::asset_balance(SAFT_ASSET_ID), 20);
+		assert_eq!(
+			SaftRegistry::converted_safts(SAFT_ASSET_ID, 0),
+			Some(ConvertedSAFT { nav: 100, units: 20, converted_at: 5 })
+		);
+		assert_eq!(last_event(), Event::SaftRegistry(pallet::Event::SAFTConverted(SAFT_ASSET_ID, 0, 20, 100)));
+
+		// the location of the liquid asset can not change
+		let other_location: MultiLocation =
+			MultiLocation { parents: 1, interior: Junctions::X1(Junction::Parachain(200)) };
+		assert_noop!(
+			SaftRegistry::convert_safts_to_liquid(
+				Origin::signed(ADMIN_ACCOUNT_ID),
+				SAFT_ASSET_ID,
+				other_location,
+				vec![1]
+			),
+			pallet::Error::<Test>::LocationMismatch
+		);
+
+		// converting the last SAFT leaves a liquid asset
+		run_to_block(8);
+		assert_ok!(SaftRegistry::convert_safts_to_liquid(
+			Origin::signed(ADMIN_ACCOUNT_ID),
+			SAFT_ASSET_ID,
+			location,
+			vec![1]
+		));
+		assert_eq!(SaftRegistry::active_safts(SAFT_ASSET_ID, 1), None);
+		assert_eq!(SaftRegistry::saft_nav(SAFT_ASSET_ID), 0);
+		assert_eq!(AssetIndex::asset_balance(SAFT_ASSET_ID), 50);
+		assert_eq!(
+			SaftRegistry::converted_safts(SAFT_ASSET_ID, 1),
+			Some(ConvertedSAFT { nav: 300, units: 30, converted_at: 8 })
+		);
+	});
+}
+
+#[test]
+fn converting_safts_rejects_duplicate_and_too_many_ids() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(SaftRegistry::add_saft(Origin::signed(ADMIN_ACCOUNT_ID), SAFT_ASSET_ID, 100, 20));
+		assert_ok!(SaftRegistry::add_saft(Origin::signed(ADMIN_ACCOUNT_ID), SAFT_ASSET_ID, 300, 30));
+
+		let location: MultiLocation = MultiLocation { parents: 1, interior: Junctions::X1(Junction::Parachain(100)) };
+		assert_noop!(
+			SaftRegistry::convert_safts_to_liquid(
+				Origin::signed(ADMIN_ACCOUNT_ID),
+				SAFT_ASSET_ID,
+				location.clone(),
+				vec![0, 0]
+			),
+			pallet::Error::<Test>::DuplicateSAFTConversion
+		);
+		assert_noop!(
+			SaftRegistry::convert_safts_to_liquid(
+				Origin::signed(ADMIN_ACCOUNT_ID),
+				SAFT_ASSET_ID,
+				location,
+				vec![0, 1, 2]
+			),
+			pallet::Error::<Test>::TooManySAFTConversions
+		);
+	});
+}
+
+#[test]
+fn can_remove_unconverted_safts_of_liquid_asset() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(SaftRegistry::add_saft(Origin::signed(ADMIN_ACCOUNT_ID), SAFT_ASSET_ID, 100, 20));
+		assert_ok!(SaftRegistry::add_saft(Origin::signed(ADMIN_ACCOUNT_ID), SAFT_ASSET_ID, 300, 30));
+
+		let location: MultiLocation = MultiLocation { parents: 1, interior: Junctions::X1(Junction::Parachain(100)) };
+		assert_ok!(SaftRegistry::convert_safts_to_liquid(
+			Origin::signed(ADMIN_ACCOUNT_ID),
+			SAFT_ASSET_ID,
+			location,
+			vec![0]
+		));
+		assert_eq!(pallet_asset_index::LockedSaftUnits::<Test>::get(SAFT_ASSET_ID), 30);
+
+		// the locked units of the remaining SAFT are burned with it
+		assert_ok!(SaftRegistry::remove_saft(Origin::signed(ADMIN_ACCOUNT_ID), SAFT_ASSET_ID, 1));
+		assert_eq!(SaftRegistry::active_safts(SAFT_ASSET_ID, 1), None);
 		assert_eq!(SaftRegistry::saft_nav(SAFT_ASSET_ID), 0);
+		assert_eq!(pallet_asset_index::LockedSaftUnits::<Test>::get(SAFT_ASSET_ID), 0);
+		assert_eq!(AssetIndex::index_total_asset_balance(SAFT_ASSET_ID), 20);
+	});
+}
+
+#[test]
+fn vested_units_are_archived() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(SaftRegistry::add_vesting_saft(
+			Origin::signed(ADMIN_ACCOUNT_ID),
+			SAFT_ASSET_ID,
+			100,
+			20,
+			vesting_schedule()
+		));
+		let location: MultiLocation = MultiLocation { parents: 1, interior: Junctions::X1(Junction::Parachain(100)) };
+		assert_ok!(SaftRegistry::convert_to_liquid(Origin::signed(ADMIN_ACCOUNT_ID), SAFT_ASSET_ID, location));
+		assert_eq!(SaftRegistry::converted_safts(SAFT_ASSET_ID, 0), None);
+
+		run_to_block(10);
+		assert_eq!(
+			SaftRegistry::converted_safts(SAFT_ASSET_ID, 0),
+			Some(ConvertedSAFT { nav: 25, units: 5, converted_at: 10 })
+		);
+
+		run_to_block(40);
 		assert_eq!(SaftRegistry::active_safts(SAFT_ASSET_ID, 0), None);
+		assert_eq!(
+			SaftRegistry::converted_safts(SAFT_ASSET_ID, 0),
+			Some(ConvertedSAFT { nav: 100, units: 20, converted_at: 40 })
+		);
+	});
+}
+
+#[test]
+fn saft_records_are_migrated_to_records_without_vesting() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<SaftRegistry>();
+
+		// a record stored before SAFTs could vest
+		let legacy: (Balance, Balance) = (100, 20);
+		unhashed::put(&pallet::ActiveSAFTs::<Test>::hashed_key_for(SAFT_ASSET_ID, 0), &legacy);
+
+		crate::migrations::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(SaftRegistry::active_safts(SAFT_ASSET_ID, 0), Some(SAFTRecord::new(100, 20)));
+		assert_eq!(SaftRegistry::on_chain_storage_version(), 1);
 	});
 }
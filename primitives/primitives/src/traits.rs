@@ -277,7 +277,19 @@ pub trait AssetRecorder<AccountId, AssetId, Balance> {
 
 	/// Burns the given amount of SAFT token from the index and
 	/// the nav from the caller's account
+	///
+	/// If the asset is liquid already, the units are burned from the units
+	/// locked with `lock_saft_units`.
 	fn remove_saft(who: &AccountId, id: AssetId, units: Balance, nav: Balance) -> DispatchResult;
+
+	/// Reserves units of a liquid asset held by the index that are still
+	/// secured by a SAFT, so that they are neither valued as liquid nor can be
+	/// redeemed until they vest.
+	fn lock_saft_units(id: AssetId, units: Balance) -> DispatchResult;
+
+	/// Releases units previously locked with `lock_saft_units` into the
+	/// liquid holdings of the index.
+	fn unlock_saft_units(id: AssetId, units: Balance) -> DispatchResult;
 }
 
 /// Helper trait for runtime benchmarks
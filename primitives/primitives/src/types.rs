@@ -8,8 +8,9 @@ use frame_support::{
 	sp_runtime::{
 		app_crypto::sp_core,
 		generic,
-		traits::{BlakeTwo256, IdentifyAccount, Verify},
+		traits::{AtLeast32BitUnsigned, BlakeTwo256, IdentifyAccount, Verify},
 		FixedPointNumber, FixedPointOperand, FixedU128, MultiSignature, OpaqueExtrinsic as UncheckedExtrinsic,
+		SaturatedConversion,
 	},
 	sp_std::vec::Vec,
 };
@@ -104,6 +105,7 @@ impl From<MultiLocation> for AssetAvailability {
 }
 
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct AssetProportions<AssetId> {
 	/// The per token value used to calculate proportions
 	pub nav: Price,
@@ -113,6 +115,7 @@ pub struct AssetProportions<AssetId> {
 
 /// Represents an asset and its proportion in the value of the index
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct AssetProportion<AssetId> {
 	/// The identifier for the asset
 	pub asset: AssetId,
@@ -197,6 +200,289 @@ impl<AssetId> AssetPricePair<AssetId> {
 	}
 }
 
+/// Represents a single asset being withdrawn
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct AssetWithdrawal<AssetId, Balance> {
+	/// The identifier of the asset
+	pub asset: AssetId,
+	/// The amount of asset units about to be transferred to the LP.
+	pub units: Balance,
+	/// The amount still reserved for this withdrawal.
+	pub reserved: Balance,
+	/// Whether this withdrawal was already been closed.
+	pub withdrawn: bool,
+}
+
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+/// Describes an in progress withdrawal of a collection of assets from the index
+pub struct PendingRedemption<AssetId, Balance, BlockNumber> {
+	/// The block after which the redemption process is over.
+	pub end_block: BlockNumber,
+	/// All the withdrawals resulted from the redemption.
+	pub assets: Vec<AssetWithdrawal<AssetId, Balance>>,
+}
+
+/// The outcome of a `withdraw` of a given amount of index tokens.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct WithdrawalQuote<AssetId, Balance, BlockNumber> {
+	/// The fee charged for every withdrawal, `BaseWithdrawalFee`
+	pub base_fee: Balance,
+	/// The fee depending on how long the redeemed deposits remained in the index
+	pub redemption_fee: Balance,
+	/// The additional fee if the index tokens are redeemed for a single asset
+	pub single_asset_fee: Balance,
+	/// All the liquid assets together with the units the LP receives
+	pub asset_amounts: Vec<(AssetId, Balance)>,
+	/// The amount of index tokens that are effectively withdrawn from the LP, including all fees
+	pub effectively_withdrawn: Balance,
+	/// The block after which the redemption process is over.
+	pub end_block: BlockNumber,
+}
+
+/// A record of the index token's `NAV` at a specific block.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct NavSnapshot<BlockNumber> {
+	/// The block at which the snapshot was taken
+	pub block: BlockNumber,
+	/// The per token value of the index token
+	pub nav: Price,
+	/// The per token value of the index token for the liquid assets only
+	pub liquid_nav: Price,
+	/// The per token value of the index token for the SAFTs only
+	pub saft_nav: Price,
+}
+
+/// Why deposits and withdrawals of the index are paused.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum PauseReason<AssetId> {
+	/// The `NAV` moved more than `MaxNavChange` within `NavChangeWindow` blocks.
+	NavDeviation {
+		/// The `NAV` at the start of the window
+		reference: Price,
+		/// The `NAV` that triggered the circuit breaker
+		current: Price,
+	},
+	/// The price feed of the liquid asset was not updated for more than `MaxPriceAge` blocks.
+	StalePriceFeed(AssetId),
+	/// The admin origin paused deposits and withdrawals, e.g. with an emergency proposal.
+	Manual,
+}
+
+/// The trades required to move the liquid assets of the index back to their target weights.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct RebalancePlan<AssetId, Balance> {
+	/// The assets that exceed their target weight together with the units to sell
+	pub sells: Vec<(AssetId, Balance)>,
+	/// The assets that fall short of their target weight together with the units to buy
+	pub buys: Vec<(AssetId, Balance)>,
+}
+
+impl<AssetId, Balance> RebalancePlan<AssetId, Balance> {
+	/// Whether the index is balanced and no trades are required
+	pub fn is_empty(&self) -> bool {
+		self.sells.is_empty() && self.buys.is_empty()
+	}
+}
+
+impl<AssetId, Balance> Default for RebalancePlan<AssetId, Balance> {
+	fn default() -> Self {
+		Self { sells: Vec::new(), buys: Vec::new() }
+	}
+}
+
+/// The state of a committee proposal
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum ProposalStatus {
+	Active,
+	Executed,
+	Timeout,
+	/// The proposal was withdrawn by its issuer before voting started, or
+	/// cancelled by the committee while it was queued
+	Cancelled,
+	/// The proposal was accepted and waits for its enactment delay to pass
+	Queued,
+}
+
+/// Human readable information about a proposal, so that members know what
+/// they are voting on without decoding its actions
+#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProposalMetadata<BoundedString> {
+	/// A short title of the proposal
+	pub title: BoundedString,
+	/// The URI or content hash of the full description of the proposal, e.g.
+	/// a link to its discussion or an IPFS CID
+	pub description: BoundedString,
+}
+
+/// Identifies a dispatchable by the index of its pallet in the runtime and
+/// the index of the call within that pallet: `(pallet_index, call_index)`
+pub type CallIndex = (u8, u8);
+
+/// The number, or the summed weight, of votes of each kind cast by one type of
+/// member
+#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct VoteCount<Count = u32> {
+	pub ayes: Count,
+	pub nays: Count,
+	pub abstentions: Count,
+}
+
+/// The live vote totals of a proposal, split by member type
+#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct VoteTally {
+	pub council: VoteCount,
+	pub constituents: VoteCount,
+	/// The constituent votes weighted by the constituent weights of the voting
+	/// period, used for the constituent veto
+	pub constituent_weights: VoteCount<u128>,
+}
+
+/// A proposed action in a form that can be displayed without decoding the
+/// runtime call
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProposedAction {
+	/// The call index of the action, used to look up its approval threshold
+	pub call_index: CallIndex,
+	/// The name of the pallet the action is dispatched to
+	pub pallet_name: Vec<u8>,
+	/// The name of the dispatched function
+	pub function_name: Vec<u8>,
+	/// The SCALE encoded action, including its arguments
+	pub encoded: Vec<u8>,
+}
+
+/// The state of a proposal as exposed by the runtime API
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProposalInfo<AccountId, Hash, BlockNumber> {
+	pub hash: Hash,
+	pub issuer: AccountId,
+	pub status: ProposalStatus,
+	pub metadata: ProposalMetadata<Vec<u8>>,
+	pub actions: Vec<ProposedAction>,
+	/// The block at which the voting period of the proposal ends
+	pub end: BlockNumber,
+	/// The current votes, including the votes of delegators
+	pub tally: VoteTally,
+	/// Whether the current votes satisfy the approval threshold of every
+	/// action of the proposal
+	pub accepted: bool,
+}
+
+/// The boundaries of the current proposal submission and voting periods
+///
+/// Every epoch starts with a voting period on the proposals submitted during
+/// the previous epoch, followed by a submission period. Proposals submitted
+/// during the current epoch are voted on from `submission_end` on.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct VotingWindow<BlockNumber> {
+	/// The first block of the current voting period
+	pub voting_start: BlockNumber,
+	/// The first block after the current voting period
+	pub voting_end: BlockNumber,
+	/// The first block of the current submission period
+	pub submission_start: BlockNumber,
+	/// The first block after the current submission period
+	pub submission_end: BlockNumber,
+}
+
+/// Describes when the units of a SAFT unlock
+///
+/// `per_period` units unlock every `period` blocks after `start`, until all
+/// `period_count` unlocks took place.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct VestingSchedule<BlockNumber, Balance> {
+	/// The block the vesting starts at
+	pub start: BlockNumber,
+	/// The number of blocks between two unlocks
+	pub period: BlockNumber,
+	/// The number of unlocks
+	pub period_count: u32,
+	/// The units that unlock with every unlock
+	pub per_period: Balance,
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy, Balance: AtLeast32BitUnsigned + Copy>
+	VestingSchedule<BlockNumber, Balance>
+{
+	/// The total number of units that vest, `None` on overflow
+	pub fn total(&self) -> Option<Balance> {
+		self.per_period.checked_mul(&self.period_count.into())
+	}
+
+	/// The number of unlocks that took place at the given block
+	fn unlocked_periods(&self, now: BlockNumber) -> u32 {
+		if self.period.is_zero() {
+			return self.period_count;
+		}
+		let periods = now.saturating_sub(self.start) / self.period;
+		periods.saturated_into::<u32>().min(self.period_count)
+	}
+
+	/// The units that are still locked at the given block
+	pub fn locked_at(&self, now: BlockNumber) -> Balance {
+		let remaining = self.period_count.saturating_sub(self.unlocked_periods(now));
+		self.per_period.saturating_mul(remaining.into())
+	}
+
+	/// The block of the next unlock after the given block, if any
+	pub fn next_unlock(&self, now: BlockNumber) -> Option<BlockNumber> {
+		let unlocked = self.unlocked_periods(now);
+		if unlocked >= self.period_count {
+			return None;
+		}
+		Some(self.start.saturating_add(self.period.saturating_mul((unlocked + 1).into())))
+	}
+}
+
+/// A SAFT record together with its id, as exposed by the runtime API
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct SAFTInfo<Balance, BlockNumber> {
+	/// The id of the SAFT
+	pub saft_id: SAFTId,
+	/// Net asset value of the SAFT
+	pub nav: Balance,
+	/// How many units of the asset are included in the SAFT
+	pub units: Balance,
+	/// When the units of the SAFT unlock
+	pub vesting: Option<VestingSchedule<BlockNumber, Balance>>,
+	/// The block the NAV of the SAFT was last reported at
+	pub reported_at: Option<BlockNumber>,
+	/// Whether the NAV of the SAFT was not reported within the last
+	/// `MaxSaftNavAge` blocks of the SAFT registry
+	pub stale: bool,
+}
+
+/// The SAFTs of an asset, as exposed by the runtime API
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct AssetSAFTs<AssetId, Balance, BlockNumber> {
+	/// The identifier of the asset
+	pub asset: AssetId,
+	/// All active SAFTs of the asset, ordered by id
+	pub safts: Vec<SAFTInfo<Balance, BlockNumber>>,
+	/// The sum of the NAV of all SAFTs of the asset
+	pub total_nav: Balance,
+	/// The sum of the units of all SAFTs of the asset
+	pub total_units: Balance,
+	/// The share of the asset in the NAV of the SAFTs of all assets
+	pub saft_nav_share: Ratio,
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
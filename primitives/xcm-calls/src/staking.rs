@@ -288,6 +288,8 @@ pub struct StakingWeights {
 	pub unbond: Weight,
 	/// Weight for `withdraw_unbonded` extrinsic
 	pub withdraw_unbonded: Weight,
+	/// Weight for `nominate` extrinsic
+	pub nominate: Weight,
 }
 
 /// Represents all staking related durations required to determine the correct chain-specific
@@ -5,7 +5,7 @@
 
 #![warn(missing_docs)]
 
-use primitives::{AccountId, AssetId, Balance, Block, Nonce};
+use primitives::{AccountId, AssetId, Balance, Block, BlockNumber, Hash, Nonce};
 pub use sc_rpc_api::DenyUnsafe;
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
@@ -36,11 +36,15 @@ where
 	C: Send + Sync + 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
-	C::Api: pallet_asset_index_rpc::AssetIndexRuntimeApi<Block, AccountId, AssetId, Balance>,
+	C::Api: pallet_asset_index_rpc::AssetIndexRuntimeApi<Block, AccountId, AssetId, Balance, BlockNumber>,
+	C::Api: pallet_committee_rpc::CommitteeRuntimeApi<Block, AccountId, Hash, BlockNumber>,
+	C::Api: pallet_saft_registry_rpc::SaftRegistryRuntimeApi<Block, AssetId, Balance, BlockNumber>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + Sync + Send + 'static,
 {
 	use pallet_asset_index_rpc::{AssetIndexApi, AssetIndexBackend};
+	use pallet_committee_rpc::{CommitteeApi, CommitteeBackend};
+	use pallet_saft_registry_rpc::{SaftRegistryApi, SaftRegistryBackend};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApi};
 	use substrate_frame_rpc_system::{FullSystem, SystemApi};
 
@@ -52,6 +56,8 @@ where
 	// Making synchronous calls in light client freezes the browser currently,
 	// more context: https://github.com/paritytech/substrate/pull/3480
 	// These RPCs should use an asynchronous caller instead.
-	io.extend_with(AssetIndexApi::to_delegate(AssetIndexBackend::new(client)));
+	io.extend_with(AssetIndexApi::to_delegate(AssetIndexBackend::new(client.clone())));
+	io.extend_with(SaftRegistryApi::to_delegate(SaftRegistryBackend::new(client.clone())));
+	io.extend_with(CommitteeApi::to_delegate(CommitteeBackend::new(client)));
 	io
 }
@@ -1,9 +1,10 @@
 // Copyright 2021 ChainSafe Systems
 // SPDX-License-Identifier: LGPL-3.0-only
+use crate::traits::XcmRuntimeCallWeights;
 use cumulus_pallet_xcm::Origin;
 use frame_support::{
 	parameter_types,
-	sp_runtime::{traits::AccountIdConversion, Perbill, Permill},
+	sp_runtime::{traits::AccountIdConversion, Perbill, Percent, Permill},
 	sp_std::prelude::*,
 	traits::{Contains, LockIdentifier},
 	weights::{
@@ -14,11 +15,13 @@ use frame_support::{
 };
 use frame_system::limits::{BlockLength, BlockWeights};
 use orml_traits::{arithmetic::Zero, parameter_type_with_key};
+use pallet_committee::{Approval, Quorum, VoteThreshold};
 use primitives::{
 	fee::{FeeRate, RedemptionFeeRange},
 	AccountId, AssetId, Balance, BlockNumber,
 };
 use xcm::v1::MultiLocation;
+use xcm_calls::staking::StakingWeights;
 
 /// Money matters.
 pub use currency::*;
@@ -100,7 +103,12 @@ parameter_types! {
 	// `T::BaseXcmWeight  + T::Weigher::weight(&msg)`
 	pub const BaseXcmWeight: Weight = 100_000_000;
 	pub const BlockHashCount: BlockNumber = 250;
+	pub const BlocksPerYear: BlockNumber = DAYS * 365;
+	pub const CouncilSeats: u32 = 7;
+	// Council terms last for this many voting epochs
+	pub const CouncilTermLength: u32 = 6;
 	pub const MaxActiveDeposits: u32 = 5;
+	pub const MaxCouncilCandidates: u32 = 50;
 	pub const Days: BlockNumber = DAYS;
 	pub const DisabledValidatorsThreshold: Perbill = Perbill::from_percent(33);
 	/// Same as Polkadot Relay Chain.
@@ -110,6 +118,8 @@ parameter_types! {
 	// Maximum number of feeds
 	pub const FeedLimit: u16 = 10;
 	pub const IndexTokenLockIdentifier: LockIdentifier = *b"pintlock";
+	pub const NavChangeWindow: BlockNumber = HOURS;
+	pub const NavSnapshotInterval: BlockNumber = HOURS;
 	pub const Offset: BlockNumber = 0;
 	// Maximum number of oracles per feed
 	pub const OracleLimit: u32 = 10;
@@ -123,8 +133,14 @@ parameter_types! {
 		default_fee: FeeRate { numerator: 1, denominator: 100 }
 	};
 	pub const RelayChainAssetId: AssetId = 42;
+	// Redeeming into a single asset costs an additional 0.5% of the redeemed index tokens
+	pub const SingleAssetWithdrawalFee: FeeRate = FeeRate{ numerator: 5, denominator: 1_000,};
+	pub const SingleAssetRedemptionCap: Permill = Permill::from_percent(10);
+	pub const MaxAssetsPerCall: u32 = 20;
+	pub const MaxTargets: u32 = 50;
 	pub RelayChainOrigin: Origin = cumulus_pallet_xcm::Origin::Relay;
 	pub const RelayLocation: MultiLocation = MultiLocation::parent();
+	pub const ResumeGracePeriod: BlockNumber = HOURS;
 	pub const ReservedXcmpWeight: Weight = MAXIMUM_BLOCK_WEIGHT / 4;
 	pub const ReservedDmpWeight: Weight = MAXIMUM_BLOCK_WEIGHT / 4;
 	pub RuntimeBlockLength: BlockLength =
@@ -159,12 +175,28 @@ parameter_types! {
 	// For weight estimation, we assume that the most locks on an individual account will be 50.
 	// This number may need to be adjusted in the future if this assumption no longer holds true.
 	pub const MaxLocks: u32 = 50;
+	pub const MaxPriceAge: BlockNumber = HOURS;
+	pub const MaxPriceFeedChecks: u32 = 10;
+	// Attestors may move the NAV of a SAFT by at most 10% per report
+	pub const MaxAttestedNavChange: Permill = Permill::from_percent(10);
+	pub const MaxSaftConversions: u32 = 50;
+	pub const MaxSaftNavAge: BlockNumber = 30 * DAYS;
+	pub const MaxNavChange: Permill = Permill::from_percent(20);
+	// Keep hourly NAV snapshots of the last 30 days
+	pub const MaxNavSnapshots: u32 = 24 * 30;
+	// Attestors may report the NAV of a SAFT at most once a day
+	pub const MinAttestationInterval: BlockNumber = DAYS;
 	pub const MinCandidates: u32 = 1;
 	pub const MinCouncilMembers: usize = 4;
 	pub const MinCouncilVotes: usize = 4;
 	pub const MinimumPeriod: u64 = SLOT_DURATION / 2;
 	pub const MinimumRedemption: u32 = 0;
 	pub const AssetUnbondingSlashingSpans: u32 = 5;
+	// `MAX_NOMINATIONS` of `pallet_staking` on polkadot
+	pub const MaxNominations: u32 = 16;
+	// `nominate` weight of the staking configs stored before it was configurable
+	pub MigratedNominateWeight: Weight = StakingWeights::polkadot().nominate;
+	pub const NominationRetryDelay: BlockNumber = 10 * MINUTES;
 	pub const MinimumStatemintTransferAmount: Balance = 1;
 	// Minimum amount of funds that need to be present in the fund account
 	pub const MinimumReserve: Balance = 100;
@@ -183,6 +215,32 @@ parameter_types! {
 	pub const SpendPeriod: BlockNumber = 7 * DAYS;
 	pub const Burn: Permill = Permill::from_percent(0);
 	pub const MaxApprovals: u32 = 100;
+
+	// Committee
+	pub const MaxBatchSize: u32 = 10;
+	pub const ProposalStringLimit: u32 = 256;
+	pub const ProposalDeposit: Balance = 10 * DOLLARS;
+	// Keep finished proposals for two of the longest voting periods
+	pub const ProposalRetentionPeriod: BlockNumber = 56 * DAYS;
+	pub const MaxPrunedProposals: u32 = 50;
+	pub const MaxDelegations: u32 = 1_000;
+	pub const MaxWeightRemovals: u32 = 100;
+	pub const MaxConstituents: u32 = 1_000;
+	pub const MaxEnactmentsPerBlock: u32 = 5;
+	// Accepted proposals skip the enactment delay with the support of three quarters of the council
+	pub ExpeditedThreshold: VoteThreshold = VoteThreshold {
+		quorum: Quorum::Percent(Percent::from_percent(75)),
+		approval: Approval::SuperMajority(Percent::from_percent(75)),
+		constituent_veto: true,
+	};
+	// Emergency proposals that are closed after their voting period need a two thirds supermajority
+	// of the votes cast by the council and cannot be vetoed. Closing them early requires the ayes of
+	// two thirds of the whole council.
+	pub EmergencyThreshold: VoteThreshold = VoteThreshold {
+		quorum: Quorum::Percent(Percent::from_percent(67)),
+		approval: Approval::SuperMajority(Percent::from_percent(67)),
+		constituent_veto: false,
+	};
 }
 
 pub fn get_all_pallet_accounts() -> Vec<AccountId> {
@@ -1,5 +1,9 @@
 // Copyright 2021 ChainSafe Systems
 // SPDX-License-Identifier: LGPL-3.0-only
+use frame_support::{
+	sp_runtime::SaturatedConversion,
+	traits::{Contains, IsSubType},
+};
 use sp_std::marker::PhantomData;
 
 /// Origin either `Root` or `CommitteeOrigin`
@@ -12,6 +16,31 @@ pub type GovernanceOrigin<AccountId, Runtime> = frame_system::EnsureOneOf<
 /// Origin that approved by committee
 pub type CommitteeOrigin<Runtime> = pallet_committee::EnsureApprovedByCommittee<Runtime>;
 
+/// Protective calls the committee can fast-track as emergency proposals
+pub struct EmergencyCalls<T>(PhantomData<T>);
+
+impl<T, Call> Contains<Call> for EmergencyCalls<T>
+where
+	T: pallet_asset_index::Config + pallet_price_feed::Config + pallet_remote_asset_manager::Config,
+	Call: IsSubType<pallet_asset_index::Call<T>>
+		+ IsSubType<pallet_price_feed::Call<T>>
+		+ IsSubType<pallet_remote_asset_manager::Call<T>>,
+{
+	fn contains(call: &Call) -> bool {
+		matches!(
+			<Call as IsSubType<pallet_asset_index::Call<T>>>::is_sub_type(call),
+			Some(pallet_asset_index::Call::pause { .. })
+		) || matches!(
+			<Call as IsSubType<pallet_price_feed::Call<T>>>::is_sub_type(call),
+			Some(pallet_price_feed::Call::unmap_asset_price_feed { .. })
+		) || matches!(
+			<Call as IsSubType<pallet_remote_asset_manager::Call<T>>>::is_sub_type(call),
+			Some(pallet_remote_asset_manager::Call::freeze { .. }) |
+				Some(pallet_remote_asset_manager::Call::disable_statemint_xcm { .. })
+		)
+	}
+}
+
 /// Range of voting period
 pub struct VotingPeriodRange<T>(PhantomData<T>);
 
@@ -37,3 +66,19 @@ impl<T: frame_system::Config> pallet_asset_index::traits::LockupPeriodRange<T::B
 		(crate::constants::DAYS * 28).into()
 	}
 }
+
+/// Index token holdings of the asset-index pallet, used to weigh constituent
+/// votes
+pub struct IndexTokenHoldings<T>(PhantomData<T>);
+
+impl<T: pallet_asset_index::Config> pallet_committee::traits::IndexTokenHoldings<T::AccountId>
+	for IndexTokenHoldings<T>
+{
+	fn balance(who: &T::AccountId) -> u128 {
+		pallet_asset_index::Pallet::<T>::index_token_balance(who).saturated_into()
+	}
+
+	fn locked(who: &T::AccountId) -> u128 {
+		pallet_asset_index::Pallet::<T>::locked_index_tokens(who).saturated_into()
+	}
+}
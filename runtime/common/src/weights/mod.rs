@@ -33,6 +33,12 @@ impl XcmRuntimeCallWeights for StakingWeights {
 			withdraw_unbonded: (52_115_000 as Weight)
 				.saturating_add(weight.reads(4 as Weight))
 				.saturating_add(weight.writes(3 as Weight)),
+			// 16 is `MAX_NOMINATIONS` on polkadot
+			nominate: (44_111_000 as Weight)
+				.saturating_add((5_732_000 as Weight).saturating_mul(16 as Weight))
+				.saturating_add(weight.reads(12 as Weight))
+				.saturating_add(weight.reads((1 as Weight).saturating_mul(16 as Weight)))
+				.saturating_add(weight.writes(6 as Weight)),
 		}
 	}
 
@@ -55,6 +61,12 @@ impl XcmRuntimeCallWeights for StakingWeights {
 			withdraw_unbonded: (57_950_000 as Weight)
 				.saturating_add(weight.reads(6 as Weight))
 				.saturating_add(weight.writes(3 as Weight)),
+			// 24 is `MAX_NOMINATIONS` on kusama
+			nominate: (45_764_000 as Weight)
+				.saturating_add((5_904_000 as Weight).saturating_mul(24 as Weight))
+				.saturating_add(weight.reads(12 as Weight))
+				.saturating_add(weight.reads((1 as Weight).saturating_mul(24 as Weight)))
+				.saturating_add(weight.writes(6 as Weight)),
 		}
 	}
 }
@@ -65,9 +65,14 @@ impl<T: frame_system::Config> pallet_committee::WeightInfo for WeightInfo<T> {
 	// Storage: Committee Proposals (r:1 w:1)
 	// Storage: Committee Votes (r:1 w:0)
 	// Storage: Committee VotingPeriod (r:1 w:0)
-	fn close() -> Weight {
+	// Storage: Committee DelegationSnapshots (r:1 w:0)
+	// Storage: Committee VoteWeightingSnapshots (r:1 w:0)
+	// Storage: Committee ConstituentWeights (r:1 w:0)
+	fn close(d: u32, ) -> Weight {
 		(34_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add((1_000_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(d as Weight)))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
 	// Storage: Committee Members (r:1 w:1)
@@ -90,4 +95,48 @@ impl<T: frame_system::Config> pallet_committee::WeightInfo for WeightInfo<T> {
 		(2_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn set_vote_threshold() -> Weight {
+		(3_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn nominate() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn vote_candidate() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn withdraw_proposal() -> Weight {
+		(29_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn delegate() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn undelegate() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn cancel_queued_proposal() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn propose_batch(n: u32, ) -> Weight {
+		(50_000_000 as Weight)
+			.saturating_add((1_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	fn set_vote_weighting() -> Weight {
+		(3_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }
@@ -46,4 +46,17 @@ impl<T: frame_system::Config> pallet_remote_asset_manager::WeightInfo for Weight
 	fn set_xcm_dest_weight() -> Weight {
 		(10_000_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+
+	// The pallet has no benchmarks, these weights are estimated from the storage accesses and
+	// the XCM messages of the calls.
+	fn staking_upkeep() -> Weight {
+		(60_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn send_nominate() -> Weight {
+		(50_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
 }
@@ -86,4 +86,31 @@ impl<T: frame_system::Config> pallet_saft_registry::WeightInfo for WeightInfo<T>
 			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(o as Weight)))
 			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 	}
+	fn add_vesting_saft() -> Weight {
+		(107_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	fn convert_safts_to_liquid(s: u32, ) -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add((30_000_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((6 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(T::DbWeight::get().writes((6 as Weight).saturating_mul(s as Weight)))
+	}
+	fn attest_nav() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn add_attestor() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn remove_attestor() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }
@@ -103,6 +103,7 @@ pub fn shot_ext(parachain_id: u32, balances: Vec<(AccountId, Balance)>) -> sp_io
 					bond_extra: 1_350_000_000u64,
 					unbond: 1_350_000_000u64,
 					withdraw_unbonded: 1000_u64,
+					nominate: 1_350_000_000u64,
 				},
 				bonding_duration: 1_000,
 				is_frozen: false,
@@ -156,6 +156,69 @@ fn tcan_transact_staking() {
 	});
 }
 
+#[test]
+fn can_nominate_after_bond() {
+	Net::reset();
+	let bond = 1_000_000_000 - 1;
+	let deposit = 2_000_000_000;
+	let targets = vec![ALICE, ADMIN_ACCOUNT];
+
+	Shot::execute_with(|| {
+		register_relay();
+		assert_ok!(shot_runtime::Currencies::deposit(RELAY_CHAIN_ASSET, &ADMIN_ACCOUNT, deposit));
+
+		assert_noop!(
+			pallet_remote_asset_manager::Pallet::<ShotRuntime>::set_nominations(
+				shot_runtime::Origin::signed(ADMIN_ACCOUNT),
+				RELAY_CHAIN_ASSET,
+				vec![]
+			),
+			pallet_remote_asset_manager::Error::<ShotRuntime>::NoNominations
+		);
+
+		// nominations are pending until bonded
+		assert_ok!(pallet_remote_asset_manager::Pallet::<ShotRuntime>::set_nominations(
+			shot_runtime::Origin::signed(ADMIN_ACCOUNT),
+			RELAY_CHAIN_ASSET,
+			targets.iter().cloned().map(Into::into).collect()
+		));
+		assert!(pallet_remote_asset_manager::Pallet::<ShotRuntime>::nominations(RELAY_CHAIN_ASSET)
+			.unwrap()
+			.is_pending());
+
+		// bond with the PINT parachain account as controller, which sends the nominations
+		assert_ok!(pallet_remote_asset_manager::Pallet::<ShotRuntime>::send_bond(
+			shot_runtime::Origin::signed(ADMIN_ACCOUNT),
+			RELAY_CHAIN_ASSET,
+			relay_sovereign_account().into(),
+			bond,
+			xcm_calls::staking::RewardDestination::Staked
+		));
+		assert!(!pallet_remote_asset_manager::Pallet::<ShotRuntime>::nominations(RELAY_CHAIN_ASSET)
+			.unwrap()
+			.is_pending());
+	});
+
+	Kusama::execute_with(|| {
+		let nominations = pallet_staking::Nominators::<KusamaRuntime>::get(&relay_sovereign_account()).unwrap();
+		assert_eq!(nominations.targets, targets);
+	});
+
+	Shot::execute_with(|| {
+		// changing the nominations resends them right away
+		assert_ok!(pallet_remote_asset_manager::Pallet::<ShotRuntime>::set_nominations(
+			shot_runtime::Origin::signed(ADMIN_ACCOUNT),
+			RELAY_CHAIN_ASSET,
+			vec![ALICE.into()]
+		));
+	});
+
+	Kusama::execute_with(|| {
+		let nominations = pallet_staking::Nominators::<KusamaRuntime>::get(&relay_sovereign_account()).unwrap();
+		assert_eq!(nominations.targets, vec![ALICE]);
+	});
+}
+
 #[test]
 fn can_transfer_to_statemint() {
 	Net::reset();
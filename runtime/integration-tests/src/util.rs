@@ -106,11 +106,13 @@ pub fn committee_origin(origin: AccountId) -> CommitteeOrigin<AccountId, BlockNu
 			votes: vec![
 				MemberVote {
 					member: CommitteeMember { account_id: Default::default(), member_type: MemberType::Council },
-					vote: VoteKind::Aye
+					vote: VoteKind::Aye,
+					weight: 1,
 				};
 				<ShotRuntime as pallet_committee::Config>::MinCouncilVotes::get() + 1
 			],
 			end: <frame_system::Pallet<ShotRuntime>>::block_number() + 1,
 		},
+		Default::default(),
 	)
 }
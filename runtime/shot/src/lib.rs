@@ -119,6 +119,9 @@ parameter_types! {
 	pub const ProposalSubmissionPeriod: BlockNumber = 10;
 	pub const Version: RuntimeVersion = VERSION;
 	pub const VotingPeriod: BlockNumber = 27 * DAYS;
+	// longer than the longest voting cycle, so that queued proposals can be cancelled
+	pub const EnactmentDelay: BlockNumber = 30 * DAYS;
+	pub const EmergencyVotingPeriod: BlockNumber = 2 * DAYS;
 }
 
 // Configure FRAME pallets to include in runtime.
@@ -452,6 +455,11 @@ impl pallet_saft_registry::Config for Runtime {
 	type AssetRecorderBenchmarks = AssetIndex;
 	type Balance = Balance;
 	type AssetId = AssetId;
+	type MaxAttestedNavChange = MaxAttestedNavChange;
+	type MaxSaftNavAge = MaxSaftNavAge;
+	type MaxSaftConversions = MaxSaftConversions;
+	type MinAttestationInterval = MinAttestationInterval;
+	type Time = Timestamp;
 	type Event = Event;
 	type WeightInfo = weights::pallet_saft_registry::WeightInfo<Runtime>;
 }
@@ -460,10 +468,29 @@ impl pallet_committee::Config for Runtime {
 	type Origin = Origin;
 	type Action = Call;
 	type ProposalNonce = u32;
+	type MaxBatchSize = MaxBatchSize;
+	type StringLimit = ProposalStringLimit;
 	type ProposalSubmissionPeriod = ProposalSubmissionPeriod;
 	type VotingPeriod = VotingPeriod;
 	type VotingPeriodRange = VotingPeriodRange<Self>;
 	type MinCouncilVotes = MinCouncilVotes;
+	type CouncilSeats = CouncilSeats;
+	type MaxCouncilCandidates = MaxCouncilCandidates;
+	type CouncilTermLength = CouncilTermLength;
+	type Currency = Balances;
+	type ProposalDeposit = ProposalDeposit;
+	type ProposalRetentionPeriod = ProposalRetentionPeriod;
+	type MaxPrunedProposals = MaxPrunedProposals;
+	type MaxDelegations = MaxDelegations;
+	type MaxWeightRemovals = MaxWeightRemovals;
+	type MaxConstituents = MaxConstituents;
+	type EnactmentDelay = EnactmentDelay;
+	type MaxEnactmentsPerBlock = MaxEnactmentsPerBlock;
+	type ExpeditedThreshold = ExpeditedThreshold;
+	type EmergencyCalls = EmergencyCalls<Runtime>;
+	type EmergencyVotingPeriod = EmergencyVotingPeriod;
+	type EmergencyThreshold = EmergencyThreshold;
+	type IndexTokenHoldings = IndexTokenHoldings<Runtime>;
 	type ProposalExecutionOrigin = EnsureMember<Self>;
 	type ProposalSubmissionOrigin = EnsureMember<Self>;
 	type ApprovedByCommitteeOrigin = GovernanceOrigin<AccountId, Runtime>;
@@ -515,6 +542,18 @@ impl pallet_asset_index::Config for Runtime {
 	type PriceFeedBenchmarks = PriceFeed;
 	type SaftRegistry = SaftRegistry;
 	type BaseWithdrawalFee = BaseWithdrawalFee;
+	type SingleAssetWithdrawalFee = SingleAssetWithdrawalFee;
+	type SingleAssetRedemptionCap = SingleAssetRedemptionCap;
+	type MaxAssetsPerCall = MaxAssetsPerCall;
+	type MaxTargets = MaxTargets;
+	type BlocksPerYear = BlocksPerYear;
+	type NavSnapshotInterval = NavSnapshotInterval;
+	type MaxNavSnapshots = MaxNavSnapshots;
+	type MaxNavChange = MaxNavChange;
+	type NavChangeWindow = NavChangeWindow;
+	type MaxPriceAge = MaxPriceAge;
+	type MaxPriceFeedChecks = MaxPriceFeedChecks;
+	type ResumeGracePeriod = ResumeGracePeriod;
 	type TreasuryPalletId = TreasuryPalletId;
 	type Event = Event;
 	type StringLimit = PalletIndexStringLimit;
@@ -657,6 +696,8 @@ impl pallet_remote_asset_manager::Config for Runtime {
 	type SelfParaId = parachain_info::Pallet<Runtime>;
 	type RelayChainAssetId = RelayChainAssetId;
 	type AssetUnbondingSlashingSpans = AssetUnbondingSlashingSpans;
+	type MaxNominations = MaxNominations;
+	type NominationRetryDelay = NominationRetryDelay;
 	type AssetStakingCap = (MinimumRemoteReserveBalance, MinimumBondExtra);
 	type Assets = Currencies;
 	type XcmExecutor = XcmExecutor<XcmConfig>;
@@ -794,8 +835,17 @@ pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signatu
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, Call, SignedExtra>;
 /// Executive: handles dispatch to the various pallets.
-pub type Executive =
-	frame_executive::Executive<Runtime, Block, frame_system::ChainContext<Runtime>, Runtime, AllPallets, ()>;
+pub type Executive = frame_executive::Executive<
+	Runtime,
+	Block,
+	frame_system::ChainContext<Runtime>,
+	Runtime,
+	AllPallets,
+	(
+		pallet_remote_asset_manager::migrations::MigrateToV1<Runtime, MigratedNominateWeight>,
+		pallet_saft_registry::migrations::MigrateToV1<Runtime>,
+	),
+>;
 
 impl_runtime_apis! {
 	impl sp_api::Core<Block> for Runtime {
@@ -909,11 +959,116 @@ impl_runtime_apis! {
 		AccountId,
 		AssetId,
 		Balance,
+		BlockNumber,
 	> for Runtime {
 		fn get_nav() -> primitives::Ratio {
 			use primitives::traits::NavProvider;
 			AssetIndex::nav().unwrap_or_default()
 		}
+
+		fn get_liquid_nav() -> primitives::Ratio {
+			use primitives::traits::NavProvider;
+			AssetIndex::liquid_nav().unwrap_or_default()
+		}
+
+		fn get_saft_nav() -> primitives::Ratio {
+			use primitives::traits::NavProvider;
+			AssetIndex::saft_nav().unwrap_or_default()
+		}
+
+		fn get_asset_proportions() -> Result<primitives::AssetProportions<AssetId>, sp_runtime::DispatchError> {
+			use primitives::traits::NavProvider;
+			AssetIndex::asset_proportions()
+		}
+
+		fn get_index_token_equivalent(asset: AssetId, units: Balance) -> Result<Balance, sp_runtime::DispatchError> {
+			use primitives::traits::NavProvider;
+			AssetIndex::index_token_equivalent(asset, units)
+		}
+
+		fn get_asset_equivalent(index_tokens: Balance, asset: AssetId) -> Result<Balance, sp_runtime::DispatchError> {
+			use primitives::traits::NavProvider;
+			AssetIndex::asset_equivalent(index_tokens, asset)
+		}
+
+		fn get_deposits(account: AccountId) -> Vec<(Balance, BlockNumber)> {
+			AssetIndex::deposits(account).into_inner()
+		}
+
+		fn get_pending_withdrawals(
+			account: AccountId,
+		) -> Vec<primitives::PendingRedemption<AssetId, Balance, BlockNumber>> {
+			AssetIndex::pending_withrawals(account).unwrap_or_default()
+		}
+
+		fn get_locked_index_tokens(account: AccountId) -> Balance {
+			AssetIndex::locked_index_tokens(account)
+		}
+
+		fn quote_withdraw(
+			account: AccountId,
+			amount: Balance,
+		) -> Result<primitives::WithdrawalQuote<AssetId, Balance, BlockNumber>, sp_runtime::DispatchError> {
+			AssetIndex::quote_withdraw(&account, amount)
+		}
+
+		fn quote_withdraw_to_asset(
+			account: AccountId,
+			amount: Balance,
+			asset: AssetId,
+		) -> Result<primitives::WithdrawalQuote<AssetId, Balance, BlockNumber>, sp_runtime::DispatchError> {
+			AssetIndex::quote_withdraw_to_asset(&account, amount, asset)
+		}
+
+		fn get_rebalance_plan() -> Result<primitives::RebalancePlan<AssetId, Balance>, sp_runtime::DispatchError> {
+			AssetIndex::rebalance_plan()
+		}
+
+		fn get_accrued_management_fees() -> Balance {
+			AssetIndex::accrued_management_fees()
+		}
+
+		fn get_nav_snapshots() -> Vec<primitives::NavSnapshot<BlockNumber>> {
+			AssetIndex::nav_snapshots()
+		}
+
+		fn get_pause_reason() -> Option<primitives::PauseReason<AssetId>> {
+			AssetIndex::pause_reason()
+		}
+	}
+
+	impl pallet_committee_rpc_runtime_api::CommitteeApi<
+		Block,
+		AccountId,
+		Hash,
+		BlockNumber,
+	> for Runtime {
+		fn get_active_proposals() -> Vec<primitives::ProposalInfo<AccountId, Hash, BlockNumber>> {
+			Committee::active_proposal_infos()
+		}
+
+		fn get_proposal(proposal_hash: Hash) -> Option<primitives::ProposalInfo<AccountId, Hash, BlockNumber>> {
+			Committee::proposal_info(proposal_hash)
+		}
+
+		fn get_voting_window() -> Result<primitives::VotingWindow<BlockNumber>, sp_runtime::DispatchError> {
+			Committee::voting_window()
+		}
+	}
+
+	impl pallet_saft_registry_rpc_runtime_api::SaftRegistryApi<
+		Block,
+		AssetId,
+		Balance,
+		BlockNumber,
+	> for Runtime {
+		fn get_asset_safts(asset: AssetId) -> primitives::AssetSAFTs<AssetId, Balance, BlockNumber> {
+			SaftRegistry::asset_safts(asset)
+		}
+
+		fn get_all_asset_safts() -> Vec<primitives::AssetSAFTs<AssetId, Balance, BlockNumber>> {
+			SaftRegistry::all_asset_safts()
+		}
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]